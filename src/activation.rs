@@ -0,0 +1,329 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Activation functions that can be applied to arbitrary matrices, e.g. for custom forward passes.
+//!
+//! All activation functions except [`Activation::Softmax`] are applied to each element
+//! independently. The softmax function is applied to each column of the matrix, i.e. each column
+//! is treated as one sample.
+//!
+//! [`Activation::Softmax`]: enum.Activation.html#variant.Softmax
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Result;
+
+/// The activation functions supported by this crate.
+///
+/// The default activation function is the [`Sigmoid`] function.
+///
+/// [`Sigmoid`]: #variant.Sigmoid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Activation {
+    /// The identity function `f(x) = x`.
+    Identity,
+
+    /// The rectified linear unit `f(x) = max(0, x)`.
+    ReLU,
+
+    /// The logistic function `f(x) = 1 / (1 + e^(-x))`.
+    Sigmoid,
+
+    /// The softmax function, normalizing each column of a matrix to a probability distribution.
+    Softmax,
+
+    /// The hyperbolic tangent `f(x) = tanh(x)`.
+    Tanh,
+}
+
+impl Activation {
+    /// Apply this activation function to the given `matrix` and return the result.
+    ///
+    /// This is the same as calling [`apply`] with this activation function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::Activation;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[-1.0, 2.0]).unwrap();
+    ///
+    /// let activated: Matrix<f64> = Activation::ReLU.apply(&matrix);
+    /// assert_eq!(activated.as_slice(), &[0.0, 2.0]);
+    /// ```
+    ///
+    /// [`apply`]: fn.apply.html
+    pub fn apply(self, matrix: &Matrix<f64>) -> Matrix<f64> {
+        apply(self, matrix)
+    }
+
+    /// Apply the derivative of this activation function to the given `matrix` and return the
+    /// result. The elements of `matrix` are the inputs of the activation function, not its outputs.
+    ///
+    /// This is the same as calling [`apply_derivative`] with this activation function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::Activation;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.0, 3.0]).unwrap();
+    ///
+    /// let derivative: Matrix<f64> = Activation::Sigmoid.derivative(&matrix);
+    /// assert_eq!(derivative.as_slice()[0], 0.25);
+    /// ```
+    ///
+    /// [`apply_derivative`]: fn.apply_derivative.html
+    pub fn derivative(self, matrix: &Matrix<f64>) -> Matrix<f64> {
+        apply_derivative(self, matrix)
+    }
+
+    /// Get the activation function with the given identifier in saved neural networks.
+    ///
+    /// If there is no activation function with this identifier, an [`Error::InvalidFormat`] will
+    /// be returned.
+    ///
+    /// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+    pub(crate) fn from_id(id: u8) -> Result<Activation> {
+        match id {
+            0 => Ok(Activation::Identity),
+            1 => Ok(Activation::ReLU),
+            2 => Ok(Activation::Sigmoid),
+            3 => Ok(Activation::Softmax),
+            4 => Ok(Activation::Tanh),
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+
+    /// Get the identifier of this activation function in saved neural networks.
+    ///
+    /// The identifiers must never change, otherwise previously saved neural networks cannot be
+    /// loaded anymore.
+    pub(crate) fn to_id(self) -> u8 {
+        match self {
+            Activation::Identity => 0,
+            Activation::ReLU => 1,
+            Activation::Sigmoid => 2,
+            Activation::Softmax => 3,
+            Activation::Tanh => 4,
+        }
+    }
+}
+
+impl Default for Activation {
+    /// Get the sigmoid function, which used to be the only activation function of the layers.
+    fn default() -> Self {
+        Activation::Sigmoid
+    }
+}
+
+/// Apply the `activation` function to the given `matrix` and return the result.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::activation;
+/// use reural_network::activation::Activation;
+/// use reural_network::matrix::Matrix;
+///
+/// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[-1.0, 2.0, 0.5, -3.0]).unwrap();
+///
+/// let activated: Matrix<f64> = activation::apply(Activation::ReLU, &matrix);
+/// assert_eq!(activated.as_slice(), [0.0, 2.0, 0.5, 0.0]);
+/// ```
+pub fn apply(activation: Activation, matrix: &Matrix<f64>) -> Matrix<f64> {
+    let mut output: Matrix<f64> = matrix.clone();
+    match activation {
+        Activation::Identity => {}
+        Activation::ReLU => output.map(|element, _row, _column| element.max(0.0)),
+        Activation::Sigmoid => output.map(|element, _row, _column| sigmoid(element)),
+        Activation::Softmax => {
+            let (maxima, sums): (Vec<f64>, Vec<f64>) = softmax_normalization(matrix);
+            output.map(|element, _row, column| (element - maxima[column]).exp() / sums[column]);
+        }
+        Activation::Tanh => output.map(|element, _row, _column| element.tanh()),
+    }
+
+    output
+}
+
+/// Apply the derivative of the `activation` function to the given `matrix` and return the result.
+/// The elements of `matrix` are the inputs of the activation function, not its outputs.
+///
+/// For the softmax function, only the diagonal of the Jacobian is computed, i.e. the derivative of
+/// each output with respect to its own input, `s * (1 - s)` where `s` is the softmax output.
+///
+/// The derivative of the rectified linear unit at `0.0` is defined as `0.0`.
+pub fn apply_derivative(activation: Activation, matrix: &Matrix<f64>) -> Matrix<f64> {
+    let mut output: Matrix<f64> = matrix.clone();
+    match activation {
+        Activation::Identity => output.map(|_element, _row, _column| 1.0),
+        Activation::ReLU => {
+            output.map(|element, _row, _column| if element > 0.0 { 1.0 } else { 0.0 })
+        }
+        Activation::Sigmoid => output.map(|element, _row, _column| {
+            let activated: f64 = sigmoid(element);
+            activated * (1.0 - activated)
+        }),
+        Activation::Softmax => {
+            output = apply(Activation::Softmax, matrix);
+            output.map(|activated, _row, _column| activated * (1.0 - activated));
+        }
+        Activation::Tanh => output.map(|element, _row, _column| 1.0 - element.tanh().powi(2)),
+    }
+
+    output
+}
+
+/// Compute the logistic function of `x`.
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Compute the maximum of each column of the `matrix` and the sum of the exponentials of each
+/// column after subtracting this maximum. Subtracting the maximum prevents overflows.
+fn softmax_normalization(matrix: &Matrix<f64>) -> (Vec<f64>, Vec<f64>) {
+    let columns: usize = matrix.get_number_of_columns();
+    let data: &[f64] = matrix.as_slice();
+
+    let mut maxima: Vec<f64> = vec![f64::NEG_INFINITY; columns];
+    for (index, element) in data.iter().enumerate() {
+        maxima[index % columns] = maxima[index % columns].max(*element);
+    }
+
+    let mut sums: Vec<f64> = vec![0.0; columns];
+    for (index, element) in data.iter().enumerate() {
+        sums[index % columns] += (element - maxima[index % columns]).exp();
+    }
+
+    (maxima, sums)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    /// Get a `2 x 3` matrix containing negative values.
+    fn matrix() -> Matrix<f64> {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        Matrix::from_slice(rows, columns, &[-1.0, 0.0, 2.0, 3.0, -0.5, 1.0]).unwrap()
+    }
+
+    /// Test that applying the rectified linear unit zeroes out all negative values.
+    #[test]
+    fn apply_relu() {
+        let activated: Matrix<f64> = apply(Activation::ReLU, &matrix());
+        assert_eq!(activated.as_slice(), [0.0, 0.0, 2.0, 3.0, 0.0, 1.0]);
+
+        let derivative: Matrix<f64> = apply_derivative(Activation::ReLU, &matrix());
+        assert_eq!(derivative.as_slice(), [0.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+    }
+
+    /// Test that applying the softmax function normalizes each column.
+    #[test]
+    fn apply_softmax() {
+        let activated: Matrix<f64> = apply(Activation::Softmax, &matrix());
+
+        let e: f64 = 1.0_f64.exp();
+        let expected_first: f64 = 1.0 / (1.0 + e.powi(4));
+        let expected_second: f64 = 1.0 / (1.0 + e.powf(-0.5));
+        let expected_third: f64 = 1.0 / (1.0 + e.powi(-1));
+        let expected: [f64; 6] = [
+            expected_first,
+            expected_second,
+            expected_third,
+            1.0 - expected_first,
+            1.0 - expected_second,
+            1.0 - expected_third,
+        ];
+        assert_relative_eq!(*activated.as_slice(), expected, epsilon = 1e-12);
+
+        let derivative: Matrix<f64> = apply_derivative(Activation::Softmax, &matrix());
+        let expected_derivative: Vec<f64> = expected.iter().map(|s| s * (1.0 - s)).collect();
+        assert_relative_eq!(
+            *derivative.as_slice(),
+            *expected_derivative,
+            epsilon = 1e-12
+        );
+    }
+
+    /// Test applying the element-wise activation functions and their derivatives.
+    #[test]
+    fn apply_element_wise() {
+        let identity: Matrix<f64> = apply(Activation::Identity, &matrix());
+        assert_eq!(identity, matrix());
+        let identity_derivative: Matrix<f64> = apply_derivative(Activation::Identity, &matrix());
+        assert_eq!(identity_derivative.as_slice(), [1.0; 6]);
+
+        let sigmoid: Matrix<f64> = apply(Activation::Sigmoid, &matrix());
+        assert_relative_eq!(sigmoid.as_slice()[1], 0.5);
+        let sigmoid_derivative: Matrix<f64> = apply_derivative(Activation::Sigmoid, &matrix());
+        assert_relative_eq!(sigmoid_derivative.as_slice()[1], 0.25);
+
+        let tanh: Matrix<f64> = apply(Activation::Tanh, &matrix());
+        assert_relative_eq!(tanh.as_slice()[2], 2.0_f64.tanh());
+        let tanh_derivative: Matrix<f64> = apply_derivative(Activation::Tanh, &matrix());
+        assert_relative_eq!(tanh_derivative.as_slice()[1], 1.0);
+    }
+
+    /// Test that each activation function can be restored from its identifier.
+    #[test]
+    fn id_round_trip() {
+        let activations: [Activation; 5] = [
+            Activation::Identity,
+            Activation::ReLU,
+            Activation::Sigmoid,
+            Activation::Softmax,
+            Activation::Tanh,
+        ];
+        for &activation in &activations {
+            assert_eq!(Activation::from_id(activation.to_id()).unwrap(), activation);
+        }
+
+        assert!(
+            matches!(Activation::from_id(5), Err(Error::InvalidFormat)),
+            "Expected error Error::InvalidFormat not satisfied."
+        );
+    }
+
+    /// Test that the methods of the activation functions match the module functions.
+    #[test]
+    fn methods() {
+        assert_eq!(Activation::default(), Activation::Sigmoid);
+
+        let activations: [Activation; 5] = [
+            Activation::Identity,
+            Activation::ReLU,
+            Activation::Sigmoid,
+            Activation::Softmax,
+            Activation::Tanh,
+        ];
+        for &activation in &activations {
+            assert_eq!(activation.apply(&matrix()), apply(activation, &matrix()));
+            assert_eq!(
+                activation.derivative(&matrix()),
+                apply_derivative(activation, &matrix())
+            );
+        }
+    }
+}
@@ -9,14 +9,15 @@
 
 use std::num::NonZeroUsize;
 
+use reural_network::Activation;
 use reural_network::NeuralNetwork;
 use reural_network::NeuralNetworkBuilder;
 
 /// The main function.
 fn main() {
     let neural_network: NeuralNetwork = NeuralNetworkBuilder::new(NonZeroUsize::new(3).unwrap())
-        .add_hidden_layer(NonZeroUsize::new(7).unwrap())
-        .add_output_layer(NonZeroUsize::new(10).unwrap())
+        .add_hidden_layer(NonZeroUsize::new(7).unwrap(), Activation::ReLU)
+        .add_output_layer(NonZeroUsize::new(10).unwrap(), Activation::Sigmoid)
         .unwrap();
 
     println!("{:?}", neural_network);
@@ -0,0 +1,107 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Helpers for cross-validation.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Error;
+use crate::Result;
+
+/// Randomly assign the sample indices `0..number_of_samples` to `k` folds for k-fold
+/// cross-validation, using the given random number generator `rng`.
+///
+/// The returned folds are disjoint and together contain each index exactly once. The indices
+/// within each fold are in random order. The sizes of the folds differ by at most one.
+///
+/// `k` must be at least `1` and at most `number_of_samples`. Otherwise, an
+/// [`Error::InvalidRange`] will be returned.
+///
+/// # Example
+///
+/// ```
+/// use reural_network::k_fold_indices;
+///
+/// let mut rng = rand::thread_rng();
+/// let folds: Vec<Vec<usize>> = k_fold_indices(10, 3, &mut rng).unwrap();
+/// assert_eq!(folds.len(), 3);
+///
+/// let sizes: Vec<usize> = folds.iter().map(Vec::len).collect();
+/// assert_eq!(sizes, vec![4, 3, 3]);
+/// ```
+///
+/// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+pub fn k_fold_indices<R>(number_of_samples: usize, k: usize, rng: &mut R) -> Result<Vec<Vec<usize>>>
+where
+    R: Rng + ?Sized,
+{
+    if k == 0 || k > number_of_samples {
+        return Err(Error::InvalidRange);
+    }
+
+    let mut indices: Vec<usize> = (0..number_of_samples).collect();
+    indices.shuffle(rng);
+
+    // The first `number_of_samples % k` folds get one additional sample.
+    let fold_size: usize = number_of_samples / k;
+    let remainder: usize = number_of_samples % k;
+
+    let mut folds: Vec<Vec<usize>> = Vec::with_capacity(k);
+    let mut start: usize = 0;
+    for fold in 0..k {
+        let end: usize = start + fold_size + if fold < remainder { 1 } else { 0 };
+        folds.push(indices[start..end].to_vec());
+        start = end;
+    }
+
+    Ok(folds)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Test that the folds are disjoint, cover all indices, and are roughly equal in size.
+    #[test]
+    fn k_fold_indices_valid_k() {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let folds_result: Result<Vec<Vec<usize>>> = k_fold_indices(11, 4, &mut rng);
+        assert!(folds_result.is_ok());
+
+        let folds: Vec<Vec<usize>> = folds_result.unwrap();
+        assert_eq!(folds.len(), 4);
+
+        let sizes: Vec<usize> = folds.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 3, 3, 2]);
+
+        let mut indices: Vec<usize> = folds.into_iter().flatten().collect();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..11).collect::<Vec<usize>>());
+    }
+
+    /// Test getting the folds with an invalid `k`.
+    #[test]
+    fn k_fold_indices_invalid_k() {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let folds_result: Result<Vec<Vec<usize>>> = k_fold_indices(5, 0, &mut rng);
+        assert!(
+            matches!(folds_result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+
+        let folds_result: Result<Vec<Vec<usize>>> = k_fold_indices(5, 6, &mut rng);
+        assert!(
+            matches!(folds_result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Definition and implementation of early stopping for training loops.
+
+use std::num::NonZeroUsize;
+
+use crate::Error;
+use crate::Result;
+
+/// A tracker deciding when to stop training because the validation loss no longer improves.
+///
+/// The tracker is independent of any specific training loop: after each epoch, pass the current
+/// validation loss to [`update`], which will tell whether the training should be stopped.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::EarlyStopping;
+///
+/// let patience: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let mut early_stopping: EarlyStopping = EarlyStopping::new(patience, 0.01).unwrap();
+///
+/// assert!(!early_stopping.update(0.5));
+/// assert!(!early_stopping.update(0.3));
+///
+/// // The loss does not improve by more than `0.01` twice in a row.
+/// assert!(!early_stopping.update(0.295));
+/// assert!(early_stopping.update(0.31));
+/// assert_eq!(early_stopping.best_loss(), Some(0.3));
+/// ```
+///
+/// [`update`]: #method.update
+#[derive(Clone, Debug)]
+pub struct EarlyStopping {
+    /// The number of consecutive updates without improvement after which the training should be
+    /// stopped.
+    patience: NonZeroUsize,
+
+    /// The amount by which the loss must decrease to count as an improvement.
+    min_delta: f64,
+
+    /// The best loss seen so far, if any.
+    best: Option<f64>,
+
+    /// The number of consecutive updates without improvement so far.
+    wait: usize,
+}
+
+impl EarlyStopping {
+    // region Initialization
+
+    /// Create a new early stopping tracker that will stop the training after `patience`
+    /// consecutive updates in which the loss has not decreased by more than `min_delta`.
+    ///
+    /// `min_delta` must neither be negative nor `NaN`. Otherwise, an [`Error::InvalidRange`] will
+    /// be returned.
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn new(patience: NonZeroUsize, min_delta: f64) -> Result<EarlyStopping> {
+        if min_delta.is_nan() || min_delta < 0.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(EarlyStopping {
+            patience,
+            min_delta,
+            best: None,
+            wait: 0,
+        })
+    }
+
+    // endregion
+
+    // region Getters
+
+    /// Get the best loss passed to [`update`] so far, or `None` if there has not been any update.
+    ///
+    /// [`update`]: #method.update
+    pub fn best_loss(&self) -> Option<f64> {
+        self.best
+    }
+
+    // endregion
+
+    // region Updates
+
+    /// Record the validation loss `loss` of the latest epoch and determine if the training should
+    /// be stopped.
+    ///
+    /// The loss counts as an improvement if it is lower than the best loss so far by more than
+    /// `min_delta`. In this case, the counter of updates without improvement will be reset.
+    /// Otherwise, the counter will be incremented, and if it reaches `patience`, `true` will be
+    /// returned.
+    pub fn update(&mut self, loss: f64) -> bool {
+        let improved: bool = match self.best {
+            Some(best) => loss < best - self.min_delta,
+            None => true,
+        };
+
+        if improved {
+            self.best = Some(loss);
+            self.wait = 0;
+            return false;
+        }
+
+        self.wait += 1;
+        self.wait >= self.patience.get()
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test creating a new early stopping tracker with a valid minimum delta.
+    #[test]
+    fn new_valid_min_delta() {
+        let patience: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let early_stopping_result: Result<EarlyStopping> = EarlyStopping::new(patience, 0.0);
+        assert!(early_stopping_result.is_ok());
+
+        let early_stopping: EarlyStopping = early_stopping_result.unwrap();
+        assert_eq!(early_stopping.patience, patience);
+        assert!(early_stopping.best_loss().is_none());
+        assert_eq!(early_stopping.wait, 0);
+    }
+
+    /// Test creating a new early stopping tracker with an invalid minimum delta.
+    #[test]
+    fn new_invalid_min_delta() {
+        let patience: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+        let early_stopping_result: Result<EarlyStopping> = EarlyStopping::new(patience, -0.1);
+        assert!(
+            matches!(early_stopping_result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+
+        let early_stopping_result: Result<EarlyStopping> = EarlyStopping::new(patience, f64::NAN);
+        assert!(
+            matches!(early_stopping_result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+    }
+
+    /// Test that the training is stopped after `patience` updates without improvement.
+    #[test]
+    fn update_stops_after_patience() {
+        let patience: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut early_stopping: EarlyStopping = EarlyStopping::new(patience, 0.1).unwrap();
+
+        assert!(!early_stopping.update(1.0));
+        assert!(!early_stopping.update(1.05));
+        assert!(!early_stopping.update(0.95));
+        assert_eq!(early_stopping.wait, 2);
+        assert!(early_stopping.update(0.91));
+        assert_eq!(early_stopping.best_loss(), Some(1.0));
+    }
+
+    /// Test that an improvement resets the number of updates without improvement.
+    #[test]
+    fn update_resets_wait_on_improvement() {
+        let patience: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let mut early_stopping: EarlyStopping = EarlyStopping::new(patience, 0.0).unwrap();
+
+        assert!(!early_stopping.update(1.0));
+        assert!(!early_stopping.update(1.0));
+        assert_eq!(early_stopping.wait, 1);
+
+        assert!(!early_stopping.update(0.5));
+        assert_eq!(early_stopping.wait, 0);
+        assert_eq!(early_stopping.best_loss(), Some(0.5));
+
+        assert!(!early_stopping.update(0.7));
+        assert!(early_stopping.update(0.6));
+    }
+}
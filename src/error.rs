@@ -11,6 +11,7 @@ use std::error;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::io;
 use std::result::Result as StdResult;
 
 /// A specialized `Result` type for Reural Network.
@@ -31,8 +32,51 @@ pub enum Error {
     /// If the dimensions of a matrix exceed the maximum allowed value, this error will be returned.
     DimensionsTooLarge,
 
+    /// If a value would have to be divided by zero, this error will be returned.
+    DivisionByZero,
+
+    /// If a matrix would be created without any elements, this error will be returned.
+    EmptyMatrix,
+
     /// If a neural network is created without any layers, this error will be returned.
     EmptyNetwork,
+
+    /// If an operation would remove all elements of a matrix (e.g. stripping the border of a
+    /// matrix that only contains default values), this error will be returned.
+    EmptyResult,
+
+    /// If data that is read is not in a valid or supported format (e.g. the header of a file is
+    /// malformed), this error will be returned.
+    InvalidFormat,
+
+    /// If a value is not within its valid range, or if the bounds of a range are invalid (e.g. the
+    /// lower bound is greater than the upper bound), this error will be returned.
+    InvalidRange,
+
+    /// If reading or writing data fails, this error will be returned. It wraps the underlying I/O
+    /// error.
+    Io(io::Error),
+
+    /// If an iterative algorithm does not converge within the given number of iterations, this
+    /// error will be returned.
+    NoConvergence,
+
+    /// If a value is infinite or `NaN` where a finite value is required (e.g. in the forward pass
+    /// of a neural network in strict mode), this error will be returned.
+    NonFiniteValue,
+
+    /// If a matrix does not have exactly the required dimensions, this error will be returned. In
+    /// contrast to [`DimensionMismatch`], it states both the `expected` and the `actual` dimensions
+    /// as `(rows, columns)`.
+    ///
+    /// [`DimensionMismatch`]: #variant.DimensionMismatch
+    ShapeMismatch {
+        /// The dimensions the matrix must have.
+        expected: (usize, usize),
+
+        /// The dimensions the matrix actually has.
+        actual: (usize, usize),
+    },
 }
 
 impl Display for Error {
@@ -51,10 +95,41 @@ impl Display for Error {
                 formatter,
                 "The product of rows and columns must not exceed the maximum usize value, ::std::usize::MAX."
             ),
+            Error::DivisionByZero => write!(formatter, "The divisor must not be zero."),
+            Error::EmptyMatrix => write!(
+                formatter,
+                "The matrix must contain at least one element."
+            ),
             Error::EmptyNetwork => write!(
                 formatter,
                 "The neural network must have at least one layer."
             ),
+            Error::EmptyResult => write!(
+                formatter,
+                "The result of the operation would not contain any elements."
+            ),
+            Error::InvalidFormat => write!(
+                formatter,
+                "The data is not in a valid or supported format."
+            ),
+            Error::InvalidRange => write!(
+                formatter,
+                "The value is not within its valid range."
+            ),
+            Error::Io(ref error) => write!(formatter, "An I/O error occurred: {}", error),
+            Error::NoConvergence => write!(
+                formatter,
+                "The algorithm did not converge within the given number of iterations."
+            ),
+            Error::NonFiniteValue => write!(
+                formatter,
+                "The value must be finite."
+            ),
+            Error::ShapeMismatch { expected, actual } => write!(
+                formatter,
+                "The matrix must have the dimensions {}x{}, but has the dimensions {}x{}.",
+                expected.0, expected.1, actual.0, actual.1
+            ),
         }
     }
 }
@@ -62,7 +137,17 @@ impl Display for Error {
 impl error::Error for Error {
     /// The underlying source of this error, if any.
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-        None
+        match *self {
+            Error::Io(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    /// Wrap the given I/O error.
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
     }
 }
 
@@ -93,6 +178,20 @@ mod tests {
         assert_eq!(format!("{:?}", error), "DimensionsTooLarge");
     }
 
+    /// Test debug formatting a `DivisionByZero` error.
+    #[test]
+    fn debug_division_by_zero() {
+        let error = Error::DivisionByZero;
+        assert_eq!(format!("{:?}", error), "DivisionByZero");
+    }
+
+    /// Test debug formatting a `EmptyMatrix` error.
+    #[test]
+    fn debug_empty_matrix() {
+        let error = Error::EmptyMatrix;
+        assert_eq!(format!("{:?}", error), "EmptyMatrix");
+    }
+
     /// Test debug formatting a `EmptyNetwork` error.
     #[test]
     fn debug_empty_network() {
@@ -100,6 +199,64 @@ mod tests {
         assert_eq!(format!("{:?}", error), "EmptyNetwork");
     }
 
+    /// Test debug formatting a `EmptyResult` error.
+    #[test]
+    fn debug_empty_result() {
+        let error = Error::EmptyResult;
+        assert_eq!(format!("{:?}", error), "EmptyResult");
+    }
+
+    /// Test debug formatting a `InvalidFormat` error.
+    #[test]
+    fn debug_invalid_format() {
+        let error = Error::InvalidFormat;
+        assert_eq!(format!("{:?}", error), "InvalidFormat");
+    }
+
+    /// Test debug formatting a `InvalidRange` error.
+    #[test]
+    fn debug_invalid_range() {
+        let error = Error::InvalidRange;
+        assert_eq!(format!("{:?}", error), "InvalidRange");
+    }
+
+    /// Test debug formatting an `Io` error.
+    #[test]
+    fn debug_io() {
+        let error = Error::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "end of file"));
+        assert_eq!(
+            format!("{:?}", error),
+            "Io(Custom { kind: UnexpectedEof, error: \"end of file\" })"
+        );
+    }
+
+    /// Test debug formatting a `NoConvergence` error.
+    #[test]
+    fn debug_no_convergence() {
+        let error = Error::NoConvergence;
+        assert_eq!(format!("{:?}", error), "NoConvergence");
+    }
+
+    /// Test debug formatting a `NonFiniteValue` error.
+    #[test]
+    fn debug_non_finite_value() {
+        let error = Error::NonFiniteValue;
+        assert_eq!(format!("{:?}", error), "NonFiniteValue");
+    }
+
+    /// Test debug formatting a `ShapeMismatch` error.
+    #[test]
+    fn debug_shape_mismatch() {
+        let error = Error::ShapeMismatch {
+            expected: (3, 2),
+            actual: (2, 3),
+        };
+        assert_eq!(
+            format!("{:?}", error),
+            "ShapeMismatch { expected: (3, 2), actual: (2, 3) }"
+        );
+    }
+
     /// Test formatting a `CellOutOfBounds` error.
     #[test]
     fn fmt_cell_out_of_bounds() {
@@ -127,6 +284,23 @@ mod tests {
         );
     }
 
+    /// Test formatting a `DivisionByZero` error.
+    #[test]
+    fn fmt_division_by_zero() {
+        let error = Error::DivisionByZero;
+        assert_eq!(format!("{}", error), "The divisor must not be zero.");
+    }
+
+    /// Test formatting a `EmptyMatrix` error.
+    #[test]
+    fn fmt_empty_matrix() {
+        let error = Error::EmptyMatrix;
+        assert_eq!(
+            format!("{}", error),
+            "The matrix must contain at least one element."
+        );
+    }
+
     /// Test formatting a `EmptyNetwork` error.
     #[test]
     fn fmt_empty_network() {
@@ -137,6 +311,73 @@ mod tests {
         );
     }
 
+    /// Test formatting a `EmptyResult` error.
+    #[test]
+    fn fmt_empty_result() {
+        let error = Error::EmptyResult;
+        assert_eq!(
+            format!("{}", error),
+            "The result of the operation would not contain any elements."
+        );
+    }
+
+    /// Test formatting a `InvalidFormat` error.
+    #[test]
+    fn fmt_invalid_format() {
+        let error = Error::InvalidFormat;
+        assert_eq!(
+            format!("{}", error),
+            "The data is not in a valid or supported format."
+        );
+    }
+
+    /// Test formatting a `InvalidRange` error.
+    #[test]
+    fn fmt_invalid_range() {
+        let error = Error::InvalidRange;
+        assert_eq!(
+            format!("{}", error),
+            "The value is not within its valid range."
+        );
+    }
+
+    /// Test formatting an `Io` error.
+    #[test]
+    fn fmt_io() {
+        let error = Error::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "end of file"));
+        assert_eq!(format!("{}", error), "An I/O error occurred: end of file");
+    }
+
+    /// Test formatting a `NoConvergence` error.
+    #[test]
+    fn fmt_no_convergence() {
+        let error = Error::NoConvergence;
+        assert_eq!(
+            format!("{}", error),
+            "The algorithm did not converge within the given number of iterations."
+        );
+    }
+
+    /// Test formatting a `NonFiniteValue` error.
+    #[test]
+    fn fmt_non_finite_value() {
+        let error = Error::NonFiniteValue;
+        assert_eq!(format!("{}", error), "The value must be finite.");
+    }
+
+    /// Test formatting a `ShapeMismatch` error.
+    #[test]
+    fn fmt_shape_mismatch() {
+        let error = Error::ShapeMismatch {
+            expected: (3, 2),
+            actual: (2, 3),
+        };
+        assert_eq!(
+            format!("{}", error),
+            "The matrix must have the dimensions 3x2, but has the dimensions 2x3."
+        );
+    }
+
     /// Test getting the source of a `CellOutOfBounds` error.
     #[test]
     fn source_cell_out_of_bounds() {
@@ -158,10 +399,86 @@ mod tests {
         assert!(error.source().is_none());
     }
 
+    /// Test getting the source of a `DivisionByZero` error.
+    #[test]
+    fn source_division_by_zero() {
+        let error = Error::DivisionByZero;
+        assert!(error.source().is_none());
+    }
+
+    /// Test getting the source of a `EmptyMatrix` error.
+    #[test]
+    fn source_empty_matrix() {
+        let error = Error::EmptyMatrix;
+        assert!(error.source().is_none());
+    }
+
     /// Test getting the source of a `EmptyNetwork` error.
     #[test]
     fn source_empty_network() {
         let error = Error::EmptyNetwork;
         assert!(error.source().is_none());
     }
+
+    /// Test getting the source of a `EmptyResult` error.
+    #[test]
+    fn source_empty_result() {
+        let error = Error::EmptyResult;
+        assert!(error.source().is_none());
+    }
+
+    /// Test getting the source of a `InvalidFormat` error.
+    #[test]
+    fn source_invalid_format() {
+        let error = Error::InvalidFormat;
+        assert!(error.source().is_none());
+    }
+
+    /// Test getting the source of a `InvalidRange` error.
+    #[test]
+    fn source_invalid_range() {
+        let error = Error::InvalidRange;
+        assert!(error.source().is_none());
+    }
+
+    /// Test getting the source of an `Io` error.
+    #[test]
+    fn source_io() {
+        let error = Error::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "end of file"));
+        assert!(error.source().is_some());
+    }
+
+    /// Test converting an I/O error.
+    #[test]
+    fn from_io_error() {
+        let error: Error = io::Error::new(io::ErrorKind::UnexpectedEof, "end of file").into();
+        assert!(
+            matches!(error, Error::Io(_)),
+            "Expected error Error::Io not satisfied."
+        );
+    }
+
+    /// Test getting the source of a `NoConvergence` error.
+    #[test]
+    fn source_no_convergence() {
+        let error = Error::NoConvergence;
+        assert!(error.source().is_none());
+    }
+
+    /// Test getting the source of a `NonFiniteValue` error.
+    #[test]
+    fn source_non_finite_value() {
+        let error = Error::NonFiniteValue;
+        assert!(error.source().is_none());
+    }
+
+    /// Test getting the source of a `ShapeMismatch` error.
+    #[test]
+    fn source_shape_mismatch() {
+        let error = Error::ShapeMismatch {
+            expected: (3, 2),
+            actual: (2, 3),
+        };
+        assert!(error.source().is_none());
+    }
 }
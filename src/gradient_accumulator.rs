@@ -0,0 +1,261 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Definition and implementation of a buffer for accumulating gradients over several mini-batches.
+
+use std::num::NonZeroUsize;
+use std::ops::Add;
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Layer;
+use crate::NeuralNetwork;
+use crate::Result;
+
+/// A buffer summing the gradients of several mini-batches, e.g. to simulate a large batch that
+/// does not fit into memory at once.
+///
+/// The gradients are given per layer as pairs of the weight gradient and the bias gradient, in
+/// the order of the layers in the neural network. After all mini-batches have been added, the
+/// averaged gradients can be applied to the neural network with [`apply`].
+///
+/// [`apply`]: #method.apply
+#[derive(Clone, Debug, Default)]
+pub struct GradientAccumulator {
+    /// The sums of the weight and bias gradients of each layer.
+    sums: Vec<(Matrix<f64>, Matrix<f64>)>,
+}
+
+impl GradientAccumulator {
+    // region Initialization
+
+    /// Create a new, empty gradient accumulator.
+    pub fn new() -> GradientAccumulator {
+        GradientAccumulator { sums: Vec::new() }
+    }
+
+    // endregion
+
+    // region Getters
+
+    /// Determine if no gradients have been added since the creation or the last application.
+    pub fn is_empty(&self) -> bool {
+        self.sums.is_empty()
+    }
+
+    // endregion
+
+    // region Accumulation
+
+    /// Add the `gradients` of one mini-batch to the accumulated gradients. For each layer, the
+    /// `gradients` must contain a pair of the weight gradient and the bias gradient.
+    ///
+    /// If the number of layers or the dimensions of any gradient differ from the gradients added
+    /// before, an [`Error::DimensionMismatch`] will be returned and the accumulated gradients will
+    /// not be changed.
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn add(&mut self, gradients: &[(Matrix<f64>, Matrix<f64>)]) -> Result<()> {
+        if self.sums.is_empty() {
+            self.sums = gradients.to_vec();
+            return Ok(());
+        }
+
+        if self.sums.len() != gradients.len() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        // Compute all sums before changing anything.
+        let sums: Vec<(Matrix<f64>, Matrix<f64>)> = self
+            .sums
+            .iter()
+            .zip(gradients)
+            .map(
+                |((weight_sum, bias_sum), (weight_gradient, bias_gradient))| {
+                    Ok((
+                        weight_sum.add(weight_gradient)?,
+                        bias_sum.add(bias_gradient)?,
+                    ))
+                },
+            )
+            .collect::<Result<Vec<(Matrix<f64>, Matrix<f64>)>>>()?;
+        self.sums = sums;
+
+        Ok(())
+    }
+
+    /// Apply the average of the accumulated gradients to the `network`, using the given
+    /// `learning_rate`, and reset the accumulator. The average is computed by dividing the sums by
+    /// `count`, which usually is the number of added mini-batches.
+    ///
+    /// If no gradients have been added, the network will not be changed.
+    ///
+    /// If the number of layers does not match the network, an [`Error::DimensionMismatch`] will be
+    /// returned. If the dimensions of any gradient do not match its layer, an
+    /// [`Error::ShapeMismatch`] will be returned. In both cases, neither the network nor the
+    /// accumulator will be changed.
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    /// [`Error::ShapeMismatch`]: enum.Error.html#variant.ShapeMismatch
+    pub fn apply(
+        &mut self,
+        network: &mut NeuralNetwork,
+        learning_rate: f64,
+        count: NonZeroUsize,
+    ) -> Result<()> {
+        if self.sums.is_empty() {
+            return Ok(());
+        }
+
+        let layers: &mut [Layer] = network.get_layers_mut();
+        if layers.len() != self.sums.len() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        // Check all gradients before changing any layer.
+        for (layer, (weight_sum, bias_sum)) in layers.iter().zip(&self.sums) {
+            let weights: &Matrix<f64> = layer.get_weights();
+            weight_sum.ensure_dimensions(
+                weights.get_number_of_rows(),
+                weights.get_number_of_columns(),
+            )?;
+            let bias: &Matrix<f64> = layer.get_bias();
+            bias_sum.ensure_dimensions(bias.get_number_of_rows(), bias.get_number_of_columns())?;
+        }
+
+        // Scaling the learning rate is equivalent to averaging the gradients.
+        let learning_rate: f64 = learning_rate / count.get() as f64;
+        for (layer, (weight_sum, bias_sum)) in layers.iter_mut().zip(&self.sums) {
+            layer.apply_gradient(weight_sum, bias_sum, learning_rate)?;
+        }
+
+        self.sums.clear();
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    use crate::Activation;
+
+    /// Get the gradients for a single layer with two inputs and one output.
+    fn gradients() -> Vec<(Matrix<f64>, Matrix<f64>)> {
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        vec![(
+            Matrix::from_slice(one, two, &[0.5, -1.0]).unwrap(),
+            Matrix::from_slice(one, one, &[2.0]).unwrap(),
+        )]
+    }
+
+    /// Test that applying the average of two identical gradients equals applying one of them.
+    #[test]
+    fn apply_averaged_gradients() {
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let layer: Layer = Layer::new(two, one, Activation::Sigmoid).unwrap();
+        let mut accumulated_network: NeuralNetwork =
+            NeuralNetwork::new(vec![layer.clone()]).unwrap();
+        let mut single_network: NeuralNetwork = NeuralNetwork::new(vec![layer]).unwrap();
+
+        let mut accumulator: GradientAccumulator = GradientAccumulator::new();
+        assert!(accumulator.add(&gradients()).is_ok());
+        assert!(accumulator.add(&gradients()).is_ok());
+        assert!(accumulator
+            .apply(&mut accumulated_network, 0.1, two)
+            .is_ok());
+        assert!(accumulator.is_empty());
+
+        let (weight_gradient, bias_gradient): (Matrix<f64>, Matrix<f64>) =
+            gradients().pop().unwrap();
+        single_network.get_layers_mut()[0]
+            .apply_gradient(&weight_gradient, &bias_gradient, 0.1)
+            .unwrap();
+
+        assert_relative_eq!(
+            *accumulated_network.all_weights(),
+            *single_network.all_weights()
+        );
+        assert_relative_eq!(
+            *accumulated_network.all_biases(),
+            *single_network.all_biases()
+        );
+    }
+
+    /// Test adding gradients that do not match the gradients added before.
+    #[test]
+    fn add_mismatching_gradients() {
+        let mut accumulator: GradientAccumulator = GradientAccumulator::new();
+        accumulator.add(&gradients()).unwrap();
+
+        let mut too_many_layers: Vec<(Matrix<f64>, Matrix<f64>)> = gradients();
+        too_many_layers.extend(gradients());
+        let result: Result<()> = accumulator.add(&too_many_layers);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        let (weight_gradient, bias_gradient): (Matrix<f64>, Matrix<f64>) =
+            gradients().pop().unwrap();
+        let transposed: Vec<(Matrix<f64>, Matrix<f64>)> =
+            vec![(weight_gradient.transpose(), bias_gradient)];
+        let result: Result<()> = accumulator.add(&transposed);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test applying gradients that do not match the network.
+    #[test]
+    fn apply_mismatching_network() {
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let layer: Layer = Layer::new(two, two, Activation::Sigmoid).unwrap();
+        let mut network: NeuralNetwork = NeuralNetwork::new(vec![layer.clone()]).unwrap();
+
+        let mut accumulator: GradientAccumulator = GradientAccumulator::new();
+        accumulator.add(&gradients()).unwrap();
+
+        let result: Result<()> = accumulator.apply(&mut network, 0.1, two);
+        assert!(
+            matches!(result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+        assert!(!accumulator.is_empty());
+        assert_eq!(network.get_layers(), &[layer]);
+    }
+
+    /// Test applying gradients to a network with a different number of layers.
+    #[test]
+    fn apply_mismatching_number_of_layers() {
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(two, one, Activation::Sigmoid).unwrap(),
+            Layer::new(one, one, Activation::Sigmoid).unwrap(),
+        ];
+        let mut network: NeuralNetwork = NeuralNetwork::new(layers.clone()).unwrap();
+
+        let mut accumulator: GradientAccumulator = GradientAccumulator::new();
+        accumulator.add(&gradients()).unwrap();
+
+        let result: Result<()> = accumulator.apply(&mut network, 0.1, two);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+        assert!(!accumulator.is_empty());
+        assert_eq!(network.get_layers(), &layers[..]);
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Strategies for initializing the weights and biases of a layer.
+
+use std::num::NonZeroUsize;
+
+use rand::distributions::Uniform;
+use rand::Rng;
+
+use crate::matrix::Matrix;
+use crate::random::Normal;
+use crate::Error;
+use crate::Result;
+
+/// The strategies for initializing the parameters of a layer with `i` input nodes (the fan-in) and
+/// `o` output nodes (the fan-out).
+///
+/// The default strategy is [`Uniform01`].
+///
+/// [`Uniform01`]: #variant.Uniform01
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InitStrategy {
+    /// Draw the weights and the bias uniformly from `[0.0, 1.0]`.
+    ///
+    /// This used to be the only initialization, but its variance is too large for deep networks.
+    Uniform01,
+
+    /// Draw the weights uniformly from `[-l, l]` with `l = sqrt(6 / (i + o))` (Glorot and Bengio),
+    /// and initialize the bias with `0.0`. This is suited for the sigmoid and tanh functions.
+    XavierUniform,
+
+    /// Draw the weights from the normal distribution `N(0, 2 / i)` (He et al.), and initialize
+    /// the bias with `0.0`. This is suited for the rectified linear unit.
+    HeNormal,
+}
+
+impl InitStrategy {
+    /// Get the initialization strategy with the given identifier in saved neural networks.
+    ///
+    /// If there is no initialization strategy with this identifier, an [`Error::InvalidFormat`]
+    /// will be returned.
+    ///
+    /// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+    pub(crate) fn from_id(id: u8) -> Result<InitStrategy> {
+        match id {
+            0 => Ok(InitStrategy::Uniform01),
+            1 => Ok(InitStrategy::XavierUniform),
+            2 => Ok(InitStrategy::HeNormal),
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+
+    /// Create the `o x i` weights and the `o x 1` bias of a layer with the given number of input
+    /// and output nodes according to this strategy, drawing from the random number generator
+    /// `rng`. The weights are drawn first.
+    ///
+    /// The product of the number of input nodes and output nodes must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
+    pub(crate) fn initialize<R>(
+        self,
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        rng: &mut R,
+    ) -> Result<(Matrix<f64>, Matrix<f64>)>
+    where
+        R: Rng + ?Sized,
+    {
+        let weights: Matrix<f64> = self.initialize_weights(input_nodes, output_nodes, rng)?;
+        let bias: Matrix<f64> = self.initialize_bias(output_nodes, rng)?;
+        Ok((weights, bias))
+    }
+
+    /// Create the `o x 1` bias of a layer with the given number of output nodes according to this
+    /// strategy, drawing from the random number generator `rng` if necessary.
+    pub(crate) fn initialize_bias<R>(
+        self,
+        output_nodes: NonZeroUsize,
+        rng: &mut R,
+    ) -> Result<Matrix<f64>>
+    where
+        R: Rng + ?Sized,
+    {
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+
+        match self {
+            InitStrategy::Uniform01 => {
+                let uniform: Uniform<f64> = Uniform::new_inclusive(0.0, 1.0);
+                Matrix::from_distribution(output_nodes, one, &uniform, rng)
+            }
+            InitStrategy::XavierUniform | InitStrategy::HeNormal => {
+                Matrix::new(output_nodes, one, 0.0)
+            }
+        }
+    }
+
+    /// Create the `o x i` weights of a layer with the given number of input and output nodes
+    /// according to this strategy, drawing from the random number generator `rng`.
+    ///
+    /// The product of the number of input nodes and output nodes must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
+    pub(crate) fn initialize_weights<R>(
+        self,
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        rng: &mut R,
+    ) -> Result<Matrix<f64>>
+    where
+        R: Rng + ?Sized,
+    {
+        let fan_in: f64 = input_nodes.get() as f64;
+        let fan_out: f64 = output_nodes.get() as f64;
+
+        match self {
+            InitStrategy::Uniform01 => {
+                let uniform: Uniform<f64> = Uniform::new_inclusive(0.0, 1.0);
+                Matrix::from_distribution(output_nodes, input_nodes, &uniform, rng)
+            }
+            InitStrategy::XavierUniform => {
+                let limit: f64 = (6.0 / (fan_in + fan_out)).sqrt();
+                let uniform: Uniform<f64> = Uniform::new_inclusive(-limit, limit);
+                Matrix::from_distribution(output_nodes, input_nodes, &uniform, rng)
+            }
+            InitStrategy::HeNormal => {
+                let normal: Normal = Normal::new(0.0, (2.0 / fan_in).sqrt())?;
+                Matrix::from_distribution(output_nodes, input_nodes, &normal, rng)
+            }
+        }
+    }
+
+    /// Get the identifier of this initialization strategy in saved neural networks.
+    ///
+    /// The identifiers must never change, otherwise previously saved neural networks cannot be
+    /// loaded anymore.
+    pub(crate) fn to_id(self) -> u8 {
+        match self {
+            InitStrategy::Uniform01 => 0,
+            InitStrategy::XavierUniform => 1,
+            InitStrategy::HeNormal => 2,
+        }
+    }
+}
+
+impl Default for InitStrategy {
+    /// Get the uniform initialization within `[0.0, 1.0]`, which used to be the only one.
+    fn default() -> Self {
+        InitStrategy::Uniform01
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// Test that each initialization strategy can be restored from its identifier.
+    #[test]
+    fn id_round_trip() {
+        let strategies: [InitStrategy; 3] = [
+            InitStrategy::Uniform01,
+            InitStrategy::XavierUniform,
+            InitStrategy::HeNormal,
+        ];
+        for &strategy in &strategies {
+            assert_eq!(InitStrategy::from_id(strategy.to_id()).unwrap(), strategy);
+        }
+
+        assert!(
+            matches!(InitStrategy::from_id(3), Err(Error::InvalidFormat)),
+            "Expected error Error::InvalidFormat not satisfied."
+        );
+    }
+
+    /// Test that the empirical variance of He-initialized weights is close to `2 / i`.
+    #[test]
+    fn initialize_he_normal() {
+        let input_nodes: NonZeroUsize = NonZeroUsize::new(50).unwrap();
+        let output_nodes: NonZeroUsize = NonZeroUsize::new(200).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let (weights, bias): (Matrix<f64>, Matrix<f64>) = InitStrategy::HeNormal
+            .initialize(input_nodes, output_nodes, &mut rng)
+            .unwrap();
+        assert_eq!(weights.get_number_of_rows(), 200);
+        assert_eq!(weights.get_number_of_columns(), 50);
+        assert_eq!(bias.as_slice(), &[0.0; 200][..]);
+
+        let data: &[f64] = weights.as_slice();
+        let mean: f64 = data.iter().sum::<f64>() / data.len() as f64;
+        let variance: f64 =
+            data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / data.len() as f64;
+        assert!(mean.abs() < 0.01);
+        assert!((variance - 2.0 / 50.0).abs() < 0.002);
+    }
+
+    /// Test that Xavier-initialized weights are within the limit and the other strategies are in
+    /// their expected ranges.
+    #[test]
+    fn initialize_ranges() {
+        let input_nodes: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let output_nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let (weights, bias): (Matrix<f64>, Matrix<f64>) = InitStrategy::XavierUniform
+            .initialize(input_nodes, output_nodes, &mut rng)
+            .unwrap();
+        assert!(weights.as_slice().iter().all(|x| x.abs() <= 1.0));
+        assert!(weights.as_slice().iter().any(|x| *x < 0.0));
+        assert_eq!(bias.as_slice(), &[0.0, 0.0]);
+
+        let (weights, bias): (Matrix<f64>, Matrix<f64>) = InitStrategy::default()
+            .initialize(input_nodes, output_nodes, &mut rng)
+            .unwrap();
+        assert!(weights.as_slice().iter().all(|x| (0.0..=1.0).contains(x)));
+        assert!(bias.as_slice().iter().all(|x| (0.0..=1.0).contains(x)));
+    }
+}
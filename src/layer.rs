@@ -7,11 +7,22 @@
 
 //! Definition and implementation of the neural network's layers.
 
+use std::io::Read;
+use std::io::Write;
 use std::num::NonZeroUsize;
-use std::ops::Add;
+use std::ops::Sub;
+
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
 
 use crate::matrix::Matrix;
+use crate::random;
+use crate::random::Normal;
+use crate::Activation;
 use crate::Error;
+use crate::InitStrategy;
 use crate::Result;
 
 /// A layer of the neural network.
@@ -29,48 +40,266 @@ pub struct Layer {
     ///
     /// This is a `o x 1` matrix where `o` is the number of this layer's output nodes.
     bias: Matrix<f64>,
+
+    /// The activation function applied to the weighted sum of this layer's input.
+    activation: Activation,
+
+    /// The optional range `(min, max)` into which all outputs of this layer will be clamped after
+    /// the activation function has been applied.
+    activation_clip: Option<(f64, f64)>,
+
+    /// The strategy with which the weights and the bias of this layer are re-initialized.
+    init_strategy: InitStrategy,
 }
 
 impl Layer {
     // region Initialize
 
+    /// Create a new layer from the given `weights` and `bias`, e.g. to use parameters that have
+    /// been trained elsewhere. The layer will apply the [`Activation::Sigmoid`] function to its
+    /// outputs.
+    ///
+    /// The `weights` must be a `o x i` matrix and the `bias` a `o x 1` matrix, where `o` is the
+    /// number of output nodes and `i` the number of input nodes. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    /// use reural_network::Layer;
+    ///
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let weights: Matrix<f64> = Matrix::from_slice(one, two, &[0.5, -0.5]).unwrap();
+    /// let bias: Matrix<f64> = Matrix::from_slice(one, one, &[0.0]).unwrap();
+    ///
+    /// let layer: Layer = Layer::from_parameters(weights, bias).unwrap();
+    /// let input: Matrix<f64> = Matrix::from_slice(two, one, &[1.0, 1.0]).unwrap();
+    /// assert_eq!(layer.predict(&input).unwrap().as_slice(), &[0.5]);
+    /// ```
+    ///
+    /// [`Activation::Sigmoid`]: ../activation/enum.Activation.html#variant.Sigmoid
+    /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+    pub fn from_parameters(weights: Matrix<f64>, bias: Matrix<f64>) -> Result<Layer> {
+        if bias.get_number_of_rows() != weights.get_number_of_rows()
+            || bias.get_number_of_columns() != 1
+        {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(Layer {
+            weights,
+            bias,
+            activation: Activation::Sigmoid,
+            activation_clip: None,
+            init_strategy: InitStrategy::default(),
+        })
+    }
+
     /// Create a new layer within a neural network. The layer will have the given number of input
-    /// and output nodes.
+    /// and output nodes, and will apply the given `activation` function to its outputs. Use
+    /// [`Activation::Sigmoid`], the default activation function, to get the behaviour of previous
+    /// versions.
     ///
-    /// The weights and bias will be initialized with random values within `[0.0, 1.0]`.
+    /// The weights and bias will be initialized with random values within `[0.0, 1.0]`, i.e. with
+    /// [`InitStrategy::Uniform01`]. Use [`new_with_init`] for a better suited initialization.
     ///
     /// The product of the number of input nodes and output nodes must not exceed the maximum
     /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
     /// returned.
     ///
     /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Activation::Sigmoid`]: ../activation/enum.Activation.html#variant.Sigmoid
     /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
-    pub fn new(input_nodes: NonZeroUsize, output_nodes: NonZeroUsize) -> Result<Layer> {
-        // Weights are `o x i`.
-        let weights = Matrix::from_random(output_nodes, input_nodes)?;
+    /// [`InitStrategy::Uniform01`]: ../enum.InitStrategy.html#variant.Uniform01
+    /// [`new_with_init`]: #method.new_with_init
+    pub fn new(
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        activation: Activation,
+    ) -> Result<Layer> {
+        Layer::new_with_init(
+            input_nodes,
+            output_nodes,
+            activation,
+            InitStrategy::Uniform01,
+        )
+    }
 
-        // Bias is `o x 1`.
-        let bias = Matrix::from_random(output_nodes, NonZeroUsize::new(1).unwrap())?;
+    /// Create a new layer like [`new`], but initialize the weights and bias with the given
+    /// `strategy`, e.g. [`InitStrategy::HeNormal`] for a deep network of rectified linear units.
+    ///
+    /// The product of the number of input nodes and output nodes must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::Activation;
+    /// use reural_network::InitStrategy;
+    /// use reural_network::Layer;
+    ///
+    /// let input_nodes: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let output_nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let layer: Layer = Layer::new_with_init(
+    ///     input_nodes,
+    ///     output_nodes,
+    ///     Activation::Tanh,
+    ///     InitStrategy::XavierUniform,
+    /// )
+    /// .unwrap();
+    ///
+    /// // The weights are within `[-sqrt(6 / (3 + 2)), sqrt(6 / (3 + 2))]`.
+    /// let limit: f64 = 1.2_f64.sqrt();
+    /// assert!(layer.get_weights().as_slice().iter().all(|weight| weight.abs() <= limit));
+    /// assert_eq!(layer.get_bias().as_slice(), &[0.0, 0.0]);
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
+    /// [`InitStrategy::HeNormal`]: ../enum.InitStrategy.html#variant.HeNormal
+    pub fn new_with_init(
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        activation: Activation,
+        strategy: InitStrategy,
+    ) -> Result<Layer> {
+        random::with_default_rng(|rng| {
+            Layer::new_with_rng(input_nodes, output_nodes, activation, strategy, rng)
+        })
+    }
 
-        Ok(Layer { weights, bias })
+    /// Create a new layer like [`new`], but draw the random weights and bias from a random number
+    /// generator seeded with the given `seed`, e.g. to reproduce a training run. The same `seed`
+    /// always results in the same parameters.
+    ///
+    /// The product of the number of input nodes and output nodes must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// [`new`]: #method.new
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
+    pub fn new_seeded(
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        activation: Activation,
+        seed: u64,
+    ) -> Result<Layer> {
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        let strategy: InitStrategy = InitStrategy::Uniform01;
+        Layer::new_with_rng(input_nodes, output_nodes, activation, strategy, &mut rng)
+    }
+
+    /// Create a new layer like [`new_with_init`], but draw the random weights and bias from the
+    /// given random number generator `rng`. The weights are drawn first.
+    ///
+    /// [`new_with_init`]: #method.new_with_init
+    pub(crate) fn new_with_rng<R>(
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        activation: Activation,
+        strategy: InitStrategy,
+        rng: &mut R,
+    ) -> Result<Layer>
+    where
+        R: Rng + ?Sized,
+    {
+        // Weights are `o x i`, bias is `o x 1`.
+        let (weights, bias): (Matrix<f64>, Matrix<f64>) =
+            strategy.initialize(input_nodes, output_nodes, rng)?;
+
+        Ok(Layer {
+            weights,
+            bias,
+            activation,
+            activation_clip: None,
+            init_strategy: strategy,
+        })
     }
 
     // endregion
 
     // region Getters
 
+    /// Get the activation function of this layer.
+    pub fn get_activation(&self) -> Activation {
+        self.activation
+    }
+
+    /// Get the bias of this layer.
+    ///
+    /// This is a `o x 1` matrix where `o` is the number of this layer's output nodes.
+    pub fn get_bias(&self) -> &Matrix<f64> {
+        &self.bias
+    }
+
+    /// Get the number of input nodes and the number of output nodes of this layer, in this order.
+    ///
+    /// Since a matrix always has at least one row and column, this never fails in practice. If it
+    /// did, an [`Error::EmptyMatrix`] would be returned.
+    ///
+    /// [`Error::EmptyMatrix`]: ../enum.Error.html#variant.EmptyMatrix
+    fn get_dimensions(&self) -> Result<(NonZeroUsize, NonZeroUsize)> {
+        let input_nodes: Option<NonZeroUsize> = NonZeroUsize::new(self.get_number_of_input_nodes());
+        let output_nodes: Option<NonZeroUsize> =
+            NonZeroUsize::new(self.get_number_of_output_nodes());
+        input_nodes.zip(output_nodes).ok_or(Error::EmptyMatrix)
+    }
+
+    /// Get the strategy with which the weights and the bias of this layer are re-initialized.
+    ///
+    /// Layers created from given parameters use the default strategy.
+    pub fn get_init_strategy(&self) -> InitStrategy {
+        self.init_strategy
+    }
+
     /// Get the number of input nodes.
-    #[cfg(test)]
     pub(crate) fn get_number_of_input_nodes(&self) -> usize {
         self.weights.get_number_of_columns()
     }
 
     /// Get the number of output nodes.
-    #[cfg(test)]
     pub(crate) fn get_number_of_output_nodes(&self) -> usize {
         self.weights.get_number_of_rows()
     }
 
+    /// Get the weights for this layer's input.
+    ///
+    /// This is a `o x i` matrix where `o` is the number of this layer's output nodes and `i` the
+    /// number of input nodes.
+    pub fn get_weights(&self) -> &Matrix<f64> {
+        &self.weights
+    }
+
+    // endregion
+
+    // region Setters
+
+    /// Set the range `(min, max)` into which all outputs of this layer will be clamped after the
+    /// activation function has been applied. If `clip` is `None`, the outputs will not be clamped.
+    ///
+    /// The bounds of the range must not be `NaN` and `min` must not be greater than `max`.
+    /// Otherwise, an [`Error::InvalidRange`] will be returned and the layer will not be changed.
+    ///
+    /// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+    pub fn set_activation_clip(&mut self, clip: Option<(f64, f64)>) -> Result<()> {
+        if let Some((min, max)) = clip {
+            if min.is_nan() || max.is_nan() || min > max {
+                return Err(Error::InvalidRange);
+            }
+        }
+
+        self.activation_clip = clip;
+
+        Ok(())
+    }
+
     // endregion
 
     // region AI
@@ -78,31 +307,254 @@ impl Layer {
     /// Predict an output of this layer for the given input.
     ///
     /// The input matrix must be an `i x 1` matrix where `i` is the number of (input) nodes in this
-    /// layer. Otherwise, [`Error::DimensionMismatch`] will be returned.
+    /// layer. Otherwise, [`Error::ShapeMismatch`] will be returned.
     ///
     /// The output matrix will be a `o x 1` matrix where `o` is the number of outputs of this layer.
     ///
+    /// [`Error::ShapeMismatch`]: ../enum.Error.html#variant.ShapeMismatch
+    pub fn predict(&self, input: &Matrix<f64>) -> Result<Matrix<f64>> {
+        // The input matrix must have one row per input node and only one column.
+        input.ensure_dimensions(self.weights.get_number_of_columns(), 1)?;
+        self.forward(input, false)
+    }
+
+    /// Predict an output of this layer for the given input, ensuring that all intermediate results
+    /// are finite.
+    ///
+    /// This works like [`predict`], but if the input, the weighted sum of the input (before the
+    /// activation function is applied), or the output contains an infinite or `NaN` value, an
+    /// [`Error::NonFiniteValue`] will be returned. Note that the activation function would
+    /// otherwise silently map infinite values to finite ones.
+    ///
+    /// [`predict`]: #method.predict
+    /// [`Error::NonFiniteValue`]: ../enum.Error.html#variant.NonFiniteValue
+    pub fn predict_strict(&self, input: &Matrix<f64>) -> Result<Matrix<f64>> {
+        input.ensure_dimensions(self.weights.get_number_of_columns(), 1)?;
+        self.forward(input, true)
+    }
+
+    /// Compute the output of this layer for the given input, which may contain any number of
+    /// samples, one per column.
+    ///
+    /// The input matrix must be an `i x n` matrix where `i` is the number of input nodes in this
+    /// layer. Otherwise, an [`Error::DimensionMismatch`] will be returned. The output matrix will
+    /// be a `o x n` matrix.
+    ///
+    /// If `strict` is `true`, the input, the weighted sum, and the output will be checked for
+    /// non-finite values.
+    ///
     /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
-    pub fn predict(&self, input: Matrix<f64>) -> Result<Matrix<f64>> {
-        // The input matrix must have only one column.
-        if input.get_number_of_columns() != 1 {
-            return Err(Error::DimensionMismatch);
+    pub(crate) fn forward(&self, input: &Matrix<f64>, strict: bool) -> Result<Matrix<f64>> {
+        if strict && !input.is_finite() {
+            return Err(Error::NonFiniteValue);
         }
 
-        // Multiply the input to the weights (using matrix multiplication), then add the bias.
-        let mut output: Matrix<f64> = self.weights.matrix_mul(&input)?;
+        // Multiply the input to the weights (using matrix multiplication), then add the bias to
+        // each column.
+        let mut output: Matrix<f64> = self.weights.matrix_mul(input)?;
+        output = output.broadcast_add(&self.bias)?;
 
-        // Explicitly call `add` instead of using the operator so it is more legible with the try
-        // operator `?`.
-        output = output.add(&self.bias)?;
+        // Some activation functions (e.g. the sigmoid function) map infinite values to finite ones,
+        // thus check the weighted sum before applying it.
+        if strict && !output.is_finite() {
+            return Err(Error::NonFiniteValue);
+        }
 
         // Apply the activation function.
-        output.map(|element, _row, _column| 1.0 / (1.0 + (-element).exp()));
+        output = self.activation.apply(&output);
+
+        // Clamp the activated outputs if requested.
+        if let Some((min, max)) = self.activation_clip {
+            output.map(|element, _row, _column| element.clamp(min, max));
+        }
+
+        if strict && !output.is_finite() {
+            return Err(Error::NonFiniteValue);
+        }
 
         Ok(output)
     }
 
     // endregion
+
+    // region Training
+
+    /// Add independent noise drawn from the distribution `noise` to each element of the weights
+    /// and the bias of this layer, using the given random number generator `rng`.
+    pub(crate) fn add_noise<R>(&mut self, noise: &Normal, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        self.weights
+            .map_ref_mut(|element, _row, _column| *element += noise.sample(rng));
+        self.bias
+            .map_ref_mut(|element, _row, _column| *element += noise.sample(rng));
+    }
+
+    /// Update the parameters of this layer with the given gradients, i.e. subtract the gradients
+    /// scaled by the `learning_rate` from the weights and the bias, respectively:
+    ///
+    /// ```text
+    /// weights = weights - learning_rate * weight_gradient
+    /// bias = bias - learning_rate * bias_gradient
+    /// ```
+    ///
+    /// This makes it possible to implement custom optimizers outside of this crate.
+    ///
+    /// The `weight_gradient` must have the same dimensions as the weights and the `bias_gradient`
+    /// must have the same dimensions as the bias. Otherwise, [`Error::ShapeMismatch`] will be
+    /// returned and the layer will not be changed.
+    ///
+    /// [`Error::ShapeMismatch`]: ../enum.Error.html#variant.ShapeMismatch
+    pub fn apply_gradient(
+        &mut self,
+        weight_gradient: &Matrix<f64>,
+        bias_gradient: &Matrix<f64>,
+        learning_rate: f64,
+    ) -> Result<()> {
+        // Check both gradients before changing anything.
+        weight_gradient.ensure_dimensions(
+            self.weights.get_number_of_rows(),
+            self.weights.get_number_of_columns(),
+        )?;
+        bias_gradient.ensure_dimensions(
+            self.bias.get_number_of_rows(),
+            self.bias.get_number_of_columns(),
+        )?;
+
+        self.weights = (&self.weights).sub(&(weight_gradient * learning_rate))?;
+        self.bias = (&self.bias).sub(&(bias_gradient * learning_rate))?;
+
+        Ok(())
+    }
+
+    /// Replace the weights of this layer with a `o x input_nodes` matrix initialized according to
+    /// the initialization strategy the layer has been created with, using the given random number
+    /// generator `rng`, e.g. after the preceding layer has changed. The bias will not be changed.
+    ///
+    /// If the new weights would be too large, an [`Error::DimensionsTooLarge`] will be returned and
+    /// the layer will not be changed.
+    ///
+    /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
+    pub(crate) fn reset_input_nodes<R>(
+        &mut self,
+        input_nodes: NonZeroUsize,
+        rng: &mut R,
+    ) -> Result<()>
+    where
+        R: Rng + ?Sized,
+    {
+        let (_, output_nodes): (NonZeroUsize, NonZeroUsize) = self.get_dimensions()?;
+        self.weights = self
+            .init_strategy
+            .initialize_weights(input_nodes, output_nodes, rng)?;
+
+        Ok(())
+    }
+
+    /// Re-initialize the weights and the bias of this layer according to the initialization
+    /// strategy the layer has been created with, using the given random number generator `rng`.
+    ///
+    /// If the new parameters cannot be created, the error will be returned and the layer will not
+    /// be changed.
+    pub(crate) fn reset_parameters<R>(&mut self, rng: &mut R) -> Result<()>
+    where
+        R: Rng + ?Sized,
+    {
+        let (input_nodes, output_nodes): (NonZeroUsize, NonZeroUsize) = self.get_dimensions()?;
+        let (weights, bias): (Matrix<f64>, Matrix<f64>) =
+            self.init_strategy
+                .initialize(input_nodes, output_nodes, rng)?;
+
+        self.weights = weights;
+        self.bias = bias;
+
+        Ok(())
+    }
+
+    /// Multiply the weights and the bias of this layer by the given `factor` in place.
+    pub fn scale_parameters(&mut self, factor: f64) {
+        self.weights.scale_in_place(factor);
+        self.bias.scale_in_place(factor);
+    }
+
+    // endregion
+
+    // region Serialization
+
+    /// Read a layer that has been written with [`write_to`] from the given `reader`.
+    ///
+    /// If the bias does not have one row per output node and a single column, an
+    /// [`Error::DimensionMismatch`] will be returned. If the data is not valid otherwise, an
+    /// [`Error::InvalidFormat`] or [`Error::InvalidRange`] (for an invalid activation clip range)
+    /// will be returned. Errors while reading will be returned as an [`Error::Io`].
+    ///
+    /// [`write_to`]: #method.write_to
+    /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+    /// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+    /// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub(crate) fn read_from<R>(reader: &mut R) -> Result<Layer>
+    where
+        R: Read + ?Sized,
+    {
+        let mut flags: [u8; 3] = [0; 3];
+        reader.read_exact(&mut flags)?;
+        let activation: Activation = Activation::from_id(flags[0])?;
+        let init_strategy: InitStrategy = InitStrategy::from_id(flags[1])?;
+        let activation_clip: Option<(f64, f64)> = match flags[2] {
+            0 => None,
+            1 => {
+                let mut bytes: [u8; 8] = [0; 8];
+                reader.read_exact(&mut bytes)?;
+                let min: f64 = f64::from_le_bytes(bytes);
+                reader.read_exact(&mut bytes)?;
+                let max: f64 = f64::from_le_bytes(bytes);
+                Some((min, max))
+            }
+            _ => return Err(Error::InvalidFormat),
+        };
+
+        let weights: Matrix<f64> = Matrix::from_npy(&mut *reader)?;
+        let bias: Matrix<f64> = Matrix::from_npy(&mut *reader)?;
+
+        let mut layer = Layer::from_parameters(weights, bias)?;
+        layer.activation = activation;
+        layer.init_strategy = init_strategy;
+        layer.set_activation_clip(activation_clip)?;
+
+        Ok(layer)
+    }
+
+    /// Write the activation function, the initialization strategy, the activation clip range, the
+    /// weights, and the bias of this layer to the given `writer`. The weights and the bias are
+    /// written in the NumPy `.npy` format.
+    ///
+    /// Errors while writing will be returned as an [`Error::Io`].
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub(crate) fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        let activation: u8 = self.activation.to_id();
+        let init_strategy: u8 = self.init_strategy.to_id();
+        match self.activation_clip {
+            Some((min, max)) => {
+                writer.write_all(&[activation, init_strategy, 1])?;
+                writer.write_all(&min.to_le_bytes())?;
+                writer.write_all(&max.to_le_bytes())?;
+            }
+            None => writer.write_all(&[activation, init_strategy, 0])?,
+        }
+
+        self.weights.to_npy(&mut *writer)?;
+        self.bias.to_npy(&mut *writer)?;
+
+        Ok(())
+    }
+
+    // endregion
 }
 
 #[cfg(test)]
@@ -117,13 +569,61 @@ mod tests {
 
     // region Initialization
 
+    /// Test creating a new layer from known parameters.
+    #[test]
+    fn from_parameters_valid_dimensions() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let weights: Matrix<f64> = Matrix::new(output_nodes, input_nodes, 0.5).unwrap();
+        let bias: Matrix<f64> = Matrix::new(output_nodes, one, 0.1).unwrap();
+
+        let layer_result: Result<Layer> = Layer::from_parameters(weights.clone(), bias.clone());
+        assert!(layer_result.is_ok());
+
+        let layer: Layer = layer_result.unwrap();
+        assert_eq!(layer.weights, weights);
+        assert_eq!(layer.bias, bias);
+        assert_eq!(layer.activation, Activation::Sigmoid);
+        assert!(layer.activation_clip.is_none());
+
+        // Same parameters as in `predict_valid_dimensions`.
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = layer.predict(&input).unwrap();
+        assert_relative_eq!(
+            *prediction.as_slice(),
+            [0.851_952_801_968_310_6, 0.851_952_801_968_310_6]
+        );
+    }
+
+    /// Test creating a new layer from parameters with inconsistent dimensions.
+    #[test]
+    fn from_parameters_mismatching_dimensions() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let two = NonZeroUsize::new(2).unwrap();
+        let three = NonZeroUsize::new(3).unwrap();
+        let weights: Matrix<f64> = Matrix::new(two, three, 0.5).unwrap();
+
+        for bias in &[
+            Matrix::new(three, one, 0.1).unwrap(),
+            Matrix::new(two, two, 0.1).unwrap(),
+        ] {
+            let layer_result: Result<Layer> = Layer::from_parameters(weights.clone(), bias.clone());
+            assert!(
+                matches!(layer_result, Err(Error::DimensionMismatch)),
+                "Expected error Error::DimensionMismatch not satisfied."
+            );
+        }
+    }
+
     /// Test creating a new layer when the size does not exceed the maximum size.
     #[test]
     fn new_valid_size() {
         let input_nodes = NonZeroUsize::new(2).unwrap();
         let output_nodes = NonZeroUsize::new(3).unwrap();
 
-        let layer_result: Result<Layer> = Layer::new(input_nodes, output_nodes);
+        let layer_result: Result<Layer> =
+            Layer::new(input_nodes, output_nodes, Activation::Sigmoid);
         assert!(layer_result.is_ok());
 
         let layer: Layer = layer_result.unwrap();
@@ -135,16 +635,75 @@ mod tests {
         // The bias is `output x 1`, i.e. `3x1`.
         assert_eq!(layer.bias.get_number_of_rows(), output_nodes.get());
         assert_eq!(layer.bias.get_number_of_columns(), 1);
+
+        assert_eq!(layer.activation, Activation::Sigmoid);
+
+        // The outputs are not clamped by default.
+        assert!(layer.activation_clip.is_none());
     }
 
     /// Test creating a new layer when the size exceeds the maximum size.
     #[test]
     fn new_invalid_size() {
-        let input_nodes = NonZeroUsize::new(::std::usize::MAX).unwrap();
+        let input_nodes = NonZeroUsize::new(usize::MAX).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layer_result: Result<Layer> =
+            Layer::new(input_nodes, output_nodes, Activation::Sigmoid);
+
+        assert!(
+            matches!(layer_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test that creating layers from the same seed results in the same parameters.
+    #[test]
+    fn new_seeded() {
+        let input_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(3).unwrap();
+
+        let first: Layer =
+            Layer::new_seeded(input_nodes, output_nodes, Activation::ReLU, 7).unwrap();
+        let second: Layer =
+            Layer::new_seeded(input_nodes, output_nodes, Activation::ReLU, 7).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.weights.get_number_of_rows(), output_nodes.get());
+        assert_eq!(first.weights.get_number_of_columns(), input_nodes.get());
+        assert_eq!(first.activation, Activation::ReLU);
+
+        let other: Layer =
+            Layer::new_seeded(input_nodes, output_nodes, Activation::ReLU, 8).unwrap();
+        assert_ne!(first.weights, other.weights);
+    }
+
+    /// Test creating a new layer with each initialization strategy.
+    #[test]
+    fn new_with_init() {
+        let input_nodes = NonZeroUsize::new(8).unwrap();
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
-        let layer_result: Result<Layer> = Layer::new(input_nodes, output_nodes);
+        for &strategy in &[
+            InitStrategy::Uniform01,
+            InitStrategy::XavierUniform,
+            InitStrategy::HeNormal,
+        ] {
+            let layer: Layer =
+                Layer::new_with_init(input_nodes, output_nodes, Activation::ReLU, strategy)
+                    .unwrap();
+            assert_eq!(layer.weights.get_number_of_rows(), output_nodes.get());
+            assert_eq!(layer.weights.get_number_of_columns(), input_nodes.get());
+            assert_eq!(layer.bias.get_number_of_rows(), output_nodes.get());
+            assert_eq!(layer.activation, Activation::ReLU);
+        }
 
+        let input_nodes = NonZeroUsize::new(usize::MAX).unwrap();
+        let layer_result: Result<Layer> = Layer::new_with_init(
+            input_nodes,
+            output_nodes,
+            Activation::ReLU,
+            InitStrategy::HeNormal,
+        );
         assert!(
             matches!(layer_result, Err(Error::DimensionsTooLarge)),
             "Expected error Error::DimensionsTooLarge not satisfied."
@@ -155,13 +714,33 @@ mod tests {
 
     // region Getters
 
+    /// Test getting the activation function of the layer.
+    #[test]
+    fn get_activation() {
+        let input_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(3).unwrap();
+
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Tanh).unwrap();
+        assert_eq!(layer.get_activation(), Activation::Tanh);
+    }
+
+    /// Test getting the bias of the layer.
+    #[test]
+    fn get_bias() {
+        let input_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(3).unwrap();
+
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        assert_eq!(layer.get_bias(), &layer.bias);
+    }
+
     /// Test getting the number of input nodes of the layer.
     #[test]
     fn get_number_of_input_nodes() {
         let input_nodes = NonZeroUsize::new(5).unwrap();
         let output_nodes = NonZeroUsize::new(3).unwrap();
 
-        let layer = Layer::new(input_nodes, output_nodes).unwrap();
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
         assert_eq!(layer.get_number_of_input_nodes(), input_nodes.get());
     }
 
@@ -171,10 +750,58 @@ mod tests {
         let input_nodes = NonZeroUsize::new(4).unwrap();
         let output_nodes = NonZeroUsize::new(9).unwrap();
 
-        let layer = Layer::new(input_nodes, output_nodes).unwrap();
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
         assert_eq!(layer.get_number_of_output_nodes(), output_nodes.get());
     }
 
+    /// Test getting the weights of the layer.
+    #[test]
+    fn get_weights() {
+        let input_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(3).unwrap();
+
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        assert_eq!(layer.get_weights(), &layer.weights);
+    }
+
+    // endregion
+
+    // region Setters
+
+    /// Test setting a valid activation clip range.
+    #[test]
+    fn set_activation_clip_valid_range() {
+        let nodes = NonZeroUsize::new(2).unwrap();
+        let mut layer = Layer::new(nodes, nodes, Activation::Sigmoid).unwrap();
+
+        assert!(layer.set_activation_clip(Some((0.0, 0.9))).is_ok());
+        assert_eq!(layer.activation_clip, Some((0.0, 0.9)));
+
+        assert!(layer.set_activation_clip(None).is_ok());
+        assert!(layer.activation_clip.is_none());
+    }
+
+    /// Test setting an invalid activation clip range.
+    #[test]
+    fn set_activation_clip_invalid_range() {
+        let nodes = NonZeroUsize::new(2).unwrap();
+        let mut layer = Layer::new(nodes, nodes, Activation::Sigmoid).unwrap();
+
+        let result: Result<()> = layer.set_activation_clip(Some((0.9, 0.0)));
+        assert!(
+            matches!(result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+
+        let result: Result<()> = layer.set_activation_clip(Some((f64::NAN, 1.0)));
+        assert!(
+            matches!(result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+
+        assert!(layer.activation_clip.is_none());
+    }
+
     // endregion
 
     // region AI
@@ -187,12 +814,12 @@ mod tests {
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
         // Create a layer, but for testing, use known weights and biases.
-        let mut layer = Layer::new(input_nodes, output_nodes).unwrap();
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
         layer.weights.map(|_element, _row, _column| 0.5);
         layer.bias.map(|_element, _row, _column| 0.1);
 
         let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
-        let prediction_result: Result<Matrix<f64>> = layer.predict(input);
+        let prediction_result: Result<Matrix<f64>> = layer.predict(&input);
         assert!(prediction_result.is_ok());
 
         let prediction: Matrix<f64> = prediction_result.unwrap();
@@ -204,19 +831,96 @@ mod tests {
         );
     }
 
+    /// Test the prediction of this layer with the rectified linear unit as activation function.
+    #[test]
+    fn predict_relu() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        // Positive weights for the first output node, negative ones for the second.
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::ReLU).unwrap();
+        layer
+            .weights
+            .map(|_element, row, _column| if row == 0 { 0.5 } else { -0.5 });
+        layer.bias.map(|_element, _row, _column| 0.1);
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = layer.predict(&input).unwrap();
+        assert_relative_eq!(*prediction.as_slice(), [1.75, 0.0]);
+    }
+
+    /// Test that the prediction of this layer never exceeds the activation clip range.
+    #[test]
+    fn predict_with_activation_clip() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        // Use large weights so the activation is almost `1.0` without clamping.
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        layer.weights.map(|_element, _row, _column| 10.0);
+        layer.set_activation_clip(Some((0.0, 0.9))).unwrap();
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = layer.predict(&input).unwrap();
+        for element in prediction.as_slice() {
+            assert!(*element >= 0.0);
+            assert!(*element <= 0.9);
+        }
+    }
+
+    /// Test the strict prediction of this layer with weights that lead to an infinite weighted sum.
+    #[test]
+    fn predict_strict_non_finite() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        // Use pathological weights whose weighted sum overflows to infinity.
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        layer.weights.map(|_element, _row, _column| 1e308);
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+
+        // Without the strict check, the infinite sum will silently be mapped to `1.0`.
+        let prediction: Matrix<f64> = layer.predict(&input).unwrap();
+        assert_relative_eq!(*prediction.as_slice(), [1.0, 1.0]);
+
+        let prediction_result: Result<Matrix<f64>> = layer.predict_strict(&input);
+        assert!(
+            matches!(prediction_result, Err(Error::NonFiniteValue)),
+            "Expected error Error::NonFiniteValue not satisfied."
+        );
+    }
+
+    /// Test the strict prediction of this layer with valid weights.
+    #[test]
+    fn predict_strict_valid() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+
+        let expected: Matrix<f64> = layer.predict(&input).unwrap();
+        assert_eq!(layer.predict_strict(&input).unwrap(), expected);
+    }
+
     /// Test the prediction of this layer if the input matrix has too many columns.
     #[test]
     fn predict_too_many_input_columns() {
         let input_nodes = NonZeroUsize::new(3).unwrap();
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
-        let layer = Layer::new(input_nodes, output_nodes).unwrap();
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
         let input: Matrix<f64> = Matrix::new(input_nodes, output_nodes, 1.0).unwrap();
-        let prediction_result: Result<Matrix<f64>> = layer.predict(input);
+        let prediction_result: Result<Matrix<f64>> = layer.predict(&input);
 
         assert!(
-            matches!(prediction_result, Err(Error::DimensionMismatch)),
-            "Expected error Error::DimensionMismatch not satisfied."
+            matches!(prediction_result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
         );
     }
 
@@ -227,15 +931,91 @@ mod tests {
         let input_nodes = NonZeroUsize::new(3).unwrap();
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
-        let layer = Layer::new(input_nodes, output_nodes).unwrap();
+        let layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
         let input: Matrix<f64> = Matrix::new(output_nodes, one, 1.0).unwrap();
-        let prediction_result: Result<Matrix<f64>> = layer.predict(input);
+        let prediction_result: Result<Matrix<f64>> = layer.predict(&input);
 
         assert!(
-            matches!(prediction_result, Err(Error::DimensionMismatch)),
-            "Expected error Error::DimensionMismatch not satisfied."
+            matches!(prediction_result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
         );
     }
 
     // endregion
+
+    // region Training
+
+    /// Test applying gradients of the correct dimensions to a layer.
+    #[test]
+    fn apply_gradient_matching_dimensions() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        layer.weights.map(|_element, _row, _column| 0.5);
+        layer.bias.map(|_element, _row, _column| 0.1);
+
+        let weight_data: [f64; 6] = [1.0, -2.0, 0.5, 0.0, 4.0, -1.0];
+        let weight_gradient: Matrix<f64> =
+            Matrix::from_slice(output_nodes, input_nodes, &weight_data).unwrap();
+        let bias_gradient: Matrix<f64> =
+            Matrix::from_slice(output_nodes, one, &[2.0, -3.0]).unwrap();
+
+        assert!(layer
+            .apply_gradient(&weight_gradient, &bias_gradient, 0.1)
+            .is_ok());
+        assert_relative_eq!(*layer.weights.as_slice(), [0.4, 0.7, 0.45, 0.5, 0.1, 0.6]);
+        assert_relative_eq!(*layer.bias.as_slice(), [-0.1, 0.4]);
+    }
+
+    /// Test applying gradients of the wrong dimensions to a layer.
+    #[test]
+    fn apply_gradient_mismatching_dimensions() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        let original: Layer = layer.clone();
+
+        // The weight gradient is transposed.
+        let weight_gradient: Matrix<f64> = Matrix::new(input_nodes, output_nodes, 1.0).unwrap();
+        let bias_gradient: Matrix<f64> = Matrix::new(output_nodes, one, 1.0).unwrap();
+        let result: Result<()> = layer.apply_gradient(&weight_gradient, &bias_gradient, 0.1);
+        assert!(
+            matches!(result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+
+        // The bias gradient has too many rows.
+        let weight_gradient: Matrix<f64> = Matrix::new(output_nodes, input_nodes, 1.0).unwrap();
+        let bias_gradient: Matrix<f64> = Matrix::new(input_nodes, one, 1.0).unwrap();
+        let result: Result<()> = layer.apply_gradient(&weight_gradient, &bias_gradient, 0.1);
+        assert!(
+            matches!(result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+
+        // The layer has not been changed.
+        assert_eq!(layer, original);
+    }
+
+    /// Test scaling the parameters of a layer.
+    #[test]
+    fn scale_parameters() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let mut layer = Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        let original: Layer = layer.clone();
+
+        layer.scale_parameters(-2.0);
+        let expected_weights: Matrix<f64> = &original.weights * -2.0;
+        let expected_bias: Matrix<f64> = &original.bias * -2.0;
+        assert_relative_eq!(*layer.weights.as_slice(), *expected_weights.as_slice());
+        assert_relative_eq!(*layer.bias.as_slice(), *expected_bias.as_slice());
+    }
+
+    // endregion
 }
@@ -0,0 +1,255 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Definition and implementation of schedules adjusting the learning rate during training.
+
+use std::f64::consts::PI;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::num::NonZeroUsize;
+
+use crate::Error;
+use crate::Result;
+
+/// A schedule determining the learning rate for each epoch of a training loop.
+pub trait LrSchedule {
+    /// Get the learning rate for the given `epoch`, starting at `0`.
+    fn rate(&self, epoch: usize) -> f64;
+}
+
+/// A cosine annealing schedule with warm restarts.
+///
+/// Within each period, the learning rate decreases from `max_lr` to `min_lr` along a half cosine
+/// wave. At the start of the next period, it is reset to `max_lr`. The learning rate for an epoch
+/// is
+///
+/// ```text
+/// min_lr + 0.5 * (max_lr - min_lr) * (1 + cos(pi * (epoch % period) / period))
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::CosineAnnealing;
+/// use reural_network::LrSchedule;
+///
+/// let period: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+/// let schedule: CosineAnnealing = CosineAnnealing::new(0.001, 0.1, period).unwrap();
+///
+/// assert_eq!(schedule.rate(0), 0.1);
+/// assert!(schedule.rate(9) < schedule.rate(1));
+/// assert_eq!(schedule.rate(10), 0.1);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CosineAnnealing {
+    /// The learning rate at the end of each period.
+    min_lr: f64,
+
+    /// The learning rate at the start of each period.
+    max_lr: f64,
+
+    /// The number of epochs after which the learning rate is reset to `max_lr`.
+    period: NonZeroUsize,
+}
+
+impl CosineAnnealing {
+    // region Initialization
+
+    /// Create a new cosine annealing schedule decreasing the learning rate from `max_lr` to
+    /// `min_lr` within each `period` of epochs.
+    ///
+    /// Both learning rates must be finite and `min_lr` must not be greater than `max_lr`.
+    /// Otherwise, an [`Error::InvalidRange`] will be returned.
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn new(min_lr: f64, max_lr: f64, period: NonZeroUsize) -> Result<CosineAnnealing> {
+        if !min_lr.is_finite() || !max_lr.is_finite() || min_lr > max_lr {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(CosineAnnealing {
+            min_lr,
+            max_lr,
+            period,
+        })
+    }
+
+    // endregion
+}
+
+impl LrSchedule for CosineAnnealing {
+    /// Get the learning rate for the given `epoch`, starting at `0`.
+    fn rate(&self, epoch: usize) -> f64 {
+        let progress: f64 = (epoch % self.period.get()) as f64 / self.period.get() as f64;
+        self.min_lr + 0.5 * (self.max_lr - self.min_lr) * (1.0 + (PI * progress).cos())
+    }
+}
+
+/// A linear warmup phase in front of another schedule, e.g. to stabilize the start of a training.
+///
+/// During the first `warmup_epochs` epochs, the learning rate increases linearly from `0.0` to
+/// `peak`, which it reaches at the epoch `warmup_epochs`. Afterwards, the wrapped schedule `then`
+/// determines the learning rate, starting with its epoch `1` at the epoch `warmup_epochs + 1`.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::CosineAnnealing;
+/// use reural_network::LrSchedule;
+/// use reural_network::Warmup;
+///
+/// let period: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+/// let cosine: CosineAnnealing = CosineAnnealing::new(0.001, 0.1, period).unwrap();
+/// let warmup_epochs: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+/// let schedule: Warmup = Warmup::new(warmup_epochs, 0.1, Box::new(cosine)).unwrap();
+///
+/// assert_eq!(schedule.rate(0), 0.0);
+/// assert!(schedule.rate(2) < schedule.rate(3));
+/// assert_eq!(schedule.rate(5), 0.1);
+/// assert_eq!(schedule.rate(7), cosine.rate(2));
+/// ```
+pub struct Warmup {
+    /// The number of epochs until the learning rate reaches `peak`.
+    warmup_epochs: NonZeroUsize,
+
+    /// The learning rate at the end of the warmup phase.
+    peak: f64,
+
+    /// The schedule determining the learning rate after the warmup phase.
+    then: Box<dyn LrSchedule>,
+}
+
+impl Warmup {
+    // region Initialization
+
+    /// Create a new warmup schedule increasing the learning rate from `0.0` to `peak` within
+    /// `warmup_epochs`, then continuing with the schedule `then`.
+    ///
+    /// The `peak` must be finite and not negative. Otherwise, an [`Error::InvalidRange`] will be
+    /// returned.
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn new(
+        warmup_epochs: NonZeroUsize,
+        peak: f64,
+        then: Box<dyn LrSchedule>,
+    ) -> Result<Warmup> {
+        if !peak.is_finite() || peak < 0.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(Warmup {
+            warmup_epochs,
+            peak,
+            then,
+        })
+    }
+
+    // endregion
+}
+
+impl Debug for Warmup {
+    /// Format the warmup phase. The wrapped schedule is not required to implement `Debug`, thus
+    /// it is omitted.
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        formatter
+            .debug_struct("Warmup")
+            .field("warmup_epochs", &self.warmup_epochs)
+            .field("peak", &self.peak)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LrSchedule for Warmup {
+    /// Get the learning rate for the given `epoch`, starting at `0`.
+    fn rate(&self, epoch: usize) -> f64 {
+        let warmup_epochs: usize = self.warmup_epochs.get();
+        if epoch <= warmup_epochs {
+            self.peak * epoch as f64 / warmup_epochs as f64
+        } else {
+            self.then.rate(epoch - warmup_epochs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    /// Test the learning rates at the start and in the middle of a period.
+    #[test]
+    fn cosine_annealing_rate() {
+        let period: NonZeroUsize = NonZeroUsize::new(8).unwrap();
+        let schedule: CosineAnnealing = CosineAnnealing::new(0.01, 0.09, period).unwrap();
+
+        assert_relative_eq!(schedule.rate(0), 0.09);
+        assert_relative_eq!(schedule.rate(4), 0.05);
+        assert!(schedule.rate(7) > 0.01);
+        assert!(schedule.rate(7) < schedule.rate(6));
+
+        // The schedule restarts after each period.
+        assert_relative_eq!(schedule.rate(8), 0.09);
+        assert_relative_eq!(schedule.rate(12), 0.05);
+    }
+
+    /// Test the learning rates during and after the warmup phase.
+    #[test]
+    fn warmup_rate() {
+        let period: NonZeroUsize = NonZeroUsize::new(8).unwrap();
+        let cosine: CosineAnnealing = CosineAnnealing::new(0.01, 0.09, period).unwrap();
+        let warmup_epochs: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let schedule: Warmup = Warmup::new(warmup_epochs, 0.2, Box::new(cosine)).unwrap();
+
+        assert!(schedule.rate(0) < 1e-12);
+        assert_relative_eq!(schedule.rate(1), 0.05);
+        assert_relative_eq!(schedule.rate(2), 0.1);
+        assert_relative_eq!(schedule.rate(4), 0.2);
+
+        // After the warmup phase, the inner schedule takes over.
+        for epoch in 5..20 {
+            assert_relative_eq!(schedule.rate(epoch), cosine.rate(epoch - 4));
+        }
+
+        assert_eq!(
+            format!("{:?}", schedule),
+            "Warmup { warmup_epochs: 4, peak: 0.2, .. }"
+        );
+    }
+
+    /// Test creating a warmup schedule with an invalid peak learning rate.
+    #[test]
+    fn warmup_invalid_peak() {
+        let period: NonZeroUsize = NonZeroUsize::new(8).unwrap();
+        for &peak in &[-0.1, f64::NAN, f64::INFINITY] {
+            let cosine: CosineAnnealing = CosineAnnealing::new(0.01, 0.09, period).unwrap();
+            let schedule_result: Result<Warmup> = Warmup::new(period, peak, Box::new(cosine));
+            assert!(
+                matches!(schedule_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
+
+    /// Test creating a cosine annealing schedule with invalid learning rates.
+    #[test]
+    fn cosine_annealing_invalid_rates() {
+        let period: NonZeroUsize = NonZeroUsize::new(8).unwrap();
+        for &(min_lr, max_lr) in &[(0.1, 0.01), (f64::NAN, 0.1), (0.0, f64::INFINITY)] {
+            let schedule_result: Result<CosineAnnealing> =
+                CosineAnnealing::new(min_lr, max_lr, period);
+            assert!(
+                matches!(schedule_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
+}
@@ -7,17 +7,37 @@
 
 //! A simple neural network implementation.
 
+pub use self::activation::Activation;
+pub use self::cross_validation::k_fold_indices;
+pub use self::early_stopping::EarlyStopping;
 pub use self::error::Error;
 pub use self::error::Result;
-use self::layer::Layer;
+pub use self::gradient_accumulator::GradientAccumulator;
+pub use self::init_strategy::InitStrategy;
+pub use self::layer::Layer;
+pub use self::learning_rate_schedule::CosineAnnealing;
+pub use self::learning_rate_schedule::LrSchedule;
+pub use self::learning_rate_schedule::Warmup;
 use self::matrix::Matrix;
 pub use self::neural_network::NeuralNetwork;
 pub use self::neural_network_builder::NeuralNetworkBuilder;
+pub use self::random::clear_default_seed;
+pub use self::random::set_default_seed;
+pub use self::tied_layer::TiedLayer;
 
 // TODO: Make the matrix module private once main.rs doesn't use it anymore.
+pub mod activation;
+mod cross_validation;
+mod early_stopping;
 mod error;
+mod gradient_accumulator;
+mod init_strategy;
 mod layer;
+mod learning_rate_schedule;
 mod macros;
 pub mod matrix;
+pub mod metrics;
 mod neural_network;
 mod neural_network_builder;
+mod random;
+mod tied_layer;
@@ -118,3 +118,50 @@ macro_rules! access_variable_as_string {
         concat!("&", stringify!($variable))
     };
 }
+
+/// Get the `stringify!`ed assertion that the given result matrix equals the expected values in a
+/// documentation example. Floating point results are compared within a small tolerance to prevent
+/// rounding differences between platforms from failing the example, all other results exactly.
+///
+/// # Parameters
+///
+/// * `$data_type`: The type `T` of the data in the result matrix.
+/// * `$result`: The result matrix as a string literal, e.g. `"result.unwrap()"`.
+/// * `$expected_result`: An array of expected values. The example must define the dimensions of
+///                       the result matrix as `rows` and `columns`.
+///
+/// # Example
+///
+/// ```
+/// # use reural_network::assert_result_as_string;
+/// # fn main() {
+/// let exact = assert_result_as_string!(u8, "result", [1, 2]);
+/// assert_eq!(exact, "assert_eq!(result.as_slice(), &[1, 2]);\n");
+/// # }
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_result_as_string {
+    // Compare floating point results within a tolerance.
+    (f64, $result:expr, $expected_result:expr) => {
+        concat!(
+            "let expected = Matrix::from_slice(rows, columns, &",
+            stringify!($expected_result),
+            ").unwrap();\n",
+            "assert!(reural_network::matrix::matrices_within(&",
+            $result,
+            ", &expected, 1e-10));\n"
+        )
+    };
+
+    // Compare all other results exactly.
+    ($data_type:tt, $result:expr, $expected_result:expr) => {
+        concat!(
+            "assert_eq!(",
+            $result,
+            ".as_slice(), &",
+            stringify!($expected_result),
+            ");\n"
+        )
+    };
+}
@@ -495,11 +495,9 @@ macro_rules! doc_scalar_assign_operator {
             "matrix ",
             stringify!($operator),
             " other;\n",
-            "assert_eq!(matrix.as_slice(), &",
-            stringify!($expected_result),
-            ");\n",
+            $crate::assert_result_as_string!($data_type, "matrix", $expected_result),
             "```"
-        );
+        )
     };
 }
 
@@ -116,8 +116,9 @@ macro_rules! impl_element_wise_binary_operators {
             Mul,
             mul,
             *,
-            "Multiply each element in `self` to the corresponding element in `other`, i.e.\
-             calculate the Hadamard product of `self` and `other`.",
+            "Multiply each element in `self` to the corresponding element in `other`, i.e. \
+             calculate the Hadamard product of `self` and `other`. This is not the matrix \
+             product, which is computed by `Matrix::mm`.",
             i64,
             [10, 333, -12,   7,  -30, 0],
             [ 2,   3,  -4,  -7,   10, 3],
@@ -336,7 +337,7 @@ macro_rules! impl_element_wise_binary_operator {
         impl<T> $trait<$crate::specify_matrix_type!($rhs_access)>
         for $crate::specify_matrix_type!($lhs_access)
         where
-            T: $trait<Output = T> + Copy,
+            T: $trait<Output = T> + Clone,
         {
             type Output = Result<Matrix<T>>;
 
@@ -349,18 +350,20 @@ macro_rules! impl_element_wise_binary_operator {
                     return Err(Error::DimensionMismatch);
                 }
 
-                let mut result: Matrix<T> = Matrix {
+                // If a matrix is owned, its data can be reused for the result. Otherwise, it must
+                // be cloned. Both matrices have the same dimensions, so the elements are zipped in
+                // the same order.
+                let data: Vec<T> = $crate::take_or_clone_matrix_data!($lhs_access self)
+                    .into_iter()
+                    .zip($crate::take_or_clone_matrix_data!($rhs_access other))
+                    .map(|(element, other_element)| element $operator other_element)
+                    .collect();
+
+                Ok(Matrix {
                     rows: self.rows,
                     columns: self.columns,
-                    data: self.data.clone(),
-                };
-
-                // The row and column are given by the map method and are thus valid.
-                result.map(|element, row, column| unsafe {
-                    element $operator other.get_unchecked(row, column)
-                });
-
-                Ok(result)
+                    data,
+                })
             }
         }
     };
@@ -772,13 +775,11 @@ macro_rules! doc_element_wise_binary_operator {
             " ",
             $crate::access_variable_as_string!($rhs_access other),
             ";\n",
-            "assert_eq!(result.unwrap().as_slice(), &",
-            stringify!($expected_result),
-            ");\n",
+            $crate::assert_result_as_string!($data_type, "result.unwrap()", $expected_result),
             "```",
             "\n\n",
             "[`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch"
-        );
+        )
     };
 }
 
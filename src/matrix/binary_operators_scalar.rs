@@ -633,11 +633,9 @@ macro_rules! doc_scalar_binary_operator {
             " ",
             stringify!($operator),
             " other;\n",
-            "assert_eq!(result.as_slice(), &",
-            stringify!($expected_result),
-            ");\n",
+            $crate::assert_result_as_string!($data_type, "result", $expected_result),
             "```"
-        );
+        )
     };
 }
 
@@ -0,0 +1,177 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Definition and implementation of the builder for creating matrices row by row.
+
+use std::num::NonZeroUsize;
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Result;
+
+/// A builder for creating matrices row by row, without knowing the number of rows in advance.
+///
+/// # Example
+///
+/// Build a `2x3` matrix from two rows:
+///
+/// ```
+/// use reural_network::matrix::Matrix;
+/// use reural_network::matrix::MatrixBuilder;
+///
+/// let mut builder: MatrixBuilder<usize> = MatrixBuilder::new();
+/// builder.push_row(vec![0, 1, 2]).unwrap();
+/// builder.push_row(vec![3, 4, 5]).unwrap();
+///
+/// let matrix: Matrix<usize> = builder.build().unwrap();
+/// assert_eq!(matrix.get_number_of_rows(), 2);
+/// assert_eq!(matrix.get_number_of_columns(), 3);
+/// assert_eq!(matrix.as_slice(), &[0, 1, 2, 3, 4, 5]);
+/// ```
+#[derive(Debug)]
+pub struct MatrixBuilder<T> {
+    /// The number of columns of the matrix that will be built.
+    ///
+    /// This is `None` until the first row has been pushed.
+    columns: Option<NonZeroUsize>,
+
+    /// The number of rows that have been pushed so far.
+    rows: usize,
+
+    /// The data of all rows that have been pushed so far, in row-major format.
+    data: Vec<T>,
+}
+
+impl<T> MatrixBuilder<T> {
+    /// Start building a new matrix without any rows.
+    ///
+    /// To finish the building process and create the actual matrix, call [`build`].
+    ///
+    /// [`build`]: #method.build
+    pub fn new() -> Self {
+        Self {
+            columns: None,
+            rows: 0,
+            data: Vec::new(),
+        }
+    }
+
+    /// Append the given `row` to the matrix.
+    ///
+    /// The first row determines the number of columns of the matrix. Each row must contain at
+    /// least one element and all rows must have the same length. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned and the row will not be added.
+    ///
+    /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+    pub fn push_row(&mut self, mut row: Vec<T>) -> Result<()> {
+        let columns: NonZeroUsize = match NonZeroUsize::new(row.len()) {
+            Some(columns) => columns,
+            None => return Err(Error::DimensionMismatch),
+        };
+
+        match self.columns {
+            Some(expected_columns) if expected_columns != columns => {
+                return Err(Error::DimensionMismatch)
+            }
+            Some(_) => {}
+            None => self.columns = Some(columns),
+        }
+
+        self.data.append(&mut row);
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    /// Finish the building process and create a matrix from all rows that have been pushed.
+    ///
+    /// At least one row must have been pushed. Otherwise, an [`Error::EmptyMatrix`] will be
+    /// returned.
+    ///
+    /// [`Error::EmptyMatrix`]: ../enum.Error.html#variant.EmptyMatrix
+    pub fn build(self) -> Result<Matrix<T>> {
+        match (NonZeroUsize::new(self.rows), self.columns) {
+            (Some(rows), Some(columns)) => {
+                // Each pushed row has exactly `columns` elements, so the data vector has the
+                // correct length.
+                Ok(Matrix::from_vec_unchecked(rows, columns, self.data))
+            }
+            _ => Err(Error::EmptyMatrix),
+        }
+    }
+}
+
+impl<T> Default for MatrixBuilder<T> {
+    /// Start building a new matrix without any rows.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test building a matrix by pushing three rows.
+    #[test]
+    fn build_success() {
+        let mut builder: MatrixBuilder<usize> = MatrixBuilder::new();
+        assert!(builder.push_row(vec![0, 1]).is_ok());
+        assert!(builder.push_row(vec![2, 3]).is_ok());
+        assert!(builder.push_row(vec![4, 5]).is_ok());
+
+        let matrix_result: Result<Matrix<usize>> = builder.build();
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<usize> = matrix_result.unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 3);
+        assert_eq!(matrix.get_number_of_columns(), 2);
+        assert_eq!(matrix.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    }
+
+    /// Test building a matrix without pushing any rows.
+    #[test]
+    fn build_without_rows() {
+        let builder: MatrixBuilder<usize> = MatrixBuilder::new();
+        let matrix_result: Result<Matrix<usize>> = builder.build();
+
+        assert!(
+            matches!(matrix_result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
+        );
+    }
+
+    /// Test pushing rows with inconsistent lengths.
+    #[test]
+    fn push_row_mismatching_columns() {
+        let mut builder: MatrixBuilder<usize> = MatrixBuilder::new();
+        assert!(builder.push_row(vec![0, 1]).is_ok());
+
+        let result: Result<()> = builder.push_row(vec![2, 3, 4]);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        // The invalid row has not been added.
+        let matrix: Matrix<usize> = builder.build().unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 1);
+        assert_eq!(matrix.as_slice(), &[0, 1]);
+    }
+
+    /// Test pushing an empty row.
+    #[test]
+    fn push_row_empty() {
+        let mut builder: MatrixBuilder<usize> = MatrixBuilder::new();
+        let result: Result<()> = builder.push_row(Vec::new());
+
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Approximate comparison of floating point matrices, e.g. for tests.
+
+use crate::matrix::Matrix;
+
+/// Determine if the matrices `a` and `b` have the same dimensions and each pair of corresponding
+/// elements differs by at most `epsilon`.
+///
+/// This is meant for tests comparing the results of floating point computations, which may differ
+/// slightly between platforms. If any element is `NaN`, the matrices are not within the tolerance.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::matrices_within;
+/// use reural_network::matrix::Matrix;
+///
+/// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+/// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let a: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.1 + 0.2, 1.0]).unwrap();
+/// let b: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.3, 1.0]).unwrap();
+///
+/// assert_ne!(a, b);
+/// assert!(matrices_within(&a, &b, 1e-10));
+/// ```
+pub fn matrices_within(a: &Matrix<f64>, b: &Matrix<f64>, epsilon: f64) -> bool {
+    if a.get_number_of_rows() != b.get_number_of_rows()
+        || a.get_number_of_columns() != b.get_number_of_columns()
+    {
+        return false;
+    }
+
+    a.as_slice()
+        .iter()
+        .zip(b.as_slice())
+        .all(|(a, b)| (a - b).abs() <= epsilon)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+
+    /// Test comparing matrices within and outside the tolerance.
+    #[test]
+    fn matrices_within_tolerance() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let a: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+        let b: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.05, 3.0, 3.98]).unwrap();
+
+        assert!(matrices_within(&a, &b, 0.1));
+        assert!(matrices_within(&b, &a, 0.1));
+        assert!(!matrices_within(&a, &b, 0.01));
+        assert!(matrices_within(&a, &a, 0.0));
+    }
+
+    /// Test comparing matrices with different dimensions or `NaN` values.
+    #[test]
+    fn matrices_within_mismatch() {
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let four: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let row: Matrix<f64> = Matrix::from_slice(one, four, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+        let column: Matrix<f64> = row.transpose();
+        assert!(!matrices_within(&row, &column, 1.0));
+
+        let nan: Matrix<f64> = Matrix::new(one, four, f64::NAN).unwrap();
+        assert!(!matrices_within(&nan, &nan, f64::INFINITY));
+    }
+}
@@ -7,9 +7,11 @@
 
 //! Definition and implementation of the matrix struct.
 
-use std::cmp::max;
+use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::num::NonZeroUsize;
 use std::ops::Add;
 use std::ops::AddAssign;
@@ -21,10 +23,13 @@ use std::ops::BitXor;
 use std::ops::BitXorAssign;
 use std::ops::Div;
 use std::ops::DivAssign;
+use std::ops::Index;
+use std::ops::IndexMut;
 use std::ops::Mul;
 use std::ops::MulAssign;
 use std::ops::Neg;
 use std::ops::Not;
+use std::ops::Range;
 use std::ops::Rem;
 use std::ops::RemAssign;
 use std::ops::Shl;
@@ -33,19 +38,37 @@ use std::ops::Shr;
 use std::ops::ShrAssign;
 use std::ops::Sub;
 use std::ops::SubAssign;
+use std::result::Result as StdResult;
 
+use rand::distributions::Distribution;
 use rand::distributions::Uniform;
-use rand::rngs::ThreadRng;
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 use rand::Rng;
+use rand::SeedableRng;
 
+use crate::activation::Activation;
 use crate::impl_element_wise_binary_operators;
 use crate::impl_scalar_assign_operators;
 use crate::impl_scalar_binary_operators;
 use crate::impl_unary_operators;
+use crate::matrix::DisplayStyle;
+use crate::matrix::MatrixViewMut;
+use crate::random;
+use crate::random::Normal;
 use crate::Error;
 use crate::Result;
 
+/// The maximum number of elements a matrix can have for all of its elements to be included in its
+/// debug output.
+const DEBUG_MAX_ELEMENTS: usize = 64;
+
+/// The number of elements at the beginning and at the end of the data included in the debug output
+/// of matrices with more than [`DEBUG_MAX_ELEMENTS`] elements.
+///
+/// [`DEBUG_MAX_ELEMENTS`]: constant.DEBUG_MAX_ELEMENTS.html
+const DEBUG_EDGE_ELEMENTS: usize = 3;
+
 /// A matrix is a 2-dimensional structure with specific dimensions that can hold data of any type.
 ///
 /// Rows and columns of a matrix are zero-indexed, meaning that the top left element of the matrix
@@ -116,12 +139,31 @@ use crate::Result;
 ///     * Scalar right shift of a matrix by a scalar value.
 /// * Negation:[<sup>*</sup>] Negate all elements of a matrix.
 /// * Logical Negation:[<sup>*</sup>] Logically negate all elements in a matrix.
-/// * Matrix Multiplication: compute the matrix product of two matrices ([`matrix_mul`]).
+/// * Matrix Multiplication: compute the matrix product of two matrices ([`mm`], [`matrix_mul`],
+///   or the free function [`matmul`]).
 /// * Transposition: flipping a matrix over its diagonal ([`transpose`]).
 /// * Map: change each element in a matrix based on a closure ([`map`]).
 ///
 /// These operations use a naive implementation without any considerations for performance.
 ///
+/// **Note:** The `*` operator on two matrices computes the element-wise (Hadamard) product, not
+/// the matrix product known from linear algebra. Use [`mm`] to compute the matrix product:
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// # use reural_network::matrix::Matrix;
+/// #
+/// let size = NonZeroUsize::new(2).unwrap();
+/// let a: Matrix<i32> = Matrix::from_slice(size, size, &[1, 2, 3, 4]).unwrap();
+/// let b: Matrix<i32> = Matrix::from_slice(size, size, &[5, 6, 7, 8]).unwrap();
+///
+/// let hadamard: Matrix<i32> = (&a * &b).unwrap();
+/// assert_eq!(hadamard.as_slice(), &[5, 12, 21, 32]);
+///
+/// let product: Matrix<i32> = a.mm(&b).unwrap();
+/// assert_eq!(product.as_slice(), &[19, 22, 43, 50]);
+/// ```
+///
 /// The binary operations are also implemented as assignment operators for scalar values (e.g.
 /// `matrix += 4.5`). However, they are not implemented as assignment operators for matrices (e.g.
 /// `matrix += other_matrix`) since both matrices must have the same dimensions. If the other matrix
@@ -151,10 +193,11 @@ use crate::Result;
 ///
 /// [<sup>*</sup>]: #impl-note-operations
 /// [`map`]: #method.map
+/// [`matmul`]: fn.matmul.html
 /// [`matrix_mul`]: #method.matrix_mul
+/// [`mm`]: #method.mm
 /// [`transpose`]: #method.transpose
 /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
-#[derive(Debug)]
 pub struct Matrix<T> {
     /// The number of rows the matrix has.
     rows: NonZeroUsize,
@@ -170,6 +213,71 @@ pub struct Matrix<T> {
 }
 
 impl<T> Matrix<T> {
+    // region Initialization
+
+    /// Convert a vector into a matrix of the given dimensions without copying the data.
+    ///
+    /// For a matrix with `m` rows and `n` columns, the first `n` elements in the vector will become
+    /// the first row in the matrix, the second `n` elements will become the second row and so on.
+    ///
+    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. If it does, an [`Error::DimensionsTooLarge`] will be
+    /// returned. Furthermore, the product must be equal to the length of the given data vector.
+    /// Otherwise, an [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// A `2x3` matrix can be created from a vector of length `6` with the following lines of code:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows = NonZeroUsize::new(2).unwrap();
+    /// let columns = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<i32> = Matrix::from_vec(rows, columns, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    /// ```
+    ///
+    /// This will produce the matrix:
+    ///
+    /// ```text
+    /// [0 1 2]
+    /// [3 4 5]
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn from_vec(rows: NonZeroUsize, columns: NonZeroUsize, data: Vec<T>) -> Result<Matrix<T>> {
+        // Check that the length of the data vector matches the dimensions of the matrix.
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
+        if length != data.len() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(Matrix::from_vec_unchecked(rows, columns, data))
+    }
+
+    /// Create a new matrix with the given dimensions from the given data vector without copying
+    /// the data.
+    ///
+    /// This method does not check if the length of the data vector matches the dimensions of the
+    /// matrix. It should only be used if it is guaranteed by the caller that the length of `data`
+    /// is exactly `rows * columns`.
+    pub(super) fn from_vec_unchecked(
+        rows: NonZeroUsize,
+        columns: NonZeroUsize,
+        data: Vec<T>,
+    ) -> Matrix<T> {
+        Matrix {
+            rows,
+            columns,
+            data,
+        }
+    }
+
+    // endregion
+
     // region Getters
 
     /// Get the data of the matrix as a 1-dimensional slice.
@@ -196,6 +304,44 @@ impl<T> Matrix<T> {
         self.data.as_slice()
     }
 
+    /// Get the data of the matrix as a mutable 1-dimensional slice, e.g. for bulk updates with
+    /// `copy_from_slice`.
+    ///
+    /// The elements are in the same order as in [`as_slice`]. Only the values can be changed
+    /// through the slice, not the dimensions of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+    ///
+    /// matrix.as_slice_mut().copy_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(matrix.get(1, 0).unwrap(), 3);
+    /// ```
+    ///
+    /// [`as_slice`]: #method.as_slice
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+
+    /// Panic with a message containing the `row` and `column` if they are not within the
+    /// dimensions of the matrix.
+    fn assert_cell_in_bounds(&self, row: usize, column: usize) {
+        assert!(
+            row < self.get_number_of_rows() && column < self.get_number_of_columns(),
+            "Cell ({}, {}) is out of bounds of the {}x{} matrix.",
+            row,
+            column,
+            self.get_number_of_rows(),
+            self.get_number_of_columns()
+        );
+    }
+
     /// Get the number of columns in the matrix.
     pub fn get_number_of_columns(&self) -> usize {
         self.columns.get()
@@ -275,6 +421,119 @@ impl<T> Matrix<T> {
         self.rows.get()
     }
 
+    /// Check if the matrix does not contain any elements.
+    ///
+    /// Since a matrix always has at least one row and one column, it can never be empty. Thus, this
+    /// method always returns `false`. It is only provided so that the matrix can be used in generic
+    /// code expecting both [`len`] and `is_empty`.
+    ///
+    /// [`len`]: #method.len
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Get the total number of elements in the matrix, i.e. the product of the number of rows and
+    /// the number of columns.
+    ///
+    /// Since a matrix always has at least one row and one column, the number of elements is never
+    /// `0`.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    // endregion
+
+    // region Properties
+
+    /// Get the size `n` of the matrix if it is a square `n x n` matrix, e.g. to validate the input
+    /// of an operation that is only defined for square matrices.
+    ///
+    /// If the number of rows differs from the number of columns, an [`Error::DimensionMismatch`]
+    /// will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let three: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let square: Matrix<usize> = Matrix::new(three, three, 0).unwrap();
+    /// let rectangle: Matrix<usize> = Matrix::new(two, three, 0).unwrap();
+    ///
+    /// assert_eq!(square.as_square().unwrap(), 3);
+    /// assert!(rectangle.as_square().is_err());
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn as_square(&self) -> Result<usize> {
+        if self.rows != self.columns {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(self.get_number_of_rows())
+    }
+
+    /// Count the elements in the matrix that satisfy the given `predicate`.
+    ///
+    /// # Example
+    ///
+    /// Count the saturated outputs of a sigmoid activation:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let data: [f64; 4] = [0.995, 0.5, 0.2, 0.999];
+    /// let activations: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// assert_eq!(activations.count_where(|activation| *activation > 0.99), 2);
+    /// ```
+    pub fn count_where<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.data
+            .iter()
+            .filter(|element| predicate(element))
+            .count()
+    }
+
+    /// Ensure that the matrix has exactly the given number of `rows` and `columns`.
+    ///
+    /// If the dimensions of the matrix differ, an [`Error::ShapeMismatch`] stating both the
+    /// expected and the actual dimensions will be returned. This makes it possible to validate
+    /// inputs (e.g. before predicting an output with a neural network) with a single call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+    ///
+    /// assert!(matrix.ensure_dimensions(2, 3).is_ok());
+    /// assert!(matrix.ensure_dimensions(3, 2).is_err());
+    /// ```
+    ///
+    /// [`Error::ShapeMismatch`]: enum.Error.html#variant.ShapeMismatch
+    pub fn ensure_dimensions(&self, rows: usize, columns: usize) -> Result<()> {
+        if self.get_number_of_rows() != rows || self.get_number_of_columns() != columns {
+            return Err(Error::ShapeMismatch {
+                expected: (rows, columns),
+                actual: (self.get_number_of_rows(), self.get_number_of_columns()),
+            });
+        }
+
+        Ok(())
+    }
+
     // endregion
 
     // region Element Operations
@@ -320,366 +579,267 @@ impl<T> Matrix<T> {
         }
     }
 
-    // endregion
-}
-
-impl<T> Matrix<T>
-where
-    T: Copy,
-{
-    // region Initialization
-
-    /// Create a new matrix with the given dimensions and the given default value in all elements.
+    /// Element-wise subtract the values of `other` from this matrix, reusing this matrix's data
+    /// for the result.
     ///
-    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
-    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
-    /// returned.
+    /// This is the same as `self - other`, but reads clearer in method chains. It never panics: if
+    /// the dimensions of both matrices differ, an [`Error::DimensionMismatch`] will be returned.
     ///
     /// # Example
     ///
-    /// A `2x3` matrix with a default value of `0.25` for all elements can be created with the
-    /// following lines of code:
-    ///
     /// ```
     /// use std::num::NonZeroUsize;
     /// use reural_network::matrix::Matrix;
+    /// use reural_network::Error;
     ///
-    /// let rows = NonZeroUsize::new(2).unwrap();
-    /// let columns = NonZeroUsize::new(3).unwrap();
-    /// let matrix: Matrix<f64> = Matrix::new(rows, columns, 0.25).unwrap();
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<i32> = Matrix::from_slice(one, two, &[5, 3]).unwrap();
+    /// let other: Matrix<i32> = Matrix::from_slice(one, two, &[1, 2]).unwrap();
+    ///
+    /// let difference: Matrix<i32> = matrix.clone().sub_checked(&other).unwrap();
+    /// assert_eq!(difference.as_slice(), &[4, 1]);
+    ///
+    /// let column: Matrix<i32> = Matrix::from_slice(two, one, &[1, 2]).unwrap();
+    /// assert!(matches!(matrix.sub_checked(&column), Err(Error::DimensionMismatch)));
     /// ```
     ///
-    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
-    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
-    pub fn new(rows: NonZeroUsize, columns: NonZeroUsize, default: T) -> Result<Matrix<T>> {
-        // Create the data structure and initialize it with the default value.
-        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
-        let mut data: Vec<T> = Vec::with_capacity(length);
-        data.resize(length, default);
-
-        // Return the matrix.
-        Ok(Matrix {
-            rows,
-            columns,
-            data,
-        })
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn sub_checked(self, other: &Matrix<T>) -> Result<Matrix<T>>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        self - other
     }
 
-    /// Convert a slice into a matrix of the given dimensions.
-    ///
-    /// For a matrix with `m` rows and `n` columns, the first `m` elements in the slice will become
-    /// the first row in the matrix, the second `m` elements will become the second row and so on.
+    // endregion
+
+    // region Rearrangement
+
+    /// Randomly shuffle the columns of the matrix using the given random number generator `rng`.
     ///
-    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
-    /// `usize` value, [`::std::usize::MAX`]. If it does, an [`Error::DimensionsTooLarge`] will be
-    /// returned. Furthermore, the product must be equal to the length of the given data slice.
-    /// Otherwise, an [`Error::DimensionMismatch`] will be returned.
+    /// Whole columns are moved, i.e. the elements within each column stay together. This is
+    /// useful for datasets that store one sample per column.
     ///
     /// # Example
     ///
-    /// A `2x3` matrix can be created from a slice of length `6` with the following lines of code:
-    ///
     /// ```
     /// use std::num::NonZeroUsize;
     /// use reural_network::matrix::Matrix;
     ///
-    /// let rows = NonZeroUsize::new(2).unwrap();
-    /// let columns = NonZeroUsize::new(3).unwrap();
-    /// let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [usize; 6] = [0, 1, 2, 0, 10, 20];
+    /// let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// matrix.shuffle_columns(&mut rand::thread_rng());
+    /// for column in 0..3 {
+    ///     let first: usize = matrix.get(0, column).unwrap();
+    ///     assert_eq!(matrix.get(1, column).unwrap(), first * 10);
+    /// }
     /// ```
+    pub fn shuffle_columns<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        // Fisher-Yates shuffle.
+        for column in (1..self.get_number_of_columns()).rev() {
+            let other_column: usize = rng.gen_range(0, column + 1);
+
+            // Both columns are always valid.
+            let _ = self.swap_columns(column, other_column);
+        }
+    }
+
+    /// Randomly shuffle the columns of the matrix and the columns of the `other` matrix in the
+    /// same way, using the given random number generator `rng`.
     ///
-    /// This will produce the matrix:
+    /// After the shuffle, columns that have been at the same position in both matrices are again
+    /// at the same position. This is useful to keep the samples in a dataset aligned with their
+    /// labels.
     ///
-    /// ```text
-    /// [0 1 2]
-    /// [3 4 5]
-    /// ```
+    /// Both matrices must have the same number of columns. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned and neither matrix will be changed.
     ///
-    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
     /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
-    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
-    pub fn from_slice(rows: NonZeroUsize, columns: NonZeroUsize, data: &[T]) -> Result<Matrix<T>> {
-        // Check that the length of the data slice matches the dimensions of the matrix.
-        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
-        if length != data.len() {
+    pub fn shuffle_columns_paired<U, R>(&mut self, other: &mut Matrix<U>, rng: &mut R) -> Result<()>
+    where
+        R: Rng + ?Sized,
+    {
+        if self.get_number_of_columns() != other.get_number_of_columns() {
             return Err(Error::DimensionMismatch);
         }
 
-        // Return the matrix.
-        Ok(Matrix {
-            rows,
-            columns,
-            data: data.to_vec(),
-        })
-    }
-
-    // endregion
+        // Fisher-Yates shuffle, applying each swap to both matrices.
+        for column in (1..self.get_number_of_columns()).rev() {
+            let other_column: usize = rng.gen_range(0, column + 1);
+            self.swap_columns(column, other_column)?;
+            other.swap_columns(column, other_column)?;
+        }
 
-    // region Getters
+        Ok(())
+    }
 
-    /// Get the value in the given `row` and `column`.
+    /// Swap the columns `column_a` and `column_b` of the matrix.
     ///
-    /// If the `row` or `column` value is larger than the number of rows or columns in the matrix,
-    /// respectively, an [`Error::CellOutOfBounds`] will be returned.
+    /// Both columns must be within the matrix. Otherwise, an [`Error::CellOutOfBounds`] will be
+    /// returned and the matrix will not be changed.
     ///
-    /// If it can be guaranteed that the `row` and `column` values do not exceed the dimensions of
-    /// the matrix, you can also use [`get_unchecked`].
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+    /// let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// matrix.swap_columns(0, 2).unwrap();
+    /// assert_eq!(matrix.as_slice(), &[2, 1, 0, 5, 4, 3]);
+    /// ```
     ///
-    /// [`get_unchecked`]: #method.get_unchecked
     /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
-    pub fn get(&self, row: usize, column: usize) -> Result<T> {
-        if row >= self.get_number_of_rows() || column >= self.get_number_of_columns() {
+    pub fn swap_columns(&mut self, column_a: usize, column_b: usize) -> Result<()> {
+        let columns: usize = self.get_number_of_columns();
+        if column_a >= columns || column_b >= columns {
             return Err(Error::CellOutOfBounds);
         }
 
-        unsafe { Ok(self.get_unchecked(row, column)) }
+        if column_a == column_b {
+            return Ok(());
+        }
+
+        for row in 0..self.get_number_of_rows() {
+            unsafe {
+                // The row is always valid, and both columns have been checked.
+                let index_a: usize = self.get_index_unchecked(row, column_a);
+                let index_b: usize = self.get_index_unchecked(row, column_b);
+                self.data.swap(index_a, index_b);
+            }
+        }
+
+        Ok(())
     }
 
-    /// Get the value in the given `row` and `column`.
+    /// Transpose the matrix in place, i.e. flip it over its diagonal without allocating a new
+    /// matrix.
     ///
-    /// This method does not check if the row and column parameters have valid values. If they are
-    /// greater than or equal to the dimensions of the matrix, the resulting index will be out of
-    /// bounds and the call will panic.
-    ///
-    /// This method should only be used if it is guaranteed by the caller that the row and column
-    /// are within the dimensions of the matrix. If this cannot be guaranteed, use [`get`] instead.
-    ///
-    /// # Safety
-    ///
-    /// If the row or column are out of bounds of this matrix, an invalid index in the internal
-    /// data structure will be accessed. This will cause the method to panic.
-    ///
-    /// [`get`]: #method.get
-    pub unsafe fn get_unchecked(&self, row: usize, column: usize) -> T {
-        self.data[self.get_index_unchecked(row, column)]
-    }
-
-    // endregion
-
-    // region Element Operations
-
-    /// Map each element in the matrix to a new element as given by the closure `mapping`.
-    ///
-    /// The `mapping` closure has three parameters, in this order:
-    ///
-    /// 1. The value of the current element.
-    /// 2. The row of the current element.
-    /// 3. The column of the current element.
-    ///
-    /// It must return the new value of the current element.
+    /// Only square matrices can be transposed in place. Otherwise, an [`Error::DimensionMismatch`]
+    /// will be returned and the matrix will not be changed. Use [`transpose`] for non-square
+    /// matrices.
     ///
     /// # Example
     ///
-    /// Convert a matrix of temperatures in °C to °F:
-    ///
     /// ```
     /// use std::num::NonZeroUsize;
     /// use reural_network::matrix::Matrix;
     ///
-    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-    /// let temperatures: [usize; 6] = [0, 10, 25, 50, 75, 100];
-    /// let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &temperatures).unwrap();
+    /// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let mut matrix: Matrix<usize> = Matrix::from_slice(size, size, &[0, 1, 2, 3]).unwrap();
     ///
-    /// // Convert Celsius to Fahrenheit (these values come out as perfect integers).
-    /// matrix.map(|celsius, _row, _column| (celsius * 9 / 5) + 32);
-    /// assert_eq!(matrix.as_slice(), [32, 50, 77, 122, 167, 212]);
+    /// matrix.transpose_in_place().unwrap();
+    /// assert_eq!(matrix.as_slice(), &[0, 2, 1, 3]);
     /// ```
-    pub fn map<F>(&mut self, mapping: F)
-    where
-        F: Fn(T, usize, usize) -> T,
-    {
-        for row in 0..self.get_number_of_rows() {
-            for column in 0..self.get_number_of_columns() {
+    ///
+    /// [`transpose`]: #method.transpose
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn transpose_in_place(&mut self) -> Result<()> {
+        let size: usize = self.as_square()?;
+
+        // Swap each element above the diagonal with its counterpart below the diagonal.
+        for row in 0..size {
+            for column in (row + 1)..size {
                 unsafe {
-                    // Since we iterate over all rows and columns, they are always valid and we
-                    // don't have to check any invariants.
+                    // Both the row and the column are less than the size of the matrix.
                     let index: usize = self.get_index_unchecked(row, column);
-                    self.data[index] = mapping(self.data[index], row, column);
+                    let transposed_index: usize = self.get_index_unchecked(column, row);
+                    self.data.swap(index, transposed_index);
                 }
             }
         }
-    }
-
-    /// Transpose this matrix.
-    ///
-    /// # Example
-    ///
-    /// A `2x3` matrix
-    ///
-    /// ```text
-    /// [0 1 2]
-    /// [3 4 5]
-    /// ```
-    ///
-    /// will become a `3x2` matrix:
-    ///
-    /// ```text
-    /// [0 3]
-    /// [1 4]
-    /// [2 5]
-    /// ```
-    ///
-    /// In code, this will look as follows:
-    ///
-    /// ```
-    /// use std::num::NonZeroUsize;
-    /// use reural_network::matrix::Matrix;
-    ///
-    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
-    ///
-    /// let transposed: Matrix<usize> = matrix.transpose();
-    /// assert_eq!(transposed.get_number_of_rows(), 3);
-    /// assert_eq!(transposed.get_number_of_columns(), 2);
-    /// assert_eq!(transposed.as_slice(), &[0, 3, 1, 4, 2, 5]);
-    /// ```
-    pub fn transpose(&self) -> Matrix<T> {
-        // The rows and columns are switched in the transposed matrix.
-        let rows: NonZeroUsize = self.columns;
-        let columns: NonZeroUsize = self.rows;
-
-        // Allocate the required memory at once. This is faster than having to resize the vector
-        // every few insertions.
-        unsafe {
-            // The rows and columns did not exceed the maximum size in the original matrix, so they
-            // won't do this here, either.
-            let length: usize =
-                Matrix::<T>::get_length_from_rows_and_columns_unchecked(rows, columns);
-            let mut data: Vec<T> = Vec::with_capacity(length);
-            for index in 0..length {
-                // Basically, iterate over the new data vector (which is still empty in the
-                // beginning). For every index of the new vector, find the corresponding value from
-                // the original matrix based on the index.
-
-                // Get the row and column for this index in the transposed matrix.
-                let row: usize = index / columns.get();
-                let column: usize = index % columns.get();
-
-                // Rows and columns are switched in the transposed matrix, so consider this when
-                // getting the index for the original data.
-                // Since we iterate over the vector and compute the row and column from this index,
-                // the values are always valid.
-                let value: T = self.get_unchecked(column, row);
-                data.push(value)
-            }
 
-            Matrix {
-                rows,
-                columns,
-                data,
-            }
-        }
+        Ok(())
     }
 
     // endregion
-}
 
-impl<T> Matrix<T>
-where
-    T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
-{
-    /// Compute the matrix product of `self` and `other` and return the result.
+    // region Views
+
+    /// Get a mutable view into the region of the matrix given by the `row_range` and the
+    /// `column_range`.
     ///
-    /// The number of columns in `self` must be equal to the number of rows in `other`. Otherwise,
-    /// [`Error::DimensionMismatch`] will be returned.
+    /// Both ranges must not be empty and must lie within the matrix. Otherwise, an
+    /// [`Error::CellOutOfBounds`] will be returned.
     ///
-    /// The resulting matrix will have the dimensions `self.rows x other.columns`. If these
-    /// dimensions would exceed the maximum size of matrices, [`Error::DimensionsTooLarge`] will be
-    /// returned.
+    /// Writing to the view will change the corresponding elements in this matrix without copying
+    /// any data.
     ///
     /// # Example
     ///
+    /// Set the bottom right `2x2` block of a `3x3` matrix to `1`:
+    ///
     /// ```
-    /// # use std::num::NonZeroUsize;
-    /// # use reural_network::Result;
-    /// # use reural_network::matrix::Matrix;
-    /// #
-    /// // `m1` (2x3):
-    /// // [1   2   3]
-    /// // [4   5   6]
-    /// let rows_m1 = NonZeroUsize::new(2).unwrap();
-    /// let columns_m1 = NonZeroUsize::new(3).unwrap();
-    /// let data_m1: [usize; 6] = [1, 2, 3, 4, 5, 6];
-    /// let m1: Matrix<usize> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
     ///
-    /// // `m2` (3x2):
-    /// // [7    8 ]
-    /// // [9    10]
-    /// // [11   12]
-    /// let rows_m2 = NonZeroUsize::new(3).unwrap();
-    /// let columns_m2 = NonZeroUsize::new(2).unwrap();
-    /// let data_m2: [usize; 6] = [7, 8, 9, 10, 11, 12];
-    /// let m2: Matrix<usize> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
+    /// let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let mut matrix: Matrix<usize> = Matrix::new(size, size, 0).unwrap();
     ///
-    /// // Calculate `m3` as the matrix product of `m1` and `m2`.
-    /// // `m3` (2x2):
-    /// // [58    64 ]
-    /// // [139   154]
-    /// let m3: Matrix<usize> = m1.matrix_mul(&m2).unwrap();
-    /// assert_eq!(m3.get_number_of_rows(), 2);
-    /// assert_eq!(m3.get_number_of_columns(), 2);
-    /// assert_eq!(m3.as_slice(), &[58, 64, 139, 154]);
+    /// {
+    ///     let mut view = matrix.submatrix_mut(1..3, 1..3).unwrap();
+    ///     view.set(0, 0, 1).unwrap();
+    ///     view.set(0, 1, 1).unwrap();
+    ///     view.set(1, 0, 1).unwrap();
+    ///     *view.get_mut(1, 1).unwrap() = 1;
+    /// }
+    ///
+    /// assert_eq!(matrix.as_slice(), &[0, 0, 0, 0, 1, 1, 0, 1, 1]);
     /// ```
     ///
-    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
-    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
-    pub fn matrix_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
-        if self.get_number_of_columns() != other.get_number_of_rows() {
-            return Err(Error::DimensionMismatch);
+    /// [`Error::CellOutOfBounds`]: ../enum.Error.html#variant.CellOutOfBounds
+    pub fn submatrix_mut(
+        &mut self,
+        row_range: Range<usize>,
+        column_range: Range<usize>,
+    ) -> Result<MatrixViewMut<'_, T>> {
+        if row_range.end > self.get_number_of_rows()
+            || column_range.end > self.get_number_of_columns()
+        {
+            return Err(Error::CellOutOfBounds);
         }
 
-        // Ensure that the dimensions of the result matrix do not exceed the maximum size.
-        let rows: NonZeroUsize = self.rows;
-        let columns: NonZeroUsize = other.columns;
-        let size: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
+        // Empty ranges would result in a view without any elements.
+        let rows: NonZeroUsize = match NonZeroUsize::new(row_range.len()) {
+            Some(rows) => rows,
+            None => return Err(Error::CellOutOfBounds),
+        };
+        let columns: NonZeroUsize = match NonZeroUsize::new(column_range.len()) {
+            Some(columns) => columns,
+            None => return Err(Error::CellOutOfBounds),
+        };
 
-        let mut result = Matrix {
+        Ok(MatrixViewMut::new(
+            self.data.as_mut_slice(),
+            self.columns,
+            row_range.start,
+            column_range.start,
             rows,
             columns,
-            data: Vec::with_capacity(size),
-        };
-
-        for row in 0..result.get_number_of_rows() {
-            for column in 0..result.get_number_of_columns() {
-                // All row and column values are valid so it is safe to use these unsafe and
-                // unchecked methods.
-                unsafe {
-                    // Calculate the sum of products. Since there is no general neutral element
-                    // of addition for `T` (e.g., 0 would be one for all number types), calculate
-                    // the first product outside the loop to initialize the variable without special
-                    // cases inside the loop. There must be at least this first element since we can
-                    // not have matrices without any elements.
-                    let mut element: T =
-                        self.get_unchecked(row, 0) * other.get_unchecked(0, column);
-
-                    for i in 1..self.get_number_of_columns() {
-                        let product: T =
-                            self.get_unchecked(row, i) * other.get_unchecked(i, column);
-
-                        // We don't want to require `T` to implement `AddAssign`, but only the
-                        // simpler `Add`.
-                        element = element + product;
-                    }
-
-                    // Set the element in the result matrix. Since we are iterating over the
-                    // elements in row-major format, the index at which `element` will be inserted
-                    // will be correct.
-                    result.data.push(element);
-                }
-            }
-        }
-
-        Ok(result)
+        ))
     }
+
+    // endregion
 }
 
-impl Matrix<f64> {
+impl<T> Matrix<T>
+where
+    T: Copy,
+{
     // region Initialization
 
-    /// Create a new matrix with the given dimensions and random elements in the inclusive range
-    /// `[0.0, 1.0]` (i.e., including both `0.0` and `1.0`).
+    /// Create a new matrix with the given dimensions and the given default value in all elements.
     ///
     /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
     /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
@@ -696,17 +856,16 @@ impl Matrix<f64> {
     ///
     /// let rows = NonZeroUsize::new(2).unwrap();
     /// let columns = NonZeroUsize::new(3).unwrap();
-    /// let matrix: Matrix<f64> = Matrix::from_random(rows, columns).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::new(rows, columns, 0.25).unwrap();
     /// ```
     ///
     /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
     /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
-    pub fn from_random(rows: NonZeroUsize, columns: NonZeroUsize) -> Result<Matrix<f64>> {
-        // Get random data in the range of [0.0, 1.0].
-        let length: usize = Matrix::<f64>::get_length_from_rows_and_columns(rows, columns)?;
-        let mut rng: ThreadRng = thread_rng();
-        let mut data: Vec<f64> = Vec::with_capacity(length);
-        data.resize_with(length, || rng.sample(Uniform::new_inclusive(0.0, 1.0)));
+    pub fn new(rows: NonZeroUsize, columns: NonZeroUsize, default: T) -> Result<Matrix<T>> {
+        // Create the data structure and initialize it with the default value. This is equivalent
+        // to allocating the vector and resizing it, but only requires a single pass.
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
+        let data: Vec<T> = vec![default; length];
 
         // Return the matrix.
         Ok(Matrix {
@@ -716,685 +875,5789 @@ impl Matrix<f64> {
         })
     }
 
-    // endregion
-}
-
-impl<T> Clone for Matrix<T>
-where
-    T: Clone,
-{
-    /// Clone this matrix.
-    fn clone(&self) -> Self {
-        Matrix {
-            rows: self.rows,
-            columns: self.columns,
-            data: self.data.clone(),
-        }
-    }
-}
-
-impl<T> Display for Matrix<T>
-where
-    T: Display,
-{
-    /// Get a human readable representation of this matrix.
+    /// Convert a slice into a matrix of the given dimensions.
     ///
-    /// The matrix will be formatted in a rectangular array with the dimensions of the matrix.
+    /// For a matrix with `m` rows and `n` columns, the first `m` elements in the slice will become
+    /// the first row in the matrix, the second `m` elements will become the second row and so on.
+    ///
+    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. If it does, an [`Error::DimensionsTooLarge`] will be
+    /// returned. Furthermore, the product must be equal to the length of the given data slice.
+    /// Otherwise, an [`Error::DimensionMismatch`] will be returned.
     ///
     /// # Example
     ///
-    /// A `2x3` matrix with some data as produced by the code
+    /// A `2x3` matrix can be created from a slice of length `6` with the following lines of code:
     ///
     /// ```
     /// use std::num::NonZeroUsize;
     /// use reural_network::matrix::Matrix;
     ///
-    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-    /// let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
-    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    /// let rows = NonZeroUsize::new(2).unwrap();
+    /// let columns = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
     /// ```
     ///
-    /// will be formatted to the following text (e.g. when using [`println!`] to print to the
-    /// console):
+    /// This will produce the matrix:
     ///
     /// ```text
-    /// [0.25   1.33    -0.1]
-    /// [1      -2.73   1.2 ]
+    /// [0 1 2]
+    /// [3 4 5]
     /// ```
     ///
-    /// [`println!`]: https://doc.rust-lang.org/stable/std/macro.println.html
-    fn fmt(&self, formatter: &mut Formatter) -> ::std::fmt::Result {
-        // Align all columns, but each column may have a different alignment. Thus, first iterate
-        // over the columns, then the rows, to get the width of each column from all values in the
-        // column.
-        let mut column_widths: Vec<usize> = Vec::with_capacity(self.get_number_of_columns());
-        for column in 0..self.get_number_of_columns() {
-            // Get the maximum width of the current column.
-            let mut max_width: usize = 0;
-            for row in 0..self.get_number_of_rows() {
-                // Do not use self.get_unchecked() here as this requires T to implement Copy.
-                unsafe {
-                    // We iterate over the rows and columns and thus, they are always valid.
-                    let value: String =
-                        format!("{}", self.data[self.get_index_unchecked(row, column)]);
-                    max_width = max(max_width, value.len());
-                }
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn from_slice(rows: NonZeroUsize, columns: NonZeroUsize, data: &[T]) -> Result<Matrix<T>> {
+        // Check that the length of the data slice matches the dimensions of the matrix.
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
+        if length != data.len() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        // Return the matrix.
+        Ok(Matrix {
+            rows,
+            columns,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Create a new block-diagonal matrix from the given `blocks`, placing each block along the
+    /// diagonal (in the given order) and filling all other elements with `zero`.
+    ///
+    /// The number of rows (columns) of the new matrix is the sum of the numbers of rows (columns)
+    /// of all blocks. If these sums or their product exceed the maximum `usize` value,
+    /// [`::std::usize::MAX`], an [`Error::DimensionsTooLarge`] will be returned. If there are no
+    /// blocks, an [`Error::EmptyMatrix`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let first: Matrix<i32> = Matrix::from_slice(one, two, &[1, 2]).unwrap();
+    /// let second: Matrix<i32> = Matrix::from_slice(one, one, &[3]).unwrap();
+    ///
+    /// let matrix: Matrix<i32> = Matrix::block_diagonal(&[first, second], 0).unwrap();
+    /// assert_eq!(matrix.get_number_of_rows(), 2);
+    /// assert_eq!(matrix.get_number_of_columns(), 3);
+    /// assert_eq!(matrix.as_slice(), &[1, 2, 0, 0, 0, 3]);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    /// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+    pub fn block_diagonal(blocks: &[Matrix<T>], zero: T) -> Result<Matrix<T>> {
+        let mut rows: usize = 0;
+        let mut columns: usize = 0;
+        for block in blocks {
+            rows = rows
+                .checked_add(block.get_number_of_rows())
+                .ok_or(Error::DimensionsTooLarge)?;
+            columns = columns
+                .checked_add(block.get_number_of_columns())
+                .ok_or(Error::DimensionsTooLarge)?;
+        }
+
+        let rows: NonZeroUsize = NonZeroUsize::new(rows).ok_or(Error::EmptyMatrix)?;
+        let columns: NonZeroUsize = NonZeroUsize::new(columns).ok_or(Error::EmptyMatrix)?;
+        let mut matrix: Matrix<T> = Matrix::new(rows, columns, zero)?;
+
+        // The offset of the current block's upper left element within the new matrix.
+        let mut row_offset: usize = 0;
+        let mut column_offset: usize = 0;
+        for block in blocks {
+            for (row, block_row) in block
+                .data
+                .chunks_exact(block.get_number_of_columns())
+                .enumerate()
+            {
+                let start: usize = (row_offset + row) * columns.get() + column_offset;
+                matrix.data[start..start + block_row.len()].copy_from_slice(block_row);
             }
 
-            // Remember the current column's width.
-            column_widths.push(max_width);
+            row_offset += block.get_number_of_rows();
+            column_offset += block.get_number_of_columns();
         }
 
-        // Now, go through each row and format each value with the width of its column.
-        let mut rows: Vec<String> = Vec::with_capacity(self.get_number_of_rows());
-        for row in 0..self.get_number_of_rows() {
-            // For each row, collect the formatted values first.
-            let mut row_values: Vec<String> = Vec::with_capacity(self.get_number_of_columns());
-            for (column, width) in column_widths.iter().enumerate() {
-                unsafe {
-                    // We iterate over the rows and columns and thus, they are always valid.
-                    let value: String = format!(
-                        "{:<width$}", // Left-align all values.
-                        // Do not use self.get_unchecked() here as this requires T to implement
-                        // Copy.
-                        self.data[self.get_index_unchecked(row, column)],
-                        width = width
-                    );
-
-                    row_values.push(value);
-                }
+        Ok(matrix)
+    }
+
+    /// Create a new matrix with the given dimensions from a sparse list of `(row, column, value)`
+    /// `triplets`. All elements that are not given in the triplets will be set to `default`. If
+    /// the same element is given multiple times, the last value will be used.
+    ///
+    /// The coordinates of all triplets must be within the matrix. Otherwise, an
+    /// [`Error::CellOutOfBounds`] will be returned. If the product of the number of `rows` and the
+    /// number of `columns` exceeds the maximum `usize` value, [`::std::usize::MAX`], an
+    /// [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let triplets: [(usize, usize, f64); 2] = [(0, 1, 1.5), (1, 2, -2.0)];
+    ///
+    /// let matrix: Matrix<f64> = Matrix::from_sparse_triplets(rows, columns, &triplets, 0.0)
+    ///     .unwrap();
+    /// assert_eq!(matrix.as_slice(), &[0.0, 1.5, 0.0, 0.0, 0.0, -2.0]);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn from_sparse_triplets(
+        rows: NonZeroUsize,
+        columns: NonZeroUsize,
+        triplets: &[(usize, usize, T)],
+        default: T,
+    ) -> Result<Matrix<T>> {
+        if triplets
+            .iter()
+            .any(|&(row, column, _value)| row >= rows.get() || column >= columns.get())
+        {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        let mut matrix: Matrix<T> = Matrix::new(rows, columns, default)?;
+        for &(row, column, value) in triplets {
+            unsafe {
+                // The coordinates of all triplets have been checked.
+                let index: usize = matrix.get_index_unchecked(row, column);
+                matrix.data[index] = value;
             }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Create a new `size x size` identity matrix, containing `one` on the diagonal and `zero`
+    /// everywhere else.
+    ///
+    /// The number of elements, `size * size`, must not exceed the maximum `usize` value,
+    /// [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let identity: Matrix<f64> = Matrix::identity(size, 0.0, 1.0).unwrap();
+    /// assert_eq!(identity.as_slice(), &[1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn identity(size: NonZeroUsize, zero: T, one: T) -> Result<Matrix<T>> {
+        let mut matrix: Matrix<T> = Matrix::new(size, size, zero)?;
 
-            // Concatenate all aligned values in the row with three spaces. Surround the values with
-            // square brackets.
-            rows.push(format!("[{}]", row_values.join("   ")));
+        // The diagonal elements are `size + 1` elements apart in the row-major data.
+        for element in matrix.data.iter_mut().step_by(size.get() + 1) {
+            *element = one;
         }
 
-        // Concatenate all rows with a new line.
-        write!(formatter, "{}", rows.join("\n"))
+        Ok(matrix)
     }
-}
 
-impl<T> Eq for Matrix<T> where T: Eq {}
+    // endregion
 
-impl<T> PartialEq for Matrix<T>
-where
-    T: PartialEq,
-{
-    /// Check if two matrices are equal to each other.
+    // region Getters
+
+    /// Get the values at the given flat `indices`, in the given order. The flat index of an
+    /// element is its index in the row-major data of the matrix, i.e. `row * columns + column`.
     ///
-    /// Two matrices `A` and `B` are equal to each other if their dimensions are the same and all
-    /// elements in matrix `A` are equal to their corresponding element in matrix `B` (i.e. if
-    /// element `A_i,j == B_i,j`.
-    fn eq(&self, other: &Self) -> bool {
-        if self.get_number_of_columns() != other.get_number_of_columns() {
-            return false;
+    /// All indices must be less than the number of elements in the matrix. Otherwise, an
+    /// [`Error::CellOutOfBounds`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// assert_eq!(matrix.gather(&[5, 0, 5]).unwrap(), vec![5, 0, 5]);
+    /// ```
+    ///
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    pub fn gather(&self, indices: &[usize]) -> Result<Vec<T>> {
+        indices
+            .iter()
+            .map(|&index| self.data.get(index).copied().ok_or(Error::CellOutOfBounds))
+            .collect()
+    }
+
+    /// Get the value in the given `row` and `column`.
+    ///
+    /// If the `row` or `column` value is larger than the number of rows or columns in the matrix,
+    /// respectively, an [`Error::CellOutOfBounds`] will be returned.
+    ///
+    /// If it can be guaranteed that the `row` and `column` values do not exceed the dimensions of
+    /// the matrix, you can also use [`get_unchecked`].
+    ///
+    /// [`get_unchecked`]: #method.get_unchecked
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    pub fn get(&self, row: usize, column: usize) -> Result<T> {
+        if row >= self.get_number_of_rows() || column >= self.get_number_of_columns() {
+            return Err(Error::CellOutOfBounds);
         }
 
-        if self.get_number_of_rows() != other.get_number_of_rows() {
-            return false;
+        unsafe { Ok(self.get_unchecked(row, column)) }
+    }
+
+    /// Get the value in the given `row` and `column`, checking the `row` and `column` values only
+    /// in debug builds.
+    ///
+    /// This is a middle ground between [`get`], which always checks the `row` and `column` values,
+    /// and [`get_unchecked`], which never checks them. In debug builds, the method will panic with
+    /// a message describing the invalid cell if the `row` or `column` value is larger than the
+    /// number of rows or columns in the matrix, respectively. In release builds, no checks are
+    /// performed: the method will only panic if the resulting index is outside the matrix, and it
+    /// may return the value of a different cell otherwise (e.g. if only the column is too large).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// assert_eq!(matrix.get_debug_checked(1, 2), 5);
+    /// ```
+    ///
+    /// [`get`]: #method.get
+    /// [`get_unchecked`]: #method.get_unchecked
+    pub fn get_debug_checked(&self, row: usize, column: usize) -> T {
+        if cfg!(debug_assertions) {
+            self.assert_cell_in_bounds(row, column);
         }
 
-        for (e1, e2) in self.as_slice().iter().zip(other.as_slice()) {
-            if e1 != e2 {
-                return false;
-            }
+        unsafe { self.get_unchecked(row, column) }
+    }
+
+    /// Get the value in the given `row` and `column`.
+    ///
+    /// This method does not check if the row and column parameters have valid values. If they are
+    /// greater than or equal to the dimensions of the matrix, the resulting index will be out of
+    /// bounds and the call will panic.
+    ///
+    /// This method should only be used if it is guaranteed by the caller that the row and column
+    /// are within the dimensions of the matrix. If this cannot be guaranteed, use [`get`] instead.
+    ///
+    /// # Safety
+    ///
+    /// If the row or column are out of bounds of this matrix, an invalid index in the internal
+    /// data structure will be accessed. This will cause the method to panic.
+    ///
+    /// [`get`]: #method.get
+    pub unsafe fn get_unchecked(&self, row: usize, column: usize) -> T {
+        self.data[self.get_index_unchecked(row, column)]
+    }
+
+    /// Get a sparse representation of the matrix as a list of `(row, column, value)` triplets,
+    /// containing only the elements that are not equal to the default value of `T` (e.g. `0` for
+    /// numbers). The triplets are in row-major order.
+    ///
+    /// The matrix can be restored from the triplets with [`from_sparse_triplets`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [f64; 6] = [0.0, 1.5, 0.0, 0.0, 0.0, -2.0];
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// let triplets: Vec<(usize, usize, f64)> = matrix.to_sparse_triplets();
+    /// assert_eq!(triplets, vec![(0, 1, 1.5), (1, 2, -2.0)]);
+    /// ```
+    ///
+    /// [`from_sparse_triplets`]: #method.from_sparse_triplets
+    pub fn to_sparse_triplets(&self) -> Vec<(usize, usize, T)>
+    where
+        T: Default + PartialEq,
+    {
+        let default: T = T::default();
+        let columns: usize = self.get_number_of_columns();
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_index, element)| **element != default)
+            .map(|(index, element)| (index / columns, index % columns, *element))
+            .collect()
+    }
+
+    /// Get an iterator over all windows of `window_rows x window_columns` elements of the matrix,
+    /// e.g. for patch-based processing or a convolution.
+    ///
+    /// Each window is returned as a new matrix. The windows are returned for all valid positions
+    /// of their top left element in row-major order.
+    ///
+    /// If the window is larger than the matrix in any dimension, an [`Error::CellOutOfBounds`] will
+    /// be returned. If `window_rows` or `window_columns` is zero, an [`Error::EmptyMatrix`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// let windows: Vec<Matrix<usize>> = matrix.windows_2d(2, 2).unwrap().collect();
+    /// assert_eq!(windows.len(), 2);
+    /// assert_eq!(windows[0].as_slice(), &[0, 1, 3, 4]);
+    /// assert_eq!(windows[1].as_slice(), &[1, 2, 4, 5]);
+    /// ```
+    ///
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    /// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+    pub fn windows_2d(
+        &self,
+        window_rows: usize,
+        window_columns: usize,
+    ) -> Result<impl Iterator<Item = Matrix<T>> + '_> {
+        let rows: NonZeroUsize = NonZeroUsize::new(window_rows).ok_or(Error::EmptyMatrix)?;
+        let columns: NonZeroUsize = NonZeroUsize::new(window_columns).ok_or(Error::EmptyMatrix)?;
+
+        if window_rows > self.get_number_of_rows() || window_columns > self.get_number_of_columns()
+        {
+            return Err(Error::CellOutOfBounds);
         }
 
-        true
+        let row_positions: usize = self.get_number_of_rows() - window_rows + 1;
+        let column_positions: usize = self.get_number_of_columns() - window_columns + 1;
+        let windows = (0..row_positions).flat_map(move |top| {
+            (0..column_positions).map(move |left| {
+                let mut data: Vec<T> = Vec::with_capacity(window_rows * window_columns);
+                for row in top..top + window_rows {
+                    let start: usize = row * self.get_number_of_columns() + left;
+                    data.extend_from_slice(&self.data[start..start + window_columns]);
+                }
+
+                Matrix::from_vec_unchecked(rows, columns, data)
+            })
+        });
+
+        Ok(windows)
     }
-}
 
-impl_scalar_assign_operators!();
-impl_element_wise_binary_operators!();
-impl_scalar_binary_operators!();
-impl_unary_operators!();
+    // endregion
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    // region Element Operations
 
-    use approx::assert_relative_eq;
-    use approx::assert_relative_ne;
+    /// Set each element in the matrix for which the corresponding element in the `mask` is `true`
+    /// to `zero`. All other elements will not be changed.
+    ///
+    /// The dimensions of the mask must match the dimensions of the matrix. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned and the matrix will not be changed.
+    ///
+    /// # Example
+    ///
+    /// Mask out the diagonal of a `2x2` matrix:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let data: [f64; 4] = [1.0, 2.0, 3.0, 4.0];
+    /// let mut matrix: Matrix<f64> = Matrix::from_slice(size, size, &data).unwrap();
+    /// let diagonal: [bool; 4] = [true, false, false, true];
+    /// let mask: Matrix<bool> = Matrix::from_slice(size, size, &diagonal).unwrap();
+    ///
+    /// matrix.apply_mask(&mask, 0.0).unwrap();
+    /// assert_eq!(matrix.as_slice(), &[0.0, 2.0, 3.0, 0.0]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+    pub fn apply_mask(&mut self, mask: &Matrix<bool>, zero: T) -> Result<()> {
+        if self.get_number_of_rows() != mask.get_number_of_rows()
+            || self.get_number_of_columns() != mask.get_number_of_columns()
+        {
+            return Err(Error::DimensionMismatch);
+        }
 
-    use crate::test_element_wise_binary_operators;
-    use crate::test_scalar_assign_operators;
-    use crate::test_scalar_binary_operators;
-    use crate::test_unary_operators;
+        for (element, masked) in self.data.iter_mut().zip(mask.as_slice()) {
+            if *masked {
+                *element = zero;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add `other` to `self` element-wise and return the result, broadcasting `other` over all
+    /// columns if it is a column vector, e.g. to add a `o x 1` bias to each column of a `o x n`
+    /// matrix.
+    ///
+    /// If both matrices have the same dimensions, they are simply added element-wise. If `other`
+    /// has the same number of rows as `self`, but only a single column, this column is added to
+    /// each column of `self`. Otherwise, an [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+    /// let bias: Matrix<i32> = Matrix::from_slice(rows, one, &[10, 20]).unwrap();
+    ///
+    /// let sum: Matrix<i32> = matrix.broadcast_add(&bias).unwrap();
+    /// assert_eq!(sum.as_slice(), &[11, 12, 13, 24, 25, 26]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn broadcast_add(&self, other: &Matrix<T>) -> Result<Matrix<T>>
+    where
+        T: Add<T, Output = T>,
+    {
+        self.zip_broadcast(other, |element, other_element| element + other_element)
+    }
+
+    /// Multiply `self` by `other` element-wise and return the result, broadcasting `other` over
+    /// all columns if it is a column vector, e.g. to scale each row of a matrix by its own factor.
+    ///
+    /// If both matrices have the same dimensions, they are simply multiplied element-wise. If
+    /// `other` has the same number of rows as `self`, but only a single column, each column of
+    /// `self` is multiplied by this column. Otherwise, an [`Error::DimensionMismatch`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+    /// let factors: Matrix<i32> = Matrix::from_slice(rows, one, &[10, -1]).unwrap();
+    ///
+    /// let product: Matrix<i32> = matrix.broadcast_mul(&factors).unwrap();
+    /// assert_eq!(product.as_slice(), &[10, 20, 30, -4, -5, -6]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn broadcast_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>>
+    where
+        T: Mul<T, Output = T>,
+    {
+        self.zip_broadcast(other, |element, other_element| element * other_element)
+    }
+
+    /// Compute the element-wise maximum of `self` and `other` and return the result.
+    ///
+    /// Both matrices must have the same dimensions. Otherwise, [`Error::DimensionMismatch`] will be
+    /// returned. If two elements cannot be compared (e.g. if one of them is `NaN`), the element
+    /// from `self` will be used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let m1: Matrix<usize> = Matrix::from_slice(rows, columns, &[1, 5, 3]).unwrap();
+    /// let m2: Matrix<usize> = Matrix::from_slice(rows, columns, &[4, 2, 6]).unwrap();
+    ///
+    /// let maximum: Matrix<usize> = m1.elementwise_max(&m2).unwrap();
+    /// assert_eq!(maximum.as_slice(), &[4, 5, 6]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn elementwise_max(&self, other: &Matrix<T>) -> Result<Matrix<T>>
+    where
+        T: PartialOrd,
+    {
+        self.zip_select(other, |element, other_element| other_element > element)
+    }
+
+    /// Compute the element-wise minimum of `self` and `other` and return the result.
+    ///
+    /// Both matrices must have the same dimensions. Otherwise, [`Error::DimensionMismatch`] will be
+    /// returned. If two elements cannot be compared (e.g. if one of them is `NaN`), the element
+    /// from `self` will be used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let m1: Matrix<usize> = Matrix::from_slice(rows, columns, &[1, 5, 3]).unwrap();
+    /// let m2: Matrix<usize> = Matrix::from_slice(rows, columns, &[4, 2, 6]).unwrap();
+    ///
+    /// let minimum: Matrix<usize> = m1.elementwise_min(&m2).unwrap();
+    /// assert_eq!(minimum.as_slice(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn elementwise_min(&self, other: &Matrix<T>) -> Result<Matrix<T>>
+    where
+        T: PartialOrd,
+    {
+        self.zip_select(other, |element, other_element| other_element < element)
+    }
+
+    /// Map each element in the matrix to a new element as given by the closure `mapping`.
+    ///
+    /// The `mapping` closure has three parameters, in this order:
+    ///
+    /// 1. The value of the current element.
+    /// 2. The row of the current element.
+    /// 3. The column of the current element.
+    ///
+    /// It must return the new value of the current element.
+    ///
+    /// # Example
+    ///
+    /// Convert a matrix of temperatures in °C to °F:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let temperatures: [usize; 6] = [0, 10, 25, 50, 75, 100];
+    /// let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &temperatures).unwrap();
+    ///
+    /// // Convert Celsius to Fahrenheit (these values come out as perfect integers).
+    /// matrix.map(|celsius, _row, _column| (celsius * 9 / 5) + 32);
+    /// assert_eq!(matrix.as_slice(), [32, 50, 77, 122, 167, 212]);
+    /// ```
+    pub fn map<F>(&mut self, mapping: F)
+    where
+        F: Fn(T, usize, usize) -> T,
+    {
+        for row in 0..self.get_number_of_rows() {
+            for column in 0..self.get_number_of_columns() {
+                unsafe {
+                    // Since we iterate over all rows and columns, they are always valid and we
+                    // don't have to check any invariants.
+                    let index: usize = self.get_index_unchecked(row, column);
+                    self.data[index] = mapping(self.data[index], row, column);
+                }
+            }
+        }
+    }
+
+    /// Add `delta` to all elements in the matrix and return the matrix for further chained calls.
+    ///
+    /// This is equivalent to `matrix += delta`, but makes it possible to chain multiple operations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let data: [f64; 4] = [1.0, 2.0, 3.0, 4.0];
+    /// let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// matrix.scale_in_place(2.0).offset_in_place(1.0);
+    /// assert_eq!(matrix.as_slice(), &[3.0, 5.0, 7.0, 9.0]);
+    /// ```
+    pub fn offset_in_place(&mut self, delta: T) -> &mut Self
+    where
+        T: AddAssign<T>,
+    {
+        *self += delta;
+        self
+    }
+
+    /// Set all elements in the matrix to the given `value`.
+    ///
+    /// The existing data of the matrix is overwritten in place, i.e. no memory will be allocated.
+    /// This makes it possible to reuse the same matrix across many iterations (e.g. when training
+    /// a neural network) without reallocating it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let data: [f64; 4] = [1.0, 2.0, 3.0, 4.0];
+    /// let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// matrix.reset(0.0);
+    /// assert_eq!(matrix.as_slice(), &[0.0, 0.0, 0.0, 0.0]);
+    /// ```
+    pub fn reset(&mut self, value: T) {
+        for element in self.data.iter_mut() {
+            *element = value;
+        }
+    }
+
+    /// Resize the matrix to `new_rows x new_columns` using nearest-neighbor interpolation and
+    /// return the result.
+    ///
+    /// Each element of the result is the element of this matrix that is nearest to it when both
+    /// matrices are stretched to the same size. This works for both upscaling and downscaling.
+    ///
+    /// If the product of `new_rows` and `new_columns` exceeds the maximum `usize` value,
+    /// [`::std::usize::MAX`], an [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[1, 2]).unwrap();
+    ///
+    /// let new_rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let new_columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+    /// let resized: Matrix<usize> = matrix.resize_nearest(new_rows, new_columns).unwrap();
+    /// assert_eq!(resized.as_slice(), &[1, 1, 2, 2, 1, 1, 2, 2]);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn resize_nearest(
+        &self,
+        new_rows: NonZeroUsize,
+        new_columns: NonZeroUsize,
+    ) -> Result<Matrix<T>> {
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(new_rows, new_columns)?;
+
+        /// Get the index in the source dimension of size `old` nearest to `index` in the target
+        /// dimension of size `new`. The computation is performed on 128 bits to avoid overflows.
+        fn nearest(index: usize, old: NonZeroUsize, new: NonZeroUsize) -> usize {
+            (index as u128 * old.get() as u128 / new.get() as u128) as usize
+        }
+
+        let mut data: Vec<T> = Vec::with_capacity(length);
+        for row in 0..new_rows.get() {
+            let source_row: usize = nearest(row, self.rows, new_rows);
+            for column in 0..new_columns.get() {
+                let source_column: usize = nearest(column, self.columns, new_columns);
+
+                // The source row and column are always less than the number of rows and columns,
+                // respectively.
+                data.push(unsafe { self.get_unchecked(source_row, source_column) });
+            }
+        }
+
+        Ok(Matrix {
+            rows: new_rows,
+            columns: new_columns,
+            data,
+        })
+    }
+
+    /// Multiply each element in the matrix by the factor for its row and the factor for its column
+    /// and return the result, i.e. compute `row_factors[i] * self[i, j] * column_factors[j]` for
+    /// each element in row `i` and column `j`.
+    ///
+    /// This is equivalent to multiplying the matrix from the left with a diagonal matrix
+    /// containing the `row_factors` and from the right with a diagonal matrix containing the
+    /// `column_factors`, but is computed in a single pass without any matrix multiplication.
+    ///
+    /// The number of `row_factors` must be equal to the number of rows in the matrix and the number
+    /// of `column_factors` must be equal to the number of columns in the matrix. Otherwise,
+    /// [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<i64> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+    ///
+    /// let scaled: Matrix<i64> = matrix.scale_both(&[1, -1], &[1, 10, 100]).unwrap();
+    /// assert_eq!(scaled.as_slice(), &[1, 20, 300, -4, -50, -600]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn scale_both(&self, row_factors: &[T], column_factors: &[T]) -> Result<Matrix<T>>
+    where
+        T: Mul<T, Output = T>,
+    {
+        if row_factors.len() != self.get_number_of_rows()
+            || column_factors.len() != self.get_number_of_columns()
+        {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let columns: usize = self.get_number_of_columns();
+        let data: Vec<T> = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, &element)| {
+                // The data is stored in row-major format.
+                row_factors[index / columns] * element * column_factors[index % columns]
+            })
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        })
+    }
+
+    /// Multiply all elements in the matrix by `factor` and return the matrix for further chained
+    /// calls.
+    ///
+    /// This is equivalent to `matrix *= factor`, but makes it possible to chain multiple
+    /// operations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let data: [f64; 4] = [1.0, 2.0, 3.0, 4.0];
+    /// let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// matrix.scale_in_place(0.5);
+    /// assert_eq!(matrix.as_slice(), &[0.5, 1.0, 1.5, 2.0]);
+    /// ```
+    pub fn scale_in_place(&mut self, factor: T) -> &mut Self
+    where
+        T: MulAssign<T>,
+    {
+        *self *= factor;
+        self
+    }
+
+    /// Write the given `values` to the elements at the given flat `indices`. The flat index of an
+    /// element is its index in the row-major data of the matrix, i.e. `row * columns + column`.
+    /// If an index is given multiple times, the last corresponding value will be written.
+    ///
+    /// There must be exactly one value per index. Otherwise, an [`Error::DimensionMismatch`] will
+    /// be returned. All indices must be less than the number of elements in the matrix.
+    /// Otherwise, an [`Error::CellOutOfBounds`] will be returned. In both cases, the matrix will
+    /// not be changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+    ///
+    /// matrix.scatter(&[5, 1], &[7, 3]).unwrap();
+    /// assert_eq!(matrix.as_slice(), &[0, 3, 0, 0, 0, 7]);
+    /// ```
+    ///
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn scatter(&mut self, indices: &[usize], values: &[T]) -> Result<()> {
+        if indices.len() != values.len() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        if indices.iter().any(|&index| index >= self.data.len()) {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        for (&index, &value) in indices.iter().zip(values) {
+            self.data[index] = value;
+        }
+
+        Ok(())
+    }
+
+    /// Transpose this matrix.
+    ///
+    /// # Example
+    ///
+    /// A `2x3` matrix
+    ///
+    /// ```text
+    /// [0 1 2]
+    /// [3 4 5]
+    /// ```
+    ///
+    /// will become a `3x2` matrix:
+    ///
+    /// ```text
+    /// [0 3]
+    /// [1 4]
+    /// [2 5]
+    /// ```
+    ///
+    /// In code, this will look as follows:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// let transposed: Matrix<usize> = matrix.transpose();
+    /// assert_eq!(transposed.get_number_of_rows(), 3);
+    /// assert_eq!(transposed.get_number_of_columns(), 2);
+    /// assert_eq!(transposed.as_slice(), &[0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        // The rows and columns are switched in the transposed matrix.
+        let rows: NonZeroUsize = self.columns;
+        let columns: NonZeroUsize = self.rows;
+
+        // Allocate the required memory at once. This is faster than having to resize the vector
+        // every few insertions.
+        unsafe {
+            // The rows and columns did not exceed the maximum size in the original matrix, so they
+            // won't do this here, either.
+            let length: usize =
+                Matrix::<T>::get_length_from_rows_and_columns_unchecked(rows, columns);
+            let mut data: Vec<T> = Vec::with_capacity(length);
+            for index in 0..length {
+                // Basically, iterate over the new data vector (which is still empty in the
+                // beginning). For every index of the new vector, find the corresponding value from
+                // the original matrix based on the index.
+
+                // Get the row and column for this index in the transposed matrix.
+                let row: usize = index / columns.get();
+                let column: usize = index % columns.get();
+
+                // Rows and columns are switched in the transposed matrix, so consider this when
+                // getting the index for the original data.
+                // Since we iterate over the vector and compute the row and column from this index,
+                // the values are always valid.
+                let value: T = self.get_unchecked(column, row);
+                data.push(value)
+            }
+
+            Matrix {
+                rows,
+                columns,
+                data,
+            }
+        }
+    }
+
+    /// Map each element in the matrix to a new element as given by the fallible closure `mapping`.
+    ///
+    /// The `mapping` closure has three parameters, in this order:
+    ///
+    /// 1. The value of the current element.
+    /// 2. The row of the current element.
+    /// 3. The column of the current element.
+    ///
+    /// It must return either the new value of the current element, or an error. The elements are
+    /// mapped in row-major order. The mapping stops at the first error, which is then returned. In
+    /// this case, all elements before the failing one will have already been mapped, while the
+    /// failing element and all elements after it will be unchanged.
+    ///
+    /// # Example
+    ///
+    /// Look up each element in a table that does not contain all values:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3]).unwrap();
+    /// let table: [usize; 3] = [10, 20, 30];
+    ///
+    /// let result = matrix.try_map_indexed(|index, row, column| match table.get(index) {
+    ///     Some(value) => Ok(*value),
+    ///     None => Err((row, column)),
+    /// });
+    /// assert_eq!(result, Err((1, 1)));
+    /// assert_eq!(matrix.as_slice(), &[10, 20, 30, 3]);
+    /// ```
+    pub fn try_map_indexed<E, F>(&mut self, mut mapping: F) -> StdResult<(), E>
+    where
+        F: FnMut(T, usize, usize) -> StdResult<T, E>,
+    {
+        for row in 0..self.get_number_of_rows() {
+            for column in 0..self.get_number_of_columns() {
+                unsafe {
+                    // Since we iterate over all rows and columns, they are always valid and we
+                    // don't have to check any invariants.
+                    let index: usize = self.get_index_unchecked(row, column);
+                    self.data[index] = mapping(self.data[index], row, column)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create a new matrix by combining the elements of `self` and `other` with the closure
+    /// `operation`, broadcasting `other` over all columns of `self` if it is a column vector.
+    ///
+    /// `other` must either have the same dimensions as `self`, or the same number of rows and a
+    /// single column. Otherwise, [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    fn zip_broadcast<F>(&self, other: &Matrix<T>, operation: F) -> Result<Matrix<T>>
+    where
+        F: Fn(T, T) -> T,
+    {
+        if self.rows != other.rows {
+            return Err(Error::DimensionMismatch);
+        }
+
+        if self.columns == other.columns {
+            return self.zip_map_into(other, operation);
+        }
+
+        if other.get_number_of_columns() != 1 {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let mut data: Vec<T> = Vec::with_capacity(self.data.len());
+        for (row, &other_element) in self
+            .data
+            .chunks_exact(self.get_number_of_columns())
+            .zip(&other.data)
+        {
+            data.extend(row.iter().map(|&element| operation(element, other_element)));
+        }
+
+        Ok(Matrix::from_vec_unchecked(self.rows, self.columns, data))
+    }
+
+    /// Create a new matrix by combining the elements of `self` and `other` at the same position
+    /// with the closure `mapping`. The elements of the new matrix may be of a different type than
+    /// the elements of the original matrices.
+    ///
+    /// The `mapping` closure has two parameters, in this order:
+    ///
+    /// 1. The value of the element of `self`.
+    /// 2. The value of the element of `other`.
+    ///
+    /// Both matrices must have the same dimensions. Otherwise, [`Error::DimensionMismatch`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// Compare two matrices element-wise:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let a: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.0, 3.0]).unwrap();
+    /// let b: Matrix<f64> = Matrix::from_slice(rows, columns, &[3.0, 2.0, 1.0]).unwrap();
+    ///
+    /// let greater: Matrix<bool> = a.zip_map_into(&b, |a, b| a > b).unwrap();
+    /// assert_eq!(greater.as_slice(), &[false, false, true]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn zip_map_into<U, F>(&self, other: &Matrix<T>, mapping: F) -> Result<Matrix<U>>
+    where
+        F: Fn(T, T) -> U,
+    {
+        if self.rows != other.rows || self.columns != other.columns {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let data: Vec<U> = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&element, &other_element)| mapping(element, other_element))
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        })
+    }
+
+    /// Create a new matrix by selecting for each position either the element of `self` or the
+    /// element of `other`. The element of `other` will be selected if `select_other` returns `true`
+    /// for the element of `self` and the element of `other` (in this order).
+    ///
+    /// Both matrices must have the same dimensions. Otherwise, [`Error::DimensionMismatch`] will be
+    /// returned.
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    fn zip_select<F>(&self, other: &Matrix<T>, select_other: F) -> Result<Matrix<T>>
+    where
+        F: Fn(T, T) -> bool,
+    {
+        self.zip_map_into(other, |element, other_element| {
+            if select_other(element, other_element) {
+                other_element
+            } else {
+                element
+            }
+        })
+    }
+
+    // endregion
+
+    // region Rearrangement
+
+    /// Create a new matrix whose columns alternate between the columns of this matrix and the
+    /// columns of the `other` matrix, starting with the first column of this matrix, e.g. to mix
+    /// the samples of two batches.
+    ///
+    /// Both matrices must have the same dimensions. Otherwise, an [`Error::DimensionMismatch`] will
+    /// be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let a: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 2, 4]).unwrap();
+    /// let b: Matrix<usize> = Matrix::from_slice(rows, columns, &[1, 3, 5]).unwrap();
+    ///
+    /// let interleaved: Matrix<usize> = a.interleave_columns(&b).unwrap();
+    /// assert_eq!(interleaved.get_number_of_columns(), 6);
+    /// assert_eq!(interleaved.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn interleave_columns(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.rows != other.rows || self.columns != other.columns {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let columns: usize = self
+            .get_number_of_columns()
+            .checked_mul(2)
+            .ok_or(Error::DimensionsTooLarge)?;
+        let columns: NonZeroUsize = NonZeroUsize::new(columns).unwrap();
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(self.rows, columns)?;
+
+        let mut data: Vec<T> = Vec::with_capacity(length);
+        for (element, other_element) in self.data.iter().zip(&other.data) {
+            data.push(*element);
+            data.push(*other_element);
+        }
+
+        Ok(Matrix::from_vec_unchecked(self.rows, columns, data))
+    }
+
+    /// Get a copy of the matrix padded to an `n x n` matrix, where `n` is the larger one of the
+    /// number of rows and the number of columns, e.g. for algorithms that only handle square
+    /// matrices.
+    ///
+    /// The rows or columns are appended after the existing ones and filled with `fill`. If the
+    /// matrix is already square, an unchanged copy will be returned.
+    ///
+    /// If `n * n` exceeds the maximum `usize` value, [`::std::usize::MAX`], an
+    /// [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[1, 2]).unwrap();
+    ///
+    /// let padded: Matrix<usize> = matrix.pad_to_square(0).unwrap();
+    /// assert_eq!(padded.get_number_of_rows(), 2);
+    /// assert_eq!(padded.as_slice(), &[1, 2, 0, 0]);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn pad_to_square(&self, fill: T) -> Result<Matrix<T>> {
+        let size: NonZeroUsize = self.rows.max(self.columns);
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(size, size)?;
+
+        let mut data: Vec<T> = Vec::with_capacity(length);
+        for row in self.data.chunks(self.get_number_of_columns()) {
+            data.extend_from_slice(row);
+            data.resize(data.len() + size.get() - row.len(), fill);
+        }
+        data.resize(length, fill);
+
+        Ok(Matrix::from_vec_unchecked(size, size, data))
+    }
+
+    /// Create a new matrix from the columns of this matrix at the given `indices`, in the given
+    /// order. Columns may be selected multiple times.
+    ///
+    /// All indices must be valid columns of this matrix. Otherwise, an [`Error::CellOutOfBounds`]
+    /// will be returned. If `indices` is empty, an [`Error::EmptyMatrix`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// let selected: Matrix<usize> = matrix.select_columns(&[2, 0]).unwrap();
+    /// assert_eq!(selected.get_number_of_rows(), 2);
+    /// assert_eq!(selected.get_number_of_columns(), 2);
+    /// assert_eq!(selected.as_slice(), &[2, 0, 5, 3]);
+    /// ```
+    ///
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    /// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+    pub fn select_columns(&self, indices: &[usize]) -> Result<Matrix<T>> {
+        let columns: NonZeroUsize = NonZeroUsize::new(indices.len()).ok_or(Error::EmptyMatrix)?;
+        if indices
+            .iter()
+            .any(|&column| column >= self.get_number_of_columns())
+        {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(self.rows, columns)?;
+        let mut data: Vec<T> = Vec::with_capacity(length);
+        for row in 0..self.get_number_of_rows() {
+            for &column in indices {
+                unsafe {
+                    // The row is always valid, and all columns have been checked.
+                    data.push(self.get_unchecked(row, column));
+                }
+            }
+        }
+
+        Ok(Matrix::from_vec_unchecked(self.rows, columns, data))
+    }
+
+    /// Create a new matrix from the rows of this matrix at the given `indices`, in the given
+    /// order. Rows may be selected multiple times.
+    ///
+    /// All indices must be valid rows of this matrix. Otherwise, an [`Error::CellOutOfBounds`]
+    /// will be returned. If `indices` is empty, an [`Error::EmptyMatrix`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// let selected: Matrix<usize> = matrix.select_rows(&[2, 0]).unwrap();
+    /// assert_eq!(selected.get_number_of_rows(), 2);
+    /// assert_eq!(selected.get_number_of_columns(), 2);
+    /// assert_eq!(selected.as_slice(), &[4, 5, 0, 1]);
+    /// ```
+    ///
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    /// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+    pub fn select_rows(&self, indices: &[usize]) -> Result<Matrix<T>> {
+        let rows: NonZeroUsize = NonZeroUsize::new(indices.len()).ok_or(Error::EmptyMatrix)?;
+        if indices.iter().any(|&row| row >= self.get_number_of_rows()) {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        let length: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, self.columns)?;
+        let mut data: Vec<T> = Vec::with_capacity(length);
+        for &row in indices {
+            let start: usize = row * self.get_number_of_columns();
+            data.extend_from_slice(&self.data[start..start + self.get_number_of_columns()]);
+        }
+
+        Ok(Matrix::from_vec_unchecked(rows, self.columns, data))
+    }
+
+    /// Split the matrix into a feature matrix containing all but the last column and a label
+    /// matrix containing only the last column, e.g. for data sets storing the label of each row in
+    /// the last column.
+    ///
+    /// If the matrix only has a single column, the feature matrix would not contain any elements
+    /// and an [`Error::EmptyResult`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// let (features, labels) = matrix.split_last_column().unwrap();
+    /// assert_eq!(features.as_slice(), &[0, 1, 3, 4]);
+    /// assert_eq!(labels.as_slice(), &[2, 5]);
+    /// ```
+    ///
+    /// [`Error::EmptyResult`]: enum.Error.html#variant.EmptyResult
+    pub fn split_last_column(&self) -> Result<(Matrix<T>, Matrix<T>)> {
+        let feature_columns: NonZeroUsize =
+            NonZeroUsize::new(self.get_number_of_columns() - 1).ok_or(Error::EmptyResult)?;
+        let label_columns: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+
+        let length: usize = self.get_number_of_rows() * feature_columns.get();
+        let mut features: Vec<T> = Vec::with_capacity(length);
+        let mut labels: Vec<T> = Vec::with_capacity(self.get_number_of_rows());
+        for row in self.data.chunks(self.get_number_of_columns()) {
+            features.extend_from_slice(&row[..feature_columns.get()]);
+            labels.push(row[feature_columns.get()]);
+        }
+
+        Ok((
+            Matrix::from_vec_unchecked(self.rows, feature_columns, features),
+            Matrix::from_vec_unchecked(self.rows, label_columns, labels),
+        ))
+    }
+
+    /// Get a copy of the matrix without its border of rows and columns that only contain the
+    /// default value of `T` (e.g. `0` for numbers), e.g. to remove the padding after a
+    /// convolution.
+    ///
+    /// Only leading and trailing rows and columns are removed. Rows and columns in between that
+    /// only contain the default value are kept. If all elements are the default value, nothing
+    /// would remain and an [`Error::EmptyResult`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+    /// let data: [usize; 12] = [0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0];
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// let stripped: Matrix<usize> = matrix.strip_zero_border().unwrap();
+    /// assert_eq!(stripped.get_number_of_rows(), 1);
+    /// assert_eq!(stripped.as_slice(), &[1, 0, 2]);
+    /// ```
+    ///
+    /// [`Error::EmptyResult`]: enum.Error.html#variant.EmptyResult
+    pub fn strip_zero_border(&self) -> Result<Matrix<T>>
+    where
+        T: Default + PartialEq,
+    {
+        let default: T = T::default();
+        let columns: usize = self.get_number_of_columns();
+        let non_default: Vec<usize> = self
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_index, element)| **element != default)
+            .map(|(index, _element)| index)
+            .collect();
+
+        // The non-default elements are in row-major order, thus the first and the last one are in
+        // the first and the last row to keep.
+        let (first, last): (usize, usize) = match (non_default.first(), non_default.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return Err(Error::EmptyResult),
+        };
+        let top: usize = first / columns;
+        let bottom: usize = last / columns;
+        let left: usize = non_default
+            .iter()
+            .map(|index| index % columns)
+            .min()
+            .unwrap_or(0);
+        let right: usize = non_default
+            .iter()
+            .map(|index| index % columns)
+            .max()
+            .unwrap_or(0);
+
+        let mut data: Vec<T> = Vec::with_capacity((bottom - top + 1) * (right - left + 1));
+        for row in top..=bottom {
+            let start: usize = row * columns;
+            data.extend_from_slice(&self.data[start + left..=start + right]);
+        }
+
+        // The ranges are inclusive, thus they contain at least one element.
+        let rows: NonZeroUsize = NonZeroUsize::new(bottom - top + 1).ok_or(Error::EmptyResult)?;
+        let columns: NonZeroUsize =
+            NonZeroUsize::new(right - left + 1).ok_or(Error::EmptyResult)?;
+        Ok(Matrix::from_vec_unchecked(rows, columns, data))
+    }
+
+    /// Randomly split the columns of the matrix into a training and a test matrix, using the given
+    /// random number generator `rng`. The training matrix is returned first.
+    ///
+    /// Each column is assigned to exactly one of the two matrices. The test matrix gets
+    /// `test_fraction` of the columns, rounded to the nearest integer, but each matrix gets at
+    /// least one column. Within both matrices, the columns keep their original relative order.
+    ///
+    /// `test_fraction` must be within `(0, 1)`. Otherwise, an [`Error::InvalidRange`] will be
+    /// returned. If the matrix has only a single column, it cannot be split and an
+    /// [`Error::EmptyMatrix`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+    /// let data: [usize; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let (train, test) = matrix.train_test_split_columns(0.4, &mut rng).unwrap();
+    /// assert_eq!(train.get_number_of_columns(), 3);
+    /// assert_eq!(test.get_number_of_columns(), 2);
+    /// ```
+    ///
+    /// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn train_test_split_columns<R>(
+        &self,
+        test_fraction: f64,
+        rng: &mut R,
+    ) -> Result<(Matrix<T>, Matrix<T>)>
+    where
+        R: Rng + ?Sized,
+    {
+        let (train_columns, test_columns): (Vec<usize>, Vec<usize>) =
+            split_column_indices(self.get_number_of_columns(), test_fraction, rng)?;
+
+        Ok((
+            self.select_columns(&train_columns)?,
+            self.select_columns(&test_columns)?,
+        ))
+    }
+
+    /// Randomly split the columns of the matrix and the columns of the `other` matrix in the same
+    /// way into training and test matrices, using the given random number generator `rng`.
+    ///
+    /// The matrices are returned in the order: training matrix of `self`, test matrix of `self`,
+    /// training matrix of `other`, test matrix of `other`. Columns that have been at the same
+    /// position in both matrices are again at the same position in the resulting matrices. This is
+    /// useful to keep the samples in a dataset aligned with their labels. Otherwise, the split
+    /// works as described for [`train_test_split_columns`].
+    ///
+    /// Both matrices must have the same number of columns. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// [`train_test_split_columns`]: #method.train_test_split_columns
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    #[allow(clippy::type_complexity)]
+    pub fn train_test_split_columns_paired<U, R>(
+        &self,
+        other: &Matrix<U>,
+        test_fraction: f64,
+        rng: &mut R,
+    ) -> Result<(Matrix<T>, Matrix<T>, Matrix<U>, Matrix<U>)>
+    where
+        U: Copy,
+        R: Rng + ?Sized,
+    {
+        if self.get_number_of_columns() != other.get_number_of_columns() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let (train_columns, test_columns): (Vec<usize>, Vec<usize>) =
+            split_column_indices(self.get_number_of_columns(), test_fraction, rng)?;
+
+        Ok((
+            self.select_columns(&train_columns)?,
+            self.select_columns(&test_columns)?,
+            other.select_columns(&train_columns)?,
+            other.select_columns(&test_columns)?,
+        ))
+    }
+
+    // endregion
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
+{
+    /// Compute the matrix product of `self` and `other` and return the result.
+    ///
+    /// Unlike the `*` operator, which computes the element-wise (Hadamard) product, this computes
+    /// the matrix product known from linear algebra. It can also be called as [`mm`].
+    ///
+    /// The number of columns in `self` must be equal to the number of rows in `other`. Otherwise,
+    /// [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// The resulting matrix will have the dimensions `self.rows x other.columns`. If these
+    /// dimensions would exceed the maximum size of matrices, [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use reural_network::Result;
+    /// # use reural_network::matrix::Matrix;
+    /// #
+    /// // `m1` (2x3):
+    /// // [1   2   3]
+    /// // [4   5   6]
+    /// let rows_m1 = NonZeroUsize::new(2).unwrap();
+    /// let columns_m1 = NonZeroUsize::new(3).unwrap();
+    /// let data_m1: [usize; 6] = [1, 2, 3, 4, 5, 6];
+    /// let m1: Matrix<usize> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
+    ///
+    /// // `m2` (3x2):
+    /// // [7    8 ]
+    /// // [9    10]
+    /// // [11   12]
+    /// let rows_m2 = NonZeroUsize::new(3).unwrap();
+    /// let columns_m2 = NonZeroUsize::new(2).unwrap();
+    /// let data_m2: [usize; 6] = [7, 8, 9, 10, 11, 12];
+    /// let m2: Matrix<usize> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
+    ///
+    /// // Calculate `m3` as the matrix product of `m1` and `m2`.
+    /// // `m3` (2x2):
+    /// // [58    64 ]
+    /// // [139   154]
+    /// let m3: Matrix<usize> = m1.matrix_mul(&m2).unwrap();
+    /// assert_eq!(m3.get_number_of_rows(), 2);
+    /// assert_eq!(m3.get_number_of_columns(), 2);
+    /// assert_eq!(m3.as_slice(), &[58, 64, 139, 154]);
+    /// ```
+    ///
+    /// [`mm`]: #method.mm
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn matrix_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        // Cloning `Copy` elements is just copying them.
+        self.matrix_mul_cloned(other)
+    }
+
+    /// Compute the matrix product of `self` and `other` and return the result.
+    ///
+    /// This is a short alias for [`matrix_mul`]. Note that the `*` operator computes the
+    /// element-wise (Hadamard) product instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let row: Matrix<i32> = Matrix::from_slice(rows, columns, &[1, 2]).unwrap();
+    /// let column: Matrix<i32> = row.transpose();
+    ///
+    /// let product: Matrix<i32> = row.mm(&column).unwrap();
+    /// assert_eq!(product.as_slice(), &[5]);
+    /// ```
+    ///
+    /// [`matrix_mul`]: #method.matrix_mul
+    pub fn mm(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        self.matrix_mul(other)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<T, Output = T> + Mul<T, Output = T> + Clone,
+{
+    /// Compute the matrix product of `self` and `other` and return the result, cloning the
+    /// elements as needed.
+    ///
+    /// This works exactly like [`matrix_mul`], but only requires the elements to implement `Clone`
+    /// instead of `Copy`, e.g. for arbitrary-precision numbers.
+    ///
+    /// The number of columns in `self` must be equal to the number of rows in `other`. Otherwise,
+    /// [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// The resulting matrix will have the dimensions `self.rows x other.columns`. If these
+    /// dimensions would exceed the maximum size of matrices, [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let row: Matrix<i64> = Matrix::from_slice(one, two, &[1, 2]).unwrap();
+    /// let square: Matrix<i64> = Matrix::from_slice(two, two, &[3, 4, 5, 6]).unwrap();
+    ///
+    /// let product: Matrix<i64> = row.matrix_mul_cloned(&square).unwrap();
+    /// assert_eq!(product.as_slice(), &[13, 16]);
+    /// assert_eq!(product, row.matrix_mul(&square).unwrap());
+    /// ```
+    ///
+    /// [`matrix_mul`]: #method.matrix_mul
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn matrix_mul_cloned(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.get_number_of_columns() != other.get_number_of_rows() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        // Ensure that the dimensions of the result matrix do not exceed the maximum size.
+        let rows: NonZeroUsize = self.rows;
+        let columns: NonZeroUsize = other.columns;
+        let size: usize = Matrix::<T>::get_length_from_rows_and_columns(rows, columns)?;
+
+        let mut result = Matrix {
+            rows,
+            columns,
+            data: Vec::with_capacity(size),
+        };
+
+        for row in 0..result.get_number_of_rows() {
+            for column in 0..result.get_number_of_columns() {
+                // All row and column values are valid so it is safe to use these unsafe and
+                // unchecked methods.
+                unsafe {
+                    // Calculate the sum of products. Since there is no general neutral element
+                    // of addition for `T` (e.g., 0 would be one for all number types), calculate
+                    // the first product outside the loop to initialize the variable without special
+                    // cases inside the loop. There must be at least this first element since we can
+                    // not have matrices without any elements.
+                    let mut element: T = self.data[self.get_index_unchecked(row, 0)].clone()
+                        * other.data[other.get_index_unchecked(0, column)].clone();
+
+                    for i in 1..self.get_number_of_columns() {
+                        let product: T = self.data[self.get_index_unchecked(row, i)].clone()
+                            * other.data[other.get_index_unchecked(i, column)].clone();
+
+                        // We don't want to require `T` to implement `AddAssign`, but only the
+                        // simpler `Add`.
+                        element = element + product;
+                    }
+
+                    // Set the element in the result matrix. Since we are iterating over the
+                    // elements in row-major format, the index at which `element` will be inserted
+                    // will be correct.
+                    result.data.push(element);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Matrix<f64> {
+    // region Initialization
+
+    /// Create a new matrix with the given dimensions and random elements drawn from the given
+    /// `distribution`, using the random number generator `rng`, e.g. to initialize weights from a
+    /// normal distribution.
+    ///
+    /// The elements are drawn in row-major order.
+    ///
+    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use rand::distributions::Uniform;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let distribution: Uniform<f64> = Uniform::new(-0.5, 0.5);
+    /// let mut rng = rand::thread_rng();
+    /// let matrix: Matrix<f64> =
+    ///     Matrix::from_distribution(rows, columns, &distribution, &mut rng).unwrap();
+    ///
+    /// assert!(matrix.as_slice().iter().all(|element| element.abs() <= 0.5));
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn from_distribution<D, R>(
+        rows: NonZeroUsize,
+        columns: NonZeroUsize,
+        distribution: &D,
+        rng: &mut R,
+    ) -> Result<Matrix<f64>>
+    where
+        D: Distribution<f64> + ?Sized,
+        R: Rng + ?Sized,
+    {
+        let length: usize = Matrix::<f64>::get_length_from_rows_and_columns(rows, columns)?;
+        let mut data: Vec<f64> = Vec::with_capacity(length);
+        data.resize_with(length, || distribution.sample(rng));
+
+        Ok(Matrix {
+            rows,
+            columns,
+            data,
+        })
+    }
+
+    /// Create a new matrix with the given dimensions and random elements in the inclusive range
+    /// `[0.0, 1.0]` (i.e., including both `0.0` and `1.0`).
+    ///
+    /// The elements are drawn from `rand::thread_rng`, unless a default seed has been set on the
+    /// current thread with [`set_default_seed`]. To use a different distribution or random number
+    /// generator, use [`from_distribution`].
+    ///
+    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// A `2x3` matrix with a default value of `0.25` for all elements can be created with the
+    /// following lines of code:
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows = NonZeroUsize::new(2).unwrap();
+    /// let columns = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_random(rows, columns).unwrap();
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    /// [`from_distribution`]: #method.from_distribution
+    /// [`set_default_seed`]: ../fn.set_default_seed.html
+    pub fn from_random(rows: NonZeroUsize, columns: NonZeroUsize) -> Result<Matrix<f64>> {
+        // Get random data in the range of [0.0, 1.0].
+        let uniform: Uniform<f64> = Uniform::new_inclusive(0.0, 1.0);
+        random::with_default_rng(|rng| Matrix::from_distribution(rows, columns, &uniform, rng))
+    }
+
+    /// Create a new matrix with the given dimensions and random elements in the inclusive range
+    /// `[0.0, 1.0]`, drawn from a random number generator seeded with the given `seed`, e.g. to
+    /// reproduce a training run.
+    ///
+    /// The same `seed` always results in the same elements. In contrast to [`from_random`], this
+    /// is not affected by [`set_default_seed`].
+    ///
+    /// The product of the number of `rows` and the number of `columns` must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let first: Matrix<f64> = Matrix::from_random_seeded(rows, columns, 42).unwrap();
+    /// let second: Matrix<f64> = Matrix::from_random_seeded(rows, columns, 42).unwrap();
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    /// [`from_random`]: #method.from_random
+    /// [`set_default_seed`]: ../fn.set_default_seed.html
+    pub fn from_random_seeded(
+        rows: NonZeroUsize,
+        columns: NonZeroUsize,
+        seed: u64,
+    ) -> Result<Matrix<f64>> {
+        let uniform: Uniform<f64> = Uniform::new_inclusive(0.0, 1.0);
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        Matrix::from_distribution(rows, columns, &uniform, &mut rng)
+    }
+
+    /// Create a new `size x size` matrix containing a two-dimensional Gaussian kernel with the
+    /// standard deviation `sigma`, centered in the middle of the matrix. The kernel is normalized
+    /// so that all its elements sum up to `1.0`.
+    ///
+    /// `sigma` must be greater than `0.0`. Otherwise, an [`Error::InvalidRange`] will be returned.
+    /// If the square of `size` exceeds the maximum `usize` value, [`::std::usize::MAX`], an
+    /// [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let kernel: Matrix<f64> = Matrix::gaussian_kernel(size, 1.0).unwrap();
+    ///
+    /// // The center has the largest weight.
+    /// let center: f64 = kernel.get(1, 1).unwrap();
+    /// assert!(kernel.as_slice().iter().all(|&element| element <= center));
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn gaussian_kernel(size: NonZeroUsize, sigma: f64) -> Result<Matrix<f64>> {
+        if sigma.is_nan() || sigma <= 0.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        let mut kernel: Matrix<f64> = Matrix::new(size, size, 0.0)?;
+
+        // For even sizes, the center lies between the two middle rows and columns, thus even the
+        // elements closest to it have a squared distance of `0.5`.
+        let center: f64 = (size.get() - 1) as f64 / 2.0;
+        let min_squared_distance: f64 = if size.get().is_multiple_of(2) {
+            0.5
+        } else {
+            0.0
+        };
+        let denominator: f64 = 2.0 * sigma * sigma;
+        kernel.map(|_element, row, column| {
+            let row_distance: f64 = row as f64 - center;
+            let column_distance: f64 = column as f64 - center;
+            let squared_distance: f64 =
+                row_distance * row_distance + column_distance * column_distance;
+
+            // Compute the exponent relative to the smallest distance so that the terms do not all
+            // underflow to zero for small values of `sigma`. This is a constant factor, which the
+            // normalization removes again.
+            (-(squared_distance - min_squared_distance) / denominator).exp()
+        });
+
+        // The elements closest to the center are exactly `1.0` before the normalization, so the
+        // sum is never zero.
+        let sum: f64 = kernel.data.iter().sum();
+        kernel /= sum;
+
+        Ok(kernel)
+    }
+
+    /// Create a new `n x 1` column vector that is the one-hot encoding of the given `class` out of
+    /// `n` classes, where `n` is `number_of_classes`: the element in the row `class` is `1.0`, all
+    /// other elements are `0.0`.
+    ///
+    /// The `class` must be less than `number_of_classes`. Otherwise, an [`Error::CellOutOfBounds`]
+    /// will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let number_of_classes: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+    /// let target: Matrix<f64> = Matrix::one_hot(2, number_of_classes).unwrap();
+    /// assert_eq!(target.get_number_of_rows(), 4);
+    /// assert_eq!(target.get_number_of_columns(), 1);
+    /// assert_eq!(target.as_slice(), &[0.0, 0.0, 1.0, 0.0]);
+    /// ```
+    ///
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    pub fn one_hot(class: usize, number_of_classes: NonZeroUsize) -> Result<Matrix<f64>> {
+        if class >= number_of_classes.get() {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        let mut matrix: Matrix<f64> =
+            Matrix::new(number_of_classes, NonZeroUsize::new(1).unwrap(), 0.0)?;
+        matrix.data[class] = 1.0;
+
+        Ok(matrix)
+    }
+
+    /// Create a new `n x l` matrix whose columns are the one-hot encodings of the given `labels`
+    /// out of `n` classes, where `n` is `number_of_classes` and `l` is the number of labels. See
+    /// [`one_hot`] for the encoding of a single label.
+    ///
+    /// There must be at least one label. Otherwise, an [`Error::EmptyMatrix`] will be returned.
+    /// Each label must be less than `number_of_classes`. Otherwise, an [`Error::CellOutOfBounds`]
+    /// will be returned. If the dimensions of the matrix would exceed the maximum `usize` value,
+    /// [`::std::usize::MAX`], an [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let number_of_classes: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let targets: Matrix<f64> = Matrix::one_hot_batch(&[0, 2], number_of_classes).unwrap();
+    /// assert_eq!(targets.get_number_of_rows(), 3);
+    /// assert_eq!(targets.get_number_of_columns(), 2);
+    /// assert_eq!(targets.as_slice(), &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+    /// ```
+    ///
+    /// [`one_hot`]: #method.one_hot
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::CellOutOfBounds`]: enum.Error.html#variant.CellOutOfBounds
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    /// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+    pub fn one_hot_batch(labels: &[usize], number_of_classes: NonZeroUsize) -> Result<Matrix<f64>> {
+        let columns: NonZeroUsize = match NonZeroUsize::new(labels.len()) {
+            Some(columns) => columns,
+            None => return Err(Error::EmptyMatrix),
+        };
+
+        if labels.iter().any(|&label| label >= number_of_classes.get()) {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        let mut matrix: Matrix<f64> = Matrix::new(number_of_classes, columns, 0.0)?;
+        for (column, &label) in labels.iter().enumerate() {
+            unsafe {
+                // All labels have been checked to be valid rows.
+                let index: usize = matrix.get_index_unchecked(label, column);
+                matrix.data[index] = 1.0;
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// Create a new `n x n` permutation matrix, i.e. an identity matrix whose rows have been
+    /// randomly shuffled using the given random number generator `rng`.
+    ///
+    /// Multiplying another matrix with `n` rows from the left with this matrix will permute the
+    /// rows of the other matrix accordingly.
+    ///
+    /// The square of `n` must not exceed the maximum `usize` value, [`::std::usize::MAX`].
+    /// Otherwise, an [`Error::DimensionsTooLarge`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let n: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let mut rng = rand::thread_rng();
+    /// let permutation: Matrix<f64> = Matrix::random_permutation(n, &mut rng).unwrap();
+    ///
+    /// // Each row and each column contains exactly one `1.0`.
+    /// let sum: f64 = permutation.as_slice().iter().sum();
+    /// assert_eq!(sum, 3.0);
+    /// ```
+    ///
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn random_permutation<R>(n: NonZeroUsize, rng: &mut R) -> Result<Matrix<f64>>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut matrix: Matrix<f64> = Matrix::new(n, n, 0.0)?;
+
+        // Row `i` of the permutation matrix selects row `permutation[i]` of the other matrix.
+        let mut permutation: Vec<usize> = (0..n.get()).collect();
+        permutation.shuffle(rng);
+
+        for (row, column) in permutation.into_iter().enumerate() {
+            unsafe {
+                // Both the row and the column are in `[0, n)` and thus always valid.
+                let index: usize = matrix.get_index_unchecked(row, column);
+                matrix.data[index] = 1.0;
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    // endregion
+
+    // region Properties
+
+    /// Determine if all elements in the matrix are finite, i.e. neither infinite nor `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    ///
+    /// let finite: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.5, -1.0]).unwrap();
+    /// assert!(finite.is_finite());
+    ///
+    /// let infinite: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.5, 1.0 / 0.0]).unwrap();
+    /// assert!(!infinite.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.data.iter().all(|element| element.is_finite())
+    }
+
+    /// Determine if the matrix is orthogonal, i.e. if it is square and its columns are orthonormal
+    /// vectors. This is the case if the product of the transposed matrix and the matrix is the
+    /// identity matrix.
+    ///
+    /// Each element of the product may differ from the identity matrix by at most `tolerance`.
+    /// Non-square matrices are never orthogonal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let (sin, cos): (f64, f64) = 0.5_f64.sin_cos();
+    /// let rotation: Matrix<f64> = Matrix::from_slice(size, size, &[cos, -sin, sin, cos]).unwrap();
+    /// assert!(rotation.is_orthogonal(1e-12));
+    ///
+    /// let scaled: Matrix<f64> = &rotation * 2.0;
+    /// assert!(!scaled.is_orthogonal(1e-12));
+    /// ```
+    pub fn is_orthogonal(&self, tolerance: f64) -> bool {
+        let size: usize = match self.as_square() {
+            Ok(size) => size,
+            Err(_) => return false,
+        };
+
+        let product: Matrix<f64> = match self.transpose().matrix_mul(self) {
+            Ok(product) => product,
+            Err(_) => return false,
+        };
+
+        product.data.iter().enumerate().all(|(index, element)| {
+            let expected: f64 = if index / size == index % size {
+                1.0
+            } else {
+                0.0
+            };
+            (element - expected).abs() <= tolerance
+        })
+    }
+
+    // endregion
+
+    // region Element Operations
+
+    /// Get a copy of the matrix with independent noise drawn from the normal distribution
+    /// `N(0, std_dev^2)` added to each element, using the given random number generator `rng`,
+    /// e.g. to augment the inputs during training.
+    ///
+    /// The standard deviation `std_dev` must be finite and not negative. Otherwise, an
+    /// [`Error::InvalidRange`] will be returned. With a standard deviation of `0.0`, the copy is
+    /// identical to the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let noisy: Matrix<f64> = matrix.add_gaussian_noise(0.1, &mut rng).unwrap();
+    /// assert_eq!(noisy.get_number_of_rows(), 2);
+    /// assert_eq!(noisy.get_number_of_columns(), 3);
+    /// ```
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn add_gaussian_noise<R>(&self, std_dev: f64, rng: &mut R) -> Result<Matrix<f64>>
+    where
+        R: Rng + ?Sized,
+    {
+        let noise: Normal = Normal::new(0.0, std_dev)?;
+        let data: Vec<f64> = self
+            .data
+            .iter()
+            .map(|element| element + noise.sample(rng))
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        })
+    }
+
+    /// Apply inverted dropout with the given precomputed `mask` to a copy of the matrix, i.e. set
+    /// each element for which the corresponding element in the `mask` is `false` to `0.0`, and
+    /// divide each element for which it is `true` by the probability `keep_probability` of keeping
+    /// an element. The scaling keeps the expected value of each element unchanged.
+    ///
+    /// Since the mask is computed outside of this method, the dropout can be made reproducible,
+    /// e.g. by generating the mask with a seeded random number generator.
+    ///
+    /// The dimensions of the mask must match the dimensions of the matrix. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned. `keep_probability` must be within `(0, 1]`.
+    /// Otherwise, an [`Error::InvalidRange`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    /// let keep: [bool; 4] = [true, false, true, false];
+    /// let mask: Matrix<bool> = Matrix::from_slice(rows, columns, &keep).unwrap();
+    ///
+    /// let dropped: Matrix<f64> = matrix.apply_dropout(&mask, 0.5).unwrap();
+    /// assert_eq!(dropped.as_slice(), &[2.0, 0.0, 6.0, 0.0]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn apply_dropout(&self, mask: &Matrix<bool>, keep_probability: f64) -> Result<Matrix<f64>> {
+        if self.rows != mask.rows || self.columns != mask.columns {
+            return Err(Error::DimensionMismatch);
+        }
+
+        if keep_probability.is_nan() || keep_probability <= 0.0 || keep_probability > 1.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        let data: Vec<f64> = self
+            .data
+            .iter()
+            .zip(&mask.data)
+            .map(|(element, &keep)| {
+                if keep {
+                    element / keep_probability
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        })
+    }
+
+    /// Get a copy of the matrix with salt-and-pepper noise, using the given random number
+    /// generator `rng`: each element is replaced with the given `probability`, with equal chance
+    /// either by `0.0` (pepper) or by the maximum element of the matrix (salt).
+    ///
+    /// The `probability` must be within `[0.0, 1.0]`. Otherwise, an [`Error::InvalidRange`] will
+    /// be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(size, size, &[0.5, 1.0, 2.0, 4.0]).unwrap();
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let noisy: Matrix<f64> = matrix.salt_and_pepper(1.0, &mut rng).unwrap();
+    /// assert!(noisy.as_slice().iter().all(|&x| x == 0.0 || x == 4.0));
+    /// ```
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn salt_and_pepper<R>(&self, probability: f64, rng: &mut R) -> Result<Matrix<f64>>
+    where
+        R: Rng + ?Sized,
+    {
+        if probability.is_nan() || !(0.0..=1.0).contains(&probability) {
+            return Err(Error::InvalidRange);
+        }
+
+        let maximum: f64 = self.data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let data: Vec<f64> = self
+            .data
+            .iter()
+            .map(|&element| {
+                if !rng.gen_bool(probability) {
+                    element
+                } else if rng.gen_bool(0.5) {
+                    maximum
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        })
+    }
+
+    /// Divide the elements of the matrix by the given `temperature`, then apply the softmax
+    /// function to each column of the matrix and return the result, e.g. to control how sharp the
+    /// predicted probabilities are. Afterwards, the elements of each column are positive and sum
+    /// up to `1.0`.
+    ///
+    /// A temperature of `1.0` yields the plain softmax function. Higher temperatures yield flatter
+    /// distributions, lower temperatures yield distributions closer to the maximum.
+    ///
+    /// The `temperature` must be greater than `0.0`. Otherwise, an [`Error::InvalidRange`] will
+    /// be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let logits: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 3.0]).unwrap();
+    ///
+    /// let sharp: Matrix<f64> = logits.softmax_columns_with_temperature(0.5).unwrap();
+    /// let flat: Matrix<f64> = logits.softmax_columns_with_temperature(10.0).unwrap();
+    /// assert!(sharp.as_slice()[1] > flat.as_slice()[1]);
+    /// ```
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    pub fn softmax_columns_with_temperature(&self, temperature: f64) -> Result<Matrix<f64>> {
+        if temperature.is_nan() || temperature <= 0.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(Activation::Softmax.apply(&(self / temperature)))
+    }
+
+    /// Apply the softmax function to each row of the matrix and return the result, e.g. to get
+    /// attention weights. Afterwards, the elements of each row are positive and sum up to `1.0`.
+    ///
+    /// Before exponentiating, the maximum of each row is subtracted from its elements to prevent
+    /// overflows. This does not change the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.0, 0.0, 1.0, 1.0]).unwrap();
+    ///
+    /// let softmax: Matrix<f64> = matrix.softmax_rows();
+    /// assert_eq!(softmax.as_slice(), &[0.5, 0.5, 0.5, 0.5]);
+    /// ```
+    pub fn softmax_rows(&self) -> Matrix<f64> {
+        let mut data: Vec<f64> = Vec::with_capacity(self.len());
+        for row in self.data.chunks_exact(self.get_number_of_columns()) {
+            let maximum: f64 = row.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let start: usize = data.len();
+            data.extend(row.iter().map(|element| (element - maximum).exp()));
+
+            let sum: f64 = data[start..].iter().sum();
+            for element in &mut data[start..] {
+                *element /= sum;
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data,
+        }
+    }
+
+    // endregion
+
+    // region Linear Algebra
+
+    /// Find the dominant eigenvalue (i.e. the eigenvalue with the largest absolute value) of the
+    /// matrix and a corresponding eigenvector of unit length using power iteration.
+    ///
+    /// Starting from a vector of ones, the vector is repeatedly multiplied by the matrix and
+    /// normalized, for at most `iterations` iterations. The iteration has converged once the
+    /// vector changes by less than `tolerance` (ignoring its sign). The eigenvalue is then computed
+    /// as the Rayleigh quotient of the eigenvector. The eigenvector is returned as an `n x 1`
+    /// column vector.
+    ///
+    /// The matrix must be square. Otherwise, an [`Error::ShapeMismatch`] will be returned.
+    /// `iterations` must be at least `1` and `tolerance` must be greater than `0.0`. Otherwise,
+    /// an [`Error::InvalidRange`] will be returned. If the iteration does not converge (e.g.
+    /// because there are two dominant eigenvalues with the same absolute value), an
+    /// [`Error::NoConvergence`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(size, size, &[2.0, 1.0, 1.0, 2.0]).unwrap();
+    ///
+    /// let (eigenvalue, eigenvector) = matrix.dominant_eigen(100, 1e-10).unwrap();
+    /// assert!((eigenvalue - 3.0).abs() < 1e-8);
+    /// assert!((eigenvector.get(0, 0).unwrap() - 0.5_f64.sqrt()).abs() < 1e-8);
+    /// assert!((eigenvector.get(1, 0).unwrap() - 0.5_f64.sqrt()).abs() < 1e-8);
+    /// ```
+    ///
+    /// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+    /// [`Error::NoConvergence`]: enum.Error.html#variant.NoConvergence
+    /// [`Error::ShapeMismatch`]: enum.Error.html#variant.ShapeMismatch
+    pub fn dominant_eigen(&self, iterations: usize, tolerance: f64) -> Result<(f64, Matrix<f64>)> {
+        let size: usize = self.get_number_of_rows();
+        self.ensure_dimensions(size, size)?;
+        if iterations == 0 || tolerance.is_nan() || tolerance <= 0.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let mut vector: Matrix<f64> = Matrix::new(self.rows, one, 1.0 / (size as f64).sqrt())?;
+        for _ in 0..iterations {
+            let mut next: Matrix<f64> = self.matrix_mul(&vector)?;
+            let norm: f64 = next.frobenius_norm();
+
+            // The vector is in the null space of the matrix, i.e. it is an eigenvector with the
+            // eigenvalue `0.0`.
+            if norm == 0.0 {
+                return Ok((0.0, vector));
+            }
+
+            next.scale_in_place(1.0 / norm);
+
+            // For negative eigenvalues, the sign of the vector flips in each iteration.
+            let dot_product: f64 = next.data.iter().zip(&vector.data).map(|(x, y)| x * y).sum();
+            let sign: f64 = if dot_product < 0.0 { -1.0 } else { 1.0 };
+            let difference: f64 = next
+                .data
+                .iter()
+                .zip(&vector.data)
+                .map(|(x, y)| (x - sign * y).abs())
+                .fold(0.0, f64::max);
+            vector = next;
+
+            if difference < tolerance {
+                let image: Matrix<f64> = self.matrix_mul(&vector)?;
+                let eigenvalue: f64 = vector
+                    .data
+                    .iter()
+                    .zip(&image.data)
+                    .map(|(x, y)| x * y)
+                    .sum();
+                return Ok((eigenvalue, vector));
+            }
+        }
+
+        Err(Error::NoConvergence)
+    }
+
+    // endregion
+
+    // region Norms
+
+    /// Compute the Frobenius norm of the matrix, i.e. the square root of the sum of the squares of
+    /// all elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[3.0, -4.0]).unwrap();
+    ///
+    /// assert_eq!(matrix.frobenius_norm(), 5.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        self.sum_of_squares().sqrt()
+    }
+
+    /// Compute the L-infinity norm of the matrix, i.e. the maximum absolute value of all elements.
+    ///
+    /// Since it is cheap to compute, it is e.g. useful to detect diverging values during the
+    /// training. If any element is `NaN`, the result will be `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[-3.0, 1.0, 2.0]).unwrap();
+    ///
+    /// assert_eq!(matrix.l_infinity_norm(), 3.0);
+    /// ```
+    pub fn l_infinity_norm(&self) -> f64 {
+        self.data.iter().fold(0.0, |norm: f64, element| {
+            if element.is_nan() || norm.is_nan() {
+                f64::NAN
+            } else {
+                norm.max(element.abs())
+            }
+        })
+    }
+
+    /// Compute the sum of the squares of all elements in the matrix, e.g. for an L2
+    /// regularization penalty. This is the square of the [`frobenius_norm`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[3.0, -4.0]).unwrap();
+    ///
+    /// assert_eq!(matrix.sum_of_squares(), 25.0);
+    /// ```
+    ///
+    /// [`frobenius_norm`]: #method.frobenius_norm
+    pub fn sum_of_squares(&self) -> f64 {
+        self.data.iter().map(|element| element * element).sum()
+    }
+
+    // endregion
+
+    // region Statistics
+
+    /// Compute the sample covariance matrix of the data in the matrix, treating each row as a
+    /// feature and each column as an observation of all features.
+    ///
+    /// The result is a symmetric `f x f` matrix where `f` is the number of features (i.e. rows).
+    /// The element at row `i` and column `j` is the covariance between the features `i` and `j`,
+    /// and the diagonal contains the variance of each feature. The data is centered on the mean of
+    /// each feature first, and the sum of the products is divided by `n - 1` where `n` is the
+    /// number of observations (i.e. columns).
+    ///
+    /// If there is only a single observation, the covariance is not defined and an
+    /// [`Error::DivisionByZero`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let features: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let observations: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [f64; 6] = [1.0, 2.0, 3.0, 2.0, 4.0, 6.0];
+    /// let matrix: Matrix<f64> = Matrix::from_slice(features, observations, &data).unwrap();
+    ///
+    /// let covariance: Matrix<f64> = matrix.covariance_matrix().unwrap();
+    /// assert_eq!(covariance.as_slice(), &[1.0, 2.0, 2.0, 4.0]);
+    /// ```
+    ///
+    /// [`Error::DivisionByZero`]: enum.Error.html#variant.DivisionByZero
+    pub fn covariance_matrix(&self) -> Result<Matrix<f64>> {
+        let observations: usize = self.get_number_of_columns();
+        if observations < 2 {
+            return Err(Error::DivisionByZero);
+        }
+
+        // Center each feature on its mean.
+        let centered: Vec<Vec<f64>> = self
+            .data
+            .chunks_exact(observations)
+            .map(|feature| {
+                let mean: f64 = feature.iter().sum::<f64>() / observations as f64;
+                feature.iter().map(|element| element - mean).collect()
+            })
+            .collect();
+
+        // The matrix is symmetric, thus only compute the upper triangle and mirror it.
+        let features: usize = self.get_number_of_rows();
+        let mut data: Vec<f64> = vec![0.0; features * features];
+        for i in 0..features {
+            for j in i..features {
+                let covariance: f64 = centered[i]
+                    .iter()
+                    .zip(&centered[j])
+                    .map(|(x, y)| x * y)
+                    .sum::<f64>()
+                    / (observations - 1) as f64;
+                data[i * features + j] = covariance;
+                data[j * features + i] = covariance;
+            }
+        }
+
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.rows,
+            data,
+        })
+    }
+
+    /// Compute the Shannon entropy (in nats) of the matrix, treating all elements as a single
+    /// probability distribution.
+    ///
+    /// The entropy is computed as `-sum(p * ln(p))` over all elements `p`. Elements with a
+    /// probability of `0.0` do not contribute to the entropy.
+    ///
+    /// If `normalize` is `false`, the elements are assumed to already sum up to `1.0`. If it is
+    /// `true`, each element is first divided by the sum of all elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[3.0, 3.0]).unwrap();
+    ///
+    /// let entropy: f64 = matrix.entropy(true);
+    /// assert!((entropy - 2.0_f64.ln()).abs() < 1e-12);
+    /// ```
+    pub fn entropy(&self, normalize: bool) -> f64 {
+        let total: f64 = if normalize {
+            self.data.iter().sum()
+        } else {
+            1.0
+        };
+
+        -self
+            .data
+            .iter()
+            .filter(|probability| **probability != 0.0)
+            .map(|probability| probability / total)
+            .map(|probability| probability * probability.ln())
+            .sum::<f64>()
+    }
+
+    /// Compute the mean of the squared differences between the elements of this matrix and the
+    /// corresponding elements of the `other` matrix, e.g. to monitor the error of a regression.
+    ///
+    /// Both matrices must have the same dimensions. Otherwise, [`Error::DimensionMismatch`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let prediction: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.0]).unwrap();
+    /// let target: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.0, 4.0]).unwrap();
+    ///
+    /// let error: f64 = prediction.mean_squared_difference(&target).unwrap();
+    /// assert_eq!(error, 2.5);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn mean_squared_difference(&self, other: &Matrix<f64>) -> Result<f64> {
+        let squared_differences: Matrix<f64> = self.zip_map_into(other, |a, b| (a - b).powi(2))?;
+
+        Ok(squared_differences.data.iter().sum::<f64>() / self.len() as f64)
+    }
+
+    /// Collapse the rows of the matrix into a single row by computing the sum of all rows, each
+    /// multiplied by its weight, i.e. `sum_i weights[i] * row_i`. The result is a `1 x n` matrix
+    /// where `n` is the number of columns in the matrix.
+    ///
+    /// There must be exactly one weight per row. Otherwise, [`Error::DimensionMismatch`] will be
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [f64; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// let pooled: Matrix<f64> = matrix.weighted_sum_rows(&[0.5, 0.25]).unwrap();
+    /// assert_eq!(pooled.get_number_of_rows(), 1);
+    /// assert_eq!(pooled.as_slice(), &[1.5, 2.25, 3.0]);
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn weighted_sum_rows(&self, weights: &[f64]) -> Result<Matrix<f64>> {
+        if weights.len() != self.get_number_of_rows() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let columns: usize = self.get_number_of_columns();
+        let mut data: Vec<f64> = vec![0.0; columns];
+        for (row, weight) in self.data.chunks_exact(columns).zip(weights) {
+            for (sum, element) in data.iter_mut().zip(row) {
+                *sum += weight * element;
+            }
+        }
+
+        Ok(Matrix {
+            rows: NonZeroUsize::new(1).unwrap(),
+            columns: self.columns,
+            data,
+        })
+    }
+
+    // endregion
+
+    // region Serialization
+
+    /// Create a new matrix with the given number of `rows` and `columns` from the raw
+    /// little-endian bytes of its elements in row-major order, as written by [`to_le_bytes`].
+    ///
+    /// Each element takes eight bytes. If the number of `bytes` does not match the dimensions of
+    /// the matrix, an [`Error::InvalidFormat`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let mut bytes: Vec<u8> = Vec::new();
+    /// bytes.extend_from_slice(&0.5_f64.to_le_bytes());
+    /// bytes.extend_from_slice(&(-2.0_f64).to_le_bytes());
+    ///
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_le_bytes(one, two, &bytes).unwrap();
+    /// assert_eq!(matrix.as_slice(), &[0.5, -2.0]);
+    /// ```
+    ///
+    /// [`to_le_bytes`]: #method.to_le_bytes
+    /// [`Error::InvalidFormat`]: enum.Error.html#variant.InvalidFormat
+    pub fn from_le_bytes(
+        rows: NonZeroUsize,
+        columns: NonZeroUsize,
+        bytes: &[u8],
+    ) -> Result<Matrix<f64>> {
+        const ELEMENT_SIZE: usize = std::mem::size_of::<f64>();
+
+        let length: usize = Matrix::<f64>::get_length_from_rows_and_columns(rows, columns)?;
+        if length.checked_mul(ELEMENT_SIZE) != Some(bytes.len()) {
+            return Err(Error::InvalidFormat);
+        }
+
+        let data: Vec<f64> = bytes
+            .chunks_exact(ELEMENT_SIZE)
+            .map(|chunk| {
+                let mut element: [u8; ELEMENT_SIZE] = [0; ELEMENT_SIZE];
+                element.copy_from_slice(chunk);
+                f64::from_le_bytes(element)
+            })
+            .collect();
+
+        Ok(Matrix {
+            rows,
+            columns,
+            data,
+        })
+    }
+
+    /// Get the raw little-endian bytes of the elements in row-major order, without any
+    /// information about the dimensions, e.g. for memory-mapped files.
+    ///
+    /// Each element takes eight bytes. The matrix can be restored with [`from_le_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let matrix: Matrix<f64> = Matrix::from_slice(one, two, &[0.5, -2.0]).unwrap();
+    ///
+    /// let bytes: Vec<u8> = matrix.to_le_bytes();
+    /// assert_eq!(bytes.len(), 16);
+    /// assert_eq!(&bytes[..8], &0.5_f64.to_le_bytes());
+    /// assert_eq!(Matrix::from_le_bytes(one, two, &bytes).unwrap(), matrix);
+    /// ```
+    ///
+    /// [`from_le_bytes`]: #method.from_le_bytes
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(self.data.len() * std::mem::size_of::<f64>());
+        for element in &self.data {
+            bytes.extend_from_slice(&element.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    // endregion
+
+    // region Visualization
+
+    /// Render the matrix as ASCII art for a quick visualization in the terminal, e.g. of
+    /// activations.
+    ///
+    /// Each element is mapped to a character of the ramp `" .:-=+*#%@"` by its magnitude, where
+    /// `0.0` is mapped to a space and `1.0` to an `@`. Elements outside `[0.0, 1.0]` are clamped
+    /// into this range, `NaN` is rendered as a space. Each row of the matrix becomes one line;
+    /// the lines are separated by a newline character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [f64; 6] = [0.0, 0.5, 1.0, 1.0, 0.5, 0.0];
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// assert_eq!(matrix.to_ascii_art(), " +@\n@+ ");
+    /// ```
+    pub fn to_ascii_art(&self) -> String {
+        /// The characters used for rendering, ordered by increasing density.
+        const RAMP: &[u8] = b" .:-=+*#%@";
+
+        let lines: Vec<String> = self
+            .data
+            .chunks_exact(self.get_number_of_columns())
+            .map(|row| {
+                row.iter()
+                    .map(|element| {
+                        // Clamping keeps `NaN`, which the cast maps to `0`.
+                        let index: usize =
+                            (element.clamp(0.0, 1.0) * (RAMP.len() - 1) as f64).round() as usize;
+                        RAMP[index] as char
+                    })
+                    .collect()
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+
+    // endregion
+}
+
+/// Randomly split the column indices `0..columns` into training and test indices, using the given
+/// random number generator `rng`. The training indices are returned first, and both lists are
+/// sorted.
+///
+/// The test indices get `test_fraction` of the columns, rounded to the nearest integer, but both
+/// lists get at least one index.
+///
+/// `test_fraction` must be within `(0, 1)`. Otherwise, an [`Error::InvalidRange`] will be
+/// returned. If there are less than two columns, an [`Error::EmptyMatrix`] will be returned.
+///
+/// [`Error::EmptyMatrix`]: enum.Error.html#variant.EmptyMatrix
+/// [`Error::InvalidRange`]: enum.Error.html#variant.InvalidRange
+fn split_column_indices<R>(
+    columns: usize,
+    test_fraction: f64,
+    rng: &mut R,
+) -> Result<(Vec<usize>, Vec<usize>)>
+where
+    R: Rng + ?Sized,
+{
+    if test_fraction.is_nan() || test_fraction <= 0.0 || test_fraction >= 1.0 {
+        return Err(Error::InvalidRange);
+    }
+
+    if columns < 2 {
+        return Err(Error::EmptyMatrix);
+    }
+
+    let number_of_test_columns: usize =
+        ((columns as f64 * test_fraction).round() as usize).clamp(1, columns - 1);
+
+    let mut indices: Vec<usize> = (0..columns).collect();
+    indices.shuffle(rng);
+
+    let mut train_indices: Vec<usize> = indices.split_off(number_of_test_columns);
+    let mut test_indices: Vec<usize> = indices;
+    train_indices.sort_unstable();
+    test_indices.sort_unstable();
+
+    Ok((train_indices, test_indices))
+}
+
+impl<T> Clone for Matrix<T>
+where
+    T: Clone,
+{
+    /// Clone this matrix.
+    fn clone(&self) -> Self {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T> Debug for Matrix<T>
+where
+    T: Debug,
+{
+    /// Format the matrix for debugging purposes.
+    ///
+    /// For matrices with up to 64 elements, all elements will be included. For larger matrices,
+    /// only the first and the last three elements will be included, separated by an ellipsis.
+    fn fmt(&self, formatter: &mut Formatter) -> ::std::fmt::Result {
+        let mut debug_struct = formatter.debug_struct("Matrix");
+        debug_struct
+            .field("rows", &self.rows)
+            .field("columns", &self.columns);
+
+        if self.data.len() <= DEBUG_MAX_ELEMENTS {
+            debug_struct.field("data", &self.data);
+        } else {
+            debug_struct.field("data", &ElidedData(&self.data));
+        }
+
+        debug_struct.finish()
+    }
+}
+
+/// A wrapper for formatting only the first and the last few elements of a large slice for
+/// debugging purposes.
+struct ElidedData<'a, T>(&'a [T]);
+
+impl<'a, T> Debug for ElidedData<'a, T>
+where
+    T: Debug,
+{
+    /// Format the first and the last elements of the slice, separated by an ellipsis.
+    fn fmt(&self, formatter: &mut Formatter) -> ::std::fmt::Result {
+        /// The ellipsis, formatted without quotes.
+        struct Ellipsis;
+
+        impl Debug for Ellipsis {
+            fn fmt(&self, formatter: &mut Formatter) -> ::std::fmt::Result {
+                write!(formatter, "...")
+            }
+        }
+
+        let length: usize = self.0.len();
+        formatter
+            .debug_list()
+            .entries(&self.0[..DEBUG_EDGE_ELEMENTS])
+            .entry(&Ellipsis)
+            .entries(&self.0[length - DEBUG_EDGE_ELEMENTS..])
+            .finish()
+    }
+}
+
+impl<T> Display for Matrix<T>
+where
+    T: Display,
+{
+    /// Get a human readable representation of this matrix.
+    ///
+    /// The matrix will be formatted in a rectangular array with the dimensions of the matrix.
+    ///
+    /// # Example
+    ///
+    /// A `2x3` matrix with some data as produced by the code
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    /// ```
+    ///
+    /// will be formatted to the following text (e.g. when using [`println!`] to print to the
+    /// console):
+    ///
+    /// ```text
+    /// [0.25   1.33    -0.1]
+    /// [1      -2.73   1.2 ]
+    /// ```
+    ///
+    /// [`println!`]: https://doc.rust-lang.org/stable/std/macro.println.html
+    fn fmt(&self, formatter: &mut Formatter) -> ::std::fmt::Result {
+        let style: DisplayStyle = DisplayStyle::default();
+        let styled = self.display_styled(&style);
+        write!(formatter, "{}", styled)
+    }
+}
+
+impl<T> Eq for Matrix<T> where T: Eq {}
+
+impl<T> Hash for Matrix<T>
+where
+    T: Hash,
+{
+    /// Feed the dimensions and the data of the matrix into the given `state`.
+    ///
+    /// The dimensions are included so that matrices with the same data but different dimensions
+    /// (e.g. a matrix and its transpose if it is a vector) get different hashes.
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.rows.hash(state);
+        self.columns.hash(state);
+        self.data.hash(state);
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Get a reference to the element in the given `(row, column)`, e.g. `matrix[(1, 2)]`.
+    ///
+    /// # Panics
+    ///
+    /// If the `row` or `column` value is larger than the number of rows or columns in the matrix,
+    /// respectively, the method will panic with a message containing the row and column. Use
+    /// [`get`] to get an error instead.
+    ///
+    /// [`get`]: #method.get
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.assert_cell_in_bounds(row, column);
+
+        unsafe {
+            // The row and column have just been checked.
+            &self.data[self.get_index_unchecked(row, column)]
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    /// Get a mutable reference to the element in the given `(row, column)`, e.g. to set it with
+    /// `matrix[(1, 2)] = 5.0`.
+    ///
+    /// # Panics
+    ///
+    /// If the `row` or `column` value is larger than the number of rows or columns in the matrix,
+    /// respectively, the method will panic with a message containing the row and column.
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        self.assert_cell_in_bounds(row, column);
+
+        unsafe {
+            // The row and column have just been checked.
+            let index: usize = self.get_index_unchecked(row, column);
+            &mut self.data[index]
+        }
+    }
+}
+
+impl<T> PartialEq for Matrix<T>
+where
+    T: PartialEq,
+{
+    /// Check if two matrices are equal to each other.
+    ///
+    /// Two matrices `A` and `B` are equal to each other if their dimensions are the same and all
+    /// elements in matrix `A` are equal to their corresponding element in matrix `B` (i.e. if
+    /// element `A_i,j == B_i,j`.
+    fn eq(&self, other: &Self) -> bool {
+        if self.get_number_of_columns() != other.get_number_of_columns() {
+            return false;
+        }
+
+        if self.get_number_of_rows() != other.get_number_of_rows() {
+            return false;
+        }
+
+        for (e1, e2) in self.as_slice().iter().zip(other.as_slice()) {
+            if e1 != e2 {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl_scalar_assign_operators!();
+impl_element_wise_binary_operators!();
+impl_scalar_binary_operators!();
+impl_unary_operators!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+
+    use approx::assert_relative_eq;
+    use approx::assert_relative_ne;
+
+    use crate::test_element_wise_binary_operators;
+    use crate::test_scalar_assign_operators;
+    use crate::test_scalar_binary_operators;
+    use crate::test_unary_operators;
+
+    // region Initialization
+
+    /// Test creating a new matrix with dimensions that are not exceeding the maximum size.
+    #[test]
+    fn new_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<usize>> = Matrix::new(rows, columns, 0);
+
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<usize> = matrix_result.unwrap();
+        assert_eq!(matrix.rows.get(), rows.get());
+        assert_eq!(matrix.columns.get(), columns.get());
+        assert_eq!(matrix.as_slice(), [0_usize; 15]);
+    }
+
+    /// Test creating a large matrix.
+    #[test]
+    fn new_large_dimensions() {
+        let size: NonZeroUsize = NonZeroUsize::new(1000).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::new(size, size, 0.5);
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<f64> = matrix_result.unwrap();
+        assert_eq!(matrix.as_slice().len(), 1_000_000);
+        assert!(matrix.as_slice().iter().all(|&element| element == 0.5));
+    }
+
+    /// Test creating a new matrix with dimensions that exceed the maximum size.
+    #[test]
+    fn new_exceeding_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix_result: Result<Matrix<usize>> = Matrix::new(rows, columns, 0);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test creating a new matrix with random data from a distribution only yielding `0.0` and
+    /// `1.0`.
+    #[test]
+    fn from_distribution_coin() {
+        /// A distribution yielding `0.0` or `1.0` with equal probability.
+        struct Coin;
+
+        impl Distribution<f64> for Coin {
+            fn sample<R>(&self, rng: &mut R) -> f64
+            where
+                R: Rng + ?Sized,
+            {
+                if rng.gen_bool(0.5) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+
+        let rows: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(20).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let matrix_result: Result<Matrix<f64>> =
+            Matrix::from_distribution(rows, columns, &Coin, &mut rng);
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<f64> = matrix_result.unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 10);
+        assert_eq!(matrix.get_number_of_columns(), 20);
+        assert!(matrix
+            .as_slice()
+            .iter()
+            .all(|element| *element == 0.0 || *element == 1.0));
+
+        // With 200 samples, both values occur.
+        let ones: usize = matrix.count_where(|element| *element == 1.0);
+        assert!(ones > 0);
+        assert!(ones < 200);
+
+        // The same seed yields the same matrix.
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let repeated: Matrix<f64> =
+            Matrix::from_distribution(rows, columns, &Coin, &mut rng).unwrap();
+        assert_eq!(repeated, matrix);
+    }
+
+    /// Test creating a new matrix with random data from a distribution with dimensions that exceed
+    /// the maximum size.
+    #[test]
+    fn from_distribution_invalid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let uniform: Uniform<f64> = Uniform::new(0.0, 1.0);
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let matrix_result: Result<Matrix<f64>> =
+            Matrix::from_distribution(rows, columns, &uniform, &mut rng);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test creating a new matrix with random data with dimensions that do not exceed the maximum
+    /// size.
+    #[test]
+    fn from_random_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::from_random(rows, columns);
+
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<f64> = matrix_result.unwrap();
+        assert_eq!(matrix.rows.get(), rows.get());
+        assert_eq!(matrix.columns.get(), columns.get());
+
+        let data: &[f64] = matrix.as_slice();
+        assert_eq!(data.len(), 15);
+        for element in data.iter() {
+            assert!(*element >= 0.0);
+            assert!(*element <= 1.0);
+        }
+    }
+
+    /// Test creating a new matrix with random data with dimensions that not exceed the maximum
+    /// size.
+    #[test]
+    fn from_random_invalid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::from_random(rows, columns);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test creating a new matrix from a vector with dimensions that do not exceed the maximum size
+    /// and that match the length of the given vector.
+    #[test]
+    fn from_vec_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: Vec<usize> = vec![0, 1, 2, 3, 4, 5];
+        let pointer: *const usize = data.as_ptr();
+        let matrix_result: Result<Matrix<usize>> = Matrix::from_vec(rows, columns, data);
+
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<usize> = matrix_result.unwrap();
+        assert_eq!(matrix.rows.get(), rows.get());
+        assert_eq!(matrix.columns.get(), columns.get());
+        assert_eq!(matrix.as_slice(), [0, 1, 2, 3, 4, 5]);
+
+        // The data has not been copied.
+        assert_eq!(matrix.as_slice().as_ptr(), pointer);
+    }
+
+    /// Test creating a new matrix from a vector with dimensions that exceed the maximum size.
+    #[test]
+    fn from_vec_exceeding_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix_result: Result<Matrix<usize>> = Matrix::from_vec(rows, columns, vec![0, 1]);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test creating a new matrix from a vector with dimensions that do not match the length of
+    /// the given vector.
+    #[test]
+    fn from_vec_not_matching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<usize>> = Matrix::from_vec(rows, columns, vec![0, 1, 2]);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test creating a new matrix from a slice with dimensions that do not exceed the maximum size
+    /// and that match the length of the given slice.
+    #[test]
+    fn from_slice_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+        let matrix_result: Result<Matrix<usize>> = Matrix::from_slice(rows, columns, &data);
+
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<usize> = matrix_result.unwrap();
+        assert_eq!(matrix.rows.get(), rows.get());
+        assert_eq!(matrix.columns.get(), columns.get());
+        assert_eq!(matrix.as_slice(), data);
+    }
+
+    /// Test creating a new matrix from a slice with dimensions that exceed the maximum size.
+    #[test]
+    fn from_slice_exceeding_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let data: [usize; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+        let matrix_result: Result<Matrix<usize>> = Matrix::from_slice(rows, columns, &data);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test creating a new matrix from a slice with dimensions that do not match the length of the
+    /// given slice.
+    #[test]
+    fn from_slice_not_matching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 5] = [0, 1, 2, 3, 4];
+        let matrix_result: Result<Matrix<usize>> = Matrix::from_slice(rows, columns, &data);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test assembling a block-diagonal matrix from a 2x2 and a 1x1 block.
+    #[test]
+    fn block_diagonal_valid_blocks() {
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let blocks: [Matrix<usize>; 2] = [
+            Matrix::from_slice(two, two, &[1, 2, 3, 4]).unwrap(),
+            Matrix::from_slice(one, one, &[5]).unwrap(),
+        ];
+
+        let matrix_result: Result<Matrix<usize>> = Matrix::block_diagonal(&blocks, 0);
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<usize> = matrix_result.unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 3);
+        assert_eq!(matrix.get_number_of_columns(), 3);
+        assert_eq!(matrix.as_slice(), &[1, 2, 0, 3, 4, 0, 0, 0, 5]);
+    }
+
+    /// Test assembling a block-diagonal matrix without any blocks.
+    #[test]
+    fn block_diagonal_without_blocks() {
+        let blocks: [Matrix<usize>; 0] = [];
+        let matrix_result: Result<Matrix<usize>> = Matrix::block_diagonal(&blocks, 0);
+        assert!(
+            matches!(matrix_result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
+        );
+    }
+
+    /// Test restoring a sparse matrix from its triplets.
+    #[test]
+    fn from_sparse_triplets_round_trip() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let data: [i32; 12] = [0, 0, 3, 0, 0, 0, 0, 0, -1, 0, 0, 7];
+        let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let triplets: Vec<(usize, usize, i32)> = matrix.to_sparse_triplets();
+        assert_eq!(triplets, vec![(0, 2, 3), (2, 0, -1), (2, 3, 7)]);
+
+        let restored_result: Result<Matrix<i32>> =
+            Matrix::from_sparse_triplets(rows, columns, &triplets, 0);
+        assert!(restored_result.is_ok());
+        assert_eq!(restored_result.unwrap(), matrix);
+    }
+
+    /// Test creating a matrix from triplets with coordinates outside the matrix.
+    #[test]
+    fn from_sparse_triplets_out_of_bounds() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+        for triplet in &[(2, 0, 1), (0, 3, 1)] {
+            let matrix_result: Result<Matrix<i32>> =
+                Matrix::from_sparse_triplets(rows, columns, &[*triplet], 0);
+            assert!(
+                matches!(matrix_result, Err(Error::CellOutOfBounds)),
+                "Expected error Error::CellOutOfBounds not satisfied."
+            );
+        }
+    }
+
+    /// Test creating an identity matrix.
+    #[test]
+    fn identity_valid_size() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<i32>> = Matrix::identity(size, 0, 1);
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<i32> = matrix_result.unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 3);
+        assert_eq!(matrix.get_number_of_columns(), 3);
+        assert_eq!(matrix.as_slice(), &[1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    }
+
+    /// Test creating an identity matrix whose number of elements exceeds the maximum size.
+    #[test]
+    fn identity_invalid_size() {
+        let size: NonZeroUsize = NonZeroUsize::new(usize::MAX / 2).unwrap();
+        let matrix_result: Result<Matrix<i32>> = Matrix::identity(size, 0, 1);
+
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test creating two matrices with random values from the same default seed.
+    #[test]
+    fn from_random_default_seed() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+        crate::set_default_seed(42);
+        let first: Matrix<f64> = Matrix::from_random(rows, columns).unwrap();
+
+        crate::set_default_seed(42);
+        let second: Matrix<f64> = Matrix::from_random(rows, columns).unwrap();
+        crate::clear_default_seed();
+
+        assert_eq!(first.as_slice(), second.as_slice());
+    }
+
+    /// Test creating matrices with random values from explicit seeds.
+    #[test]
+    fn from_random_seeded() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+        let first: Matrix<f64> = Matrix::from_random_seeded(rows, columns, 42).unwrap();
+        let second: Matrix<f64> = Matrix::from_random_seeded(rows, columns, 42).unwrap();
+        assert_eq!(first.as_slice(), second.as_slice());
+        assert!(first
+            .as_slice()
+            .iter()
+            .all(|element| (0.0..=1.0).contains(element)));
+
+        let other: Matrix<f64> = Matrix::from_random_seeded(rows, columns, 43).unwrap();
+        assert_ne!(first.as_slice(), other.as_slice());
+
+        let too_large: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::from_random_seeded(too_large, columns, 42);
+        assert!(
+            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test cloning a matrix.
+    #[test]
+    fn clone() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+        let original: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let copy: Matrix<usize> = original.clone();
+        assert_eq!(original, copy);
+    }
+
+    /// Test creating a Gaussian kernel.
+    #[test]
+    fn gaussian_kernel_valid_sigma() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let kernel_result: Result<Matrix<f64>> = Matrix::gaussian_kernel(size, 0.8);
+        assert!(kernel_result.is_ok());
+
+        let kernel: Matrix<f64> = kernel_result.unwrap();
+        assert_eq!(kernel.get_number_of_rows(), 3);
+        assert_eq!(kernel.get_number_of_columns(), 3);
+        assert_relative_eq!(kernel.as_slice().iter().sum::<f64>(), 1.0);
+
+        // The kernel is symmetric and the center has the largest weight.
+        assert_eq!(kernel, kernel.transpose());
+        for row in 0..3 {
+            for column in 0..3 {
+                let element: f64 = kernel.get(row, column).unwrap();
+                assert_relative_eq!(element, kernel.get(2 - row, 2 - column).unwrap());
+                assert!(element <= kernel.get(1, 1).unwrap());
+            }
+        }
+    }
+
+    /// Test creating Gaussian kernels with an even size and a very small standard deviation, for
+    /// which all terms would underflow without the relative exponent.
+    #[test]
+    fn gaussian_kernel_even_size_small_sigma() {
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let kernel: Matrix<f64> = Matrix::gaussian_kernel(two, 0.01).unwrap();
+        assert_eq!(kernel.as_slice(), &[0.25; 4]);
+
+        // Only the four elements around the center contribute.
+        let four: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let kernel: Matrix<f64> = Matrix::gaussian_kernel(four, 0.01).unwrap();
+        assert!(kernel.is_finite());
+        assert_eq!(kernel.get(1, 1).unwrap(), 0.25);
+        assert_eq!(kernel.get(2, 2).unwrap(), 0.25);
+        assert_eq!(kernel.get(0, 0).unwrap(), 0.0);
+        assert_relative_eq!(kernel.as_slice().iter().sum::<f64>(), 1.0);
+    }
+
+    /// Test creating a Gaussian kernel with an invalid standard deviation.
+    #[test]
+    fn gaussian_kernel_invalid_sigma() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+        for &sigma in &[0.0, -1.0, f64::NAN] {
+            let kernel_result: Result<Matrix<f64>> = Matrix::gaussian_kernel(size, sigma);
+            assert!(
+                matches!(kernel_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
+
+    /// Test creating a one-hot encoded column vector.
+    #[test]
+    fn one_hot_valid_class() {
+        let number_of_classes: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::one_hot(2, number_of_classes);
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<f64> = matrix_result.unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 4);
+        assert_eq!(matrix.get_number_of_columns(), 1);
+        assert_relative_eq!(*matrix.as_slice(), [0.0, 0.0, 1.0, 0.0]);
+    }
+
+    /// Test creating a one-hot encoded column vector for a class out of range.
+    #[test]
+    fn one_hot_invalid_class() {
+        let number_of_classes: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::one_hot(4, number_of_classes);
+
+        assert!(
+            matches!(matrix_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+    }
+
+    /// Test creating a matrix of one-hot encoded columns.
+    #[test]
+    fn one_hot_batch_valid_labels() {
+        let number_of_classes: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::one_hot_batch(&[0, 2], number_of_classes);
+        assert!(matrix_result.is_ok());
+
+        let matrix: Matrix<f64> = matrix_result.unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 3);
+        assert_eq!(matrix.get_number_of_columns(), 2);
+        assert_relative_eq!(*matrix.as_slice(), [1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+    }
+
+    /// Test creating a matrix of one-hot encoded columns if a label is out of range.
+    #[test]
+    fn one_hot_batch_invalid_label() {
+        let number_of_classes: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<f64>> =
+            Matrix::one_hot_batch(&[0, 3, 1], number_of_classes);
+
+        assert!(
+            matches!(matrix_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+    }
+
+    /// Test creating a matrix of one-hot encoded columns without any labels.
+    #[test]
+    fn one_hot_batch_without_labels() {
+        let number_of_classes: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix_result: Result<Matrix<f64>> = Matrix::one_hot_batch(&[], number_of_classes);
+
+        assert!(
+            matches!(matrix_result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
+        );
+    }
+
+    /// Test creating a random permutation matrix.
+    #[test]
+    fn random_permutation() {
+        let n: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let permutation: Matrix<f64> = Matrix::random_permutation(n, &mut rng).unwrap();
+        assert_eq!(permutation.get_number_of_rows(), 4);
+        assert_eq!(permutation.get_number_of_columns(), 4);
+
+        // Each row and each column contains exactly one `1.0`, all other elements are `0.0`.
+        for i in 0..4 {
+            let row: Vec<f64> = (0..4).map(|j| permutation.get(i, j).unwrap()).collect();
+            let column: Vec<f64> = (0..4).map(|j| permutation.get(j, i).unwrap()).collect();
+            assert_eq!(row.iter().filter(|&&value| value == 1.0).count(), 1);
+            assert_eq!(row.iter().filter(|&&value| value == 0.0).count(), 3);
+            assert_eq!(column.iter().filter(|&&value| value == 1.0).count(), 1);
+            assert_eq!(column.iter().filter(|&&value| value == 0.0).count(), 3);
+        }
+
+        // Multiplying with the permutation matrix permutes the rows of the other matrix.
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let data: [f64; 8] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let matrix: Matrix<f64> = Matrix::from_slice(n, columns, &data).unwrap();
+        let permuted: Matrix<f64> = permutation.matrix_mul(&matrix).unwrap();
+        for i in 0..4 {
+            let j: usize = (0..4)
+                .find(|&j| permutation.get(i, j).unwrap() == 1.0)
+                .unwrap();
+            assert_eq!(permuted.get(i, 0).unwrap(), matrix.get(j, 0).unwrap());
+            assert_eq!(permuted.get(i, 1).unwrap(), matrix.get(j, 1).unwrap());
+        }
+    }
+
+    // endregion
+
+    // region Getters
+
+    /// Test getting a slice of the matrix data.
+    #[test]
+    fn as_slice() {
+        let data: [usize; 6] = [0, 10, 20, 30, 40, 50];
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        assert_eq!(matrix.as_slice(), &data);
+    }
+
+    /// Test changing the matrix data through a mutable slice.
+    #[test]
+    fn as_slice_mut() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        matrix
+            .as_slice_mut()
+            .copy_from_slice(&[0, 10, 20, 30, 40, 50]);
+        matrix.as_slice_mut()[4] = 42;
+
+        assert_eq!(matrix.get(0, 1).unwrap(), 10);
+        assert_eq!(matrix.get(1, 0).unwrap(), 30);
+        assert_eq!(matrix.get(1, 1).unwrap(), 42);
+        assert_eq!(matrix.get_number_of_rows(), 2);
+        assert_eq!(matrix.get_number_of_columns(), 3);
+    }
+
+    /// Test getting the number of columns.
+    #[test]
+    fn get_number_of_columns() {
+        let rows: usize = 3;
+        let columns: usize = 2;
+        let matrix = Matrix {
+            rows: NonZeroUsize::new(rows).unwrap(),
+            columns: NonZeroUsize::new(columns).unwrap(),
+            data: vec![0, 1],
+        };
+
+        assert_eq!(matrix.get_number_of_columns(), columns);
+    }
+
+    /// Test getting the unchecked index for given rows and columns.
+    #[test]
+    fn get_index_unchecked() {
+        let rows: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        unsafe {
+            // (0, 0) => 0
+            assert_eq!(matrix.get_index_unchecked(0, 0), 0);
+
+            // (0, 1) => 1
+            assert_eq!(matrix.get_index_unchecked(0, 1), 1);
+
+            // (1, 0) => 10
+            assert_eq!(matrix.get_index_unchecked(1, 0), 10);
+
+            // (3, 7) => 37
+            assert_eq!(matrix.get_index_unchecked(3, 7), 37);
+
+            // (9, 9) => 99
+            assert_eq!(matrix.get_index_unchecked(9, 9), 99);
+
+            // (10, 0) => 100 (out of bounds)
+            assert_eq!(matrix.get_index_unchecked(10, 0), 100);
+        }
+    }
+
+    /// Test that getting get index when iterating over all elements in row-major format will yield
+    /// indices that are increasing exactly by 1.
+    #[test]
+    fn get_index_unchecked_correct_increments() {
+        let rows = NonZeroUsize::new(5).unwrap();
+        let columns = NonZeroUsize::new(7).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        let mut previous_index: usize = 0;
+        for row in 0..matrix.get_number_of_rows() {
+            for column in 0..matrix.get_number_of_columns() {
+                unsafe {
+                    let index: usize = matrix.get_index_unchecked(row, column);
+
+                    // For the very first element e_0,0, the expected index is, of course, 0. For
+                    // all other elements, the expected index is the previous index plus 1.
+                    let expected_index: usize = match (row, column) {
+                        (0, 0) => 0,
+                        _ => previous_index + 1,
+                    };
+
+                    assert_eq!(index, expected_index, "row {}, column {}", row, column);
+
+                    previous_index = index;
+                }
+            }
+        }
+    }
+
+    /// Test getting the length of the data vector based on the number of rows and columns in the
+    /// matrix when the product of the number of rows and columns does not overflow.
+    #[test]
+    fn get_length_from_rows_and_columns_non_overflowing() {
+        let rows: NonZeroUsize = NonZeroUsize::new(7).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(6).unwrap();
+        let length: Result<usize> =
+            Matrix::<usize>::get_length_from_rows_and_columns(rows, columns);
+
+        assert!(length.is_ok());
+        assert_eq!(length.unwrap(), rows.get() * columns.get());
+    }
+
+    /// Test getting the length of the data vector based on the number of rows and columns in the
+    /// matrix when the product of the number of rows and columns would overflow.
+    #[test]
+    fn get_length_from_rows_and_columns_overflowing() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let length: Result<usize> =
+            Matrix::<usize>::get_length_from_rows_and_columns(rows, columns);
+
+        assert!(
+            matches!(length, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test getting the length of the data vector based on the number of rows and columns in the
+    /// matrix when the product of the number of rows and columns does not overflow, without
+    /// checking if the length would exceed the maximum size.
+    #[test]
+    fn get_length_from_rows_and_columns_unchecked_non_overflowing() {
+        let rows: NonZeroUsize = NonZeroUsize::new(7).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(6).unwrap();
+        unsafe {
+            let length: usize =
+                Matrix::<usize>::get_length_from_rows_and_columns_unchecked(rows, columns);
+
+            assert_eq!(length, rows.get() * columns.get());
+        }
+    }
+
+    /// Test getting the length of the data vector based on the number of rows and columns in the
+    /// matrix when the product of the number of rows and columns would overflow, without checking
+    /// if the length would exceed the maximum size.
+    ///
+    /// In debug mode, the overflow will cause a panic.
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn get_length_from_rows_and_columns_unchecked_overflowing_debug() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX - 1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        unsafe {
+            let _ = Matrix::<usize>::get_length_from_rows_and_columns_unchecked(rows, columns);
+        }
+    }
+
+    /// Test getting the length of the data vector based on the number of rows and columns in the
+    /// matrix when the product of the number of rows and columns would overflow, without checking
+    /// if the length would exceed the maximum size.
+    ///
+    /// In release mode, the computation will silently overflow.
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn get_length_from_rows_and_columns_unchecked_overflowing_release() {
+        let rows: NonZeroUsize = NonZeroUsize::new(usize::MAX - 1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        unsafe {
+            let length: usize =
+                Matrix::<usize>::get_length_from_rows_and_columns_unchecked(rows, columns);
+
+            assert_eq!(length, usize::MAX - 3);
+        }
+    }
+
+    /// Test getting the number of rows.
+    #[test]
+    fn get_number_of_rows() {
+        let rows: usize = 3;
+        let columns: usize = 2;
+        let matrix = Matrix {
+            rows: NonZeroUsize::new(rows).unwrap(),
+            columns: NonZeroUsize::new(columns).unwrap(),
+            data: vec![0, 1],
+        };
+
+        assert_eq!(matrix.get_number_of_rows(), rows);
+    }
+
+    /// Test checking if a matrix is empty.
+    #[test]
+    fn is_empty() {
+        let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        assert!(!matrix.is_empty());
+    }
+
+    /// Test getting the number of elements in a matrix.
+    #[test]
+    fn len() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        assert_eq!(matrix.len(), rows.get() * columns.get());
+    }
+
+    /// Test getting a value when the row and column are valid.
+    #[test]
+    fn get_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let value: Result<u64> = matrix.get(0, 0);
+        assert!(value.is_ok());
+        assert_eq!(value.unwrap(), data[0]);
+    }
+
+    /// Test getting a value when the row or column are invalid.
+    #[test]
+    fn get_invalid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        // Both the row and column are invalid.
+        let value: Result<u64> = matrix.get(rows.get() + 1, columns.get() + 2);
+
+        assert!(
+            matches!(value, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        // Only the row is invalid.
+        let value: Result<u64> = matrix.get(rows.get() + 1, columns.get());
+
+        assert!(
+            matches!(value, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        // Only the column is invalid.
+        let value: Result<u64> = matrix.get(rows.get(), columns.get() + 2);
+
+        assert!(
+            matches!(value, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+    }
+
+    /// Test getting a value without checking the row and column when the row and column are valid.
+    #[test]
+    fn get_unchecked_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        unsafe {
+            assert_eq!(matrix.get_unchecked(0, 0), 10);
+            assert_eq!(matrix.get_unchecked(0, 1), 11);
+            assert_eq!(matrix.get_unchecked(0, 2), 12);
+            assert_eq!(matrix.get_unchecked(1, 0), 13);
+            assert_eq!(matrix.get_unchecked(1, 1), 14);
+            assert_eq!(matrix.get_unchecked(1, 2), 15);
+        }
+    }
+
+    /// Test getting a value with checks in debug builds when the row and column are valid.
+    #[test]
+    fn get_debug_checked_valid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        assert_eq!(matrix.get_debug_checked(0, 0), 10);
+        assert_eq!(matrix.get_debug_checked(0, 2), 12);
+        assert_eq!(matrix.get_debug_checked(1, 1), 14);
+    }
+
+    /// Test getting a value with checks in debug builds when the column is invalid, but the
+    /// resulting index would still be within the data.
+    ///
+    /// In debug mode, the method will panic with a descriptive message.
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Cell (0, 3) is out of bounds of the 2x3 matrix.")]
+    fn get_debug_checked_invalid_dimensions_debug() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let _: u64 = matrix.get_debug_checked(0, columns.get());
+    }
+
+    /// Test getting a value with checks in debug builds when the column is invalid, but the
+    /// resulting index would still be within the data.
+    ///
+    /// In release mode, the value of the first cell in the next row will be returned.
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn get_debug_checked_invalid_dimensions_release() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        assert_eq!(matrix.get_debug_checked(0, columns.get()), 13);
+    }
+
+    /// Test getting a value without checking the row and column when the row or column are invalid.
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 6 but the index is 11")]
+    fn get_unchecked_invalid_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        unsafe {
+            let _: u64 = matrix.get_unchecked(rows.get(), columns.get() + 2);
+        }
+    }
+
+    /// Test getting the values at flat indices.
+    #[test]
+    fn gather_valid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let values_result: Result<Vec<u64>> = matrix.gather(&[0, 5]);
+        assert!(values_result.is_ok());
+        assert_eq!(values_result.unwrap(), vec![10, 15]);
+    }
+
+    /// Test getting the values at flat indices outside the matrix.
+    #[test]
+    fn gather_invalid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
+        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let values_result: Result<Vec<u64>> = matrix.gather(&[0, 6]);
+        assert!(
+            matches!(values_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+    }
+
+    /// Test getting all `2x2` windows of a `3x3` matrix.
+    #[test]
+    fn windows_2d_valid_size() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [u64; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let matrix: Matrix<u64> = Matrix::from_slice(size, size, &data).unwrap();
+
+        let windows: Vec<Matrix<u64>> = matrix.windows_2d(2, 2).unwrap().collect();
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0].as_slice(), &[1, 2, 4, 5]);
+        assert_eq!(windows[1].as_slice(), &[2, 3, 5, 6]);
+        assert_eq!(windows[2].as_slice(), &[4, 5, 7, 8]);
+        assert_eq!(windows[3].as_slice(), &[5, 6, 8, 9]);
+        for window in &windows {
+            assert_eq!(window.get_number_of_rows(), 2);
+            assert_eq!(window.get_number_of_columns(), 2);
+        }
+    }
+
+    /// Test getting windows that do not fit into the matrix.
+    #[test]
+    fn windows_2d_invalid_size() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<u64> = Matrix::new(size, size, 0).unwrap();
+
+        assert!(
+            matches!(matrix.windows_2d(4, 1), Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+        assert!(
+            matches!(matrix.windows_2d(2, 0), Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
+        );
+    }
+
+    // endregion
+
+    // region Properties
+
+    /// Test getting the size of square and non-square matrices.
+    #[test]
+    fn as_square() {
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let three: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+        let square: Matrix<usize> = Matrix::new(three, three, 0).unwrap();
+        let size_result: Result<usize> = square.as_square();
+        assert!(size_result.is_ok());
+        assert_eq!(size_result.unwrap(), 3);
+
+        let rectangle: Matrix<usize> = Matrix::new(two, three, 0).unwrap();
+        let size_result: Result<usize> = rectangle.as_square();
+        assert!(
+            matches!(size_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test counting the elements in a matrix that satisfy a predicate.
+    #[test]
+    fn count_where() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 0.75, 0.5, 1.0, -2.73, 0.51];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        assert_eq!(matrix.count_where(|element| *element > 0.5), 3);
+        assert_eq!(matrix.count_where(|element| *element > 1.0), 0);
+        assert_eq!(matrix.count_where(|_element| true), 6);
+    }
+
+    /// Test ensuring the dimensions of a matrix.
+    #[test]
+    fn ensure_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        assert!(matrix.ensure_dimensions(2, 3).is_ok());
+        assert!(
+            matches!(
+                matrix.ensure_dimensions(3, 2),
+                Err(Error::ShapeMismatch {
+                    expected: (3, 2),
+                    actual: (2, 3)
+                })
+            ),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+    }
+
+    // endregion
+
+    // region Element Operations
+
+    /// Test masking out the diagonal of a matrix.
+    #[test]
+    fn apply_mask_matching_dimensions() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 9] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let mut matrix: Matrix<f64> = Matrix::from_slice(size, size, &data).unwrap();
+        let mut mask: Matrix<bool> = Matrix::new(size, size, false).unwrap();
+        mask.map(|_element, row, column| row == column);
+
+        let result: Result<()> = matrix.apply_mask(&mask, 0.0);
+        assert!(result.is_ok());
+        assert_relative_eq!(
+            *matrix.as_slice(),
+            [0.0, 2.0, 3.0, 4.0, 0.0, 6.0, 7.0, 8.0, 0.0]
+        );
+    }
+
+    /// Test masking a matrix with a mask of different dimensions.
+    #[test]
+    fn apply_mask_mismatching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
+        let mask: Matrix<bool> = Matrix::new(columns, rows, true).unwrap();
+
+        let result: Result<()> = matrix.apply_mask(&mask, 0.0);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+        assert_relative_eq!(*matrix.as_slice(), [1.0; 6]);
+    }
+
+    /// Test adding a column vector to each column of a matrix.
+    #[test]
+    fn broadcast_add_column_vector() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let data: Vec<i32> = (0..12).collect();
+        let matrix: Matrix<i32> = Matrix::from_vec(rows, columns, data).unwrap();
+        let bias: Matrix<i32> = Matrix::from_slice(rows, one, &[100, 200, 300]).unwrap();
+
+        let sum_result: Result<Matrix<i32>> = matrix.broadcast_add(&bias);
+        assert!(sum_result.is_ok());
+
+        let sum: Matrix<i32> = sum_result.unwrap();
+        assert_eq!(sum.get_number_of_rows(), 3);
+        assert_eq!(sum.get_number_of_columns(), 4);
+        assert_eq!(
+            sum.as_slice(),
+            &[100, 101, 102, 103, 204, 205, 206, 207, 308, 309, 310, 311]
+        );
+
+        // Matrices with the same dimensions are added element-wise.
+        assert_eq!(
+            matrix.broadcast_add(&matrix).unwrap(),
+            (&matrix + &matrix).unwrap()
+        );
+    }
+
+    /// Test multiplying each column of a matrix by a column vector.
+    #[test]
+    fn broadcast_mul_column_vector() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let matrix: Matrix<f64> =
+            Matrix::from_slice(rows, columns, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let factors: Matrix<f64> = Matrix::from_slice(rows, one, &[2.0, -0.5]).unwrap();
+
+        let product: Matrix<f64> = matrix.broadcast_mul(&factors).unwrap();
+        assert_relative_eq!(*product.as_slice(), [2.0, 4.0, 6.0, -2.0, -2.5, -3.0]);
+    }
+
+    /// Test broadcasting matrices whose dimensions are not compatible.
+    #[test]
+    fn broadcast_add_mismatching_dimensions() {
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let three: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<i32> = Matrix::new(two, three, 1).unwrap();
+
+        // Different number of rows, and more than one column but not the same number of columns.
+        for other in &[
+            Matrix::new(three, NonZeroUsize::new(1).unwrap(), 1).unwrap(),
+            Matrix::new(two, two, 1).unwrap(),
+        ] {
+            let result: Result<Matrix<i32>> = matrix.broadcast_add(other);
+            assert!(
+                matches!(result, Err(Error::DimensionMismatch)),
+                "Expected error Error::DimensionMismatch not satisfied."
+            );
+        }
+    }
+
+    /// Test computing the element-wise maximum of two matrices.
+    #[test]
+    fn elementwise_max_matching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data_m1: [f64; 6] = [1.0, 5.0, 3.0, -0.5, f64::NAN, 2.0];
+        let data_m2: [f64; 6] = [4.0, 2.0, 6.0, -1.5, 0.0, 2.0];
+        let m1: Matrix<f64> = Matrix::from_slice(rows, columns, &data_m1).unwrap();
+        let m2: Matrix<f64> = Matrix::from_slice(rows, columns, &data_m2).unwrap();
+
+        let maximum: Matrix<f64> = m1.elementwise_max(&m2).unwrap();
+        assert_eq!(maximum.get_number_of_rows(), 2);
+        assert_eq!(maximum.get_number_of_columns(), 3);
+        assert_relative_eq!(maximum.as_slice()[..4], [4.0, 5.0, 6.0, -0.5]);
+        assert!(maximum.as_slice()[4].is_nan());
+        assert_relative_eq!(maximum.as_slice()[5], 2.0);
+    }
+
+    /// Test computing the element-wise maximum of two matrices with different dimensions.
+    #[test]
+    fn elementwise_max_mismatching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let m1: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+        let m2: Matrix<usize> = Matrix::new(columns, rows, 0).unwrap();
+
+        let result: Result<Matrix<usize>> = m1.elementwise_max(&m2);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test computing the element-wise minimum of two matrices.
+    #[test]
+    fn elementwise_min_matching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let m1: Matrix<i64> = Matrix::from_slice(rows, columns, &[1, 5, -3]).unwrap();
+        let m2: Matrix<i64> = Matrix::from_slice(rows, columns, &[4, 2, 6]).unwrap();
+
+        let minimum: Matrix<i64> = m1.elementwise_min(&m2).unwrap();
+        assert_eq!(minimum.as_slice(), &[1, 2, -3]);
+    }
+
+    /// Test computing the element-wise minimum of two matrices with different dimensions.
+    #[test]
+    fn elementwise_min_mismatching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let m1: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+        let m2: Matrix<usize> = Matrix::new(columns, rows, 0).unwrap();
+
+        let result: Result<Matrix<usize>> = m1.elementwise_min(&m2);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test mapping the data in a matrix.
+    #[test]
+    fn map() {
+        // Temperature in °C.
+        let temperatures: [usize; 6] = [0, 10, 25, 50, 75, 100];
+
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut temperature: Matrix<usize> =
+            Matrix::from_slice(rows, columns, &temperatures).unwrap();
+
+        // Convert Celsius to Fahrenheit (the values come out as perfect integers).
+        temperature.map(|celsius, _row, _column| (celsius * 9 / 5) + 32);
+
+        // Temperature in °F.
+        assert_eq!(temperature.as_slice(), [32, 50, 77, 122, 167, 212]);
+    }
+
+    /// Test mapping the data in a matrix by mutable reference.
+    #[test]
+    fn map_ref_mut() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let temperatures: [f64; 6] = [0.0, 10.0, 25.0, 50.0, 75.0, 100.0];
+        let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &temperatures).unwrap();
+
+        // Convert Celsius to Kelvin.
+        matrix.map_ref_mut(|celsius, _row, _column| *celsius += 273.15);
+        assert_relative_eq!(
+            *matrix.as_slice(),
+            [273.15, 283.15, 298.15, 323.15, 348.15, 373.15]
+        );
+    }
+
+    /// Test adding a scalar to all elements in a matrix.
+    #[test]
+    fn offset_in_place() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [i64; 6] = [25, 133, -1, 1, -273, 12];
+        let mut matrix: Matrix<i64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        matrix.offset_in_place(13);
+        assert_eq!(matrix.as_slice(), &[38, 146, 12, 14, -260, 25]);
+    }
+
+    /// Test resetting all elements in a matrix without reallocating its data.
+    #[test]
+    fn reset() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let pointer: *const f64 = matrix.data.as_ptr();
+        let capacity: usize = matrix.data.capacity();
+
+        matrix.reset(0.0);
+        assert_relative_eq!(*matrix.as_slice(), [0.0; 6]);
+        assert_eq!(matrix.data.as_ptr(), pointer);
+        assert_eq!(matrix.data.capacity(), capacity);
+    }
+
+    /// Test upscaling a matrix with nearest-neighbor interpolation.
+    #[test]
+    fn resize_nearest_upscale() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        let size: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let resized: Matrix<f64> = matrix.resize_nearest(size, size).unwrap();
+        assert_eq!(resized.get_number_of_rows(), 4);
+        assert_eq!(resized.get_number_of_columns(), 4);
+        let expected: [f64; 16] = [
+            1.0, 1.0, 2.0, 2.0, //
+            1.0, 1.0, 2.0, 2.0, //
+            3.0, 3.0, 4.0, 4.0, //
+            3.0, 3.0, 4.0, 4.0,
+        ];
+        assert_relative_eq!(*resized.as_slice(), expected);
+    }
+
+    /// Test downscaling a matrix with nearest-neighbor interpolation.
+    #[test]
+    fn resize_nearest_downscale() {
+        let rows: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let new_rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let new_columns: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let resized: Matrix<usize> = matrix.resize_nearest(new_rows, new_columns).unwrap();
+        assert_eq!(resized.get_number_of_rows(), 2);
+        assert_eq!(resized.get_number_of_columns(), 1);
+        assert_eq!(resized.as_slice(), &[0, 6]);
+    }
+
+    /// Test resizing a matrix to dimensions exceeding the maximum size.
+    #[test]
+    fn resize_nearest_exceeding_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, rows, 0).unwrap();
+
+        let new_rows: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let result: Result<Matrix<usize>> = matrix.resize_nearest(new_rows, rows);
+        assert!(
+            matches!(result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test scaling the rows and columns of a matrix against the multiplication with diagonal
+    /// matrices.
+    #[test]
+    fn scale_both_matching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let row_factors: [f64; 2] = [2.0, -0.5];
+        let column_factors: [f64; 3] = [0.1, 3.0, 1.5];
+
+        let mut row_diagonal: Matrix<f64> = Matrix::new(rows, rows, 0.0).unwrap();
+        row_diagonal.map(
+            |_element, row, column| {
+                if row == column {
+                    row_factors[row]
+                } else {
+                    0.0
+                }
+            },
+        );
+        let mut column_diagonal: Matrix<f64> = Matrix::new(columns, columns, 0.0).unwrap();
+        column_diagonal.map(|_element, row, column| {
+            if row == column {
+                column_factors[row]
+            } else {
+                0.0
+            }
+        });
+        let expected: Matrix<f64> = row_diagonal
+            .matrix_mul(&matrix)
+            .unwrap()
+            .matrix_mul(&column_diagonal)
+            .unwrap();
+
+        let scaled: Matrix<f64> = matrix.scale_both(&row_factors, &column_factors).unwrap();
+        assert_eq!(scaled.get_number_of_rows(), 2);
+        assert_eq!(scaled.get_number_of_columns(), 3);
+        assert_relative_eq!(*scaled.as_slice(), *expected.as_slice());
+    }
+
+    /// Test scaling the rows and columns of a matrix with the wrong number of factors.
+    #[test]
+    fn scale_both_mismatching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
+
+        let result: Result<Matrix<f64>> = matrix.scale_both(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        let result: Result<Matrix<f64>> = matrix.scale_both(&[1.0, 2.0], &[1.0, 2.0]);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test chaining scaling and offsetting all elements in a matrix.
+    #[test]
+    fn scale_in_place_chained() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        matrix.scale_in_place(2.0).offset_in_place(1.0);
+        assert_relative_eq!(*matrix.as_slice(), [1.5, 3.66, 0.8, 3.0, -4.46, 3.4]);
+    }
+
+    /// Test writing values at flat indices and reading them again.
+    #[test]
+    fn scatter_valid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        let result: Result<()> = matrix.scatter(&[0, 5], &[10, 15]);
+        assert!(result.is_ok());
+        assert_eq!(matrix.as_slice(), &[10, 0, 0, 0, 0, 15]);
+        assert_eq!(matrix.gather(&[0, 5]).unwrap(), vec![10, 15]);
+    }
+
+    /// Test writing values at invalid flat indices.
+    #[test]
+    fn scatter_invalid_arguments() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        let result: Result<()> = matrix.scatter(&[0, 6], &[10, 16]);
+        assert!(
+            matches!(result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        let result: Result<()> = matrix.scatter(&[0, 1], &[10]);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        // The matrix has not been changed.
+        assert_eq!(matrix.as_slice(), &[0; 6]);
+    }
+
+    /// Test transposing a matrix.
+    #[test]
+    fn transpose() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let transposed: Matrix<usize> = matrix.transpose();
+        assert_eq!(transposed.get_number_of_rows(), columns.get());
+        assert_eq!(transposed.get_number_of_columns(), rows.get());
+        assert_eq!(transposed.as_slice(), [0, 3, 1, 4, 2, 5]);
+    }
+
+    /// Test mapping the data in a matrix with a fallible closure that succeeds for all elements.
+    #[test]
+    fn try_map_indexed_success() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 1).unwrap();
+
+        let result: StdResult<(), ()> =
+            matrix.try_map_indexed(|element, row, column| Ok(element + row * 10 + column));
+        assert!(result.is_ok());
+        assert_eq!(matrix.as_slice(), [1, 2, 3, 11, 12, 13]);
+    }
+
+    /// Test mapping the data in a matrix with a fallible closure that fails for one element.
+    #[test]
+    fn try_map_indexed_failure() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 1).unwrap();
+
+        let result: StdResult<(), (usize, usize)> =
+            matrix.try_map_indexed(|element, row, column| match (row, column) {
+                (1, 2) => Err((row, column)),
+                _ => Ok(element + 1),
+            });
+        assert_eq!(result, Err((1, 2)));
+
+        // All elements before the failing one have been mapped.
+        assert_eq!(matrix.as_slice(), [2, 2, 2, 2, 2, 1]);
+    }
+
+    /// Test combining two matrices into a matrix of a different type.
+    #[test]
+    fn zip_map_into_matching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let a: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.5, -1.0, 2.0, 3.0]).unwrap();
+        let b: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.25, 1.0, 2.0, 4.0]).unwrap();
+
+        let greater_result: Result<Matrix<bool>> = a.zip_map_into(&b, |a, b| a > b);
+        assert!(greater_result.is_ok());
+
+        let greater: Matrix<bool> = greater_result.unwrap();
+        assert_eq!(greater.get_number_of_rows(), 2);
+        assert_eq!(greater.get_number_of_columns(), 2);
+        assert_eq!(greater.as_slice(), &[true, false, false, false]);
+    }
+
+    /// Test combining two matrices of different dimensions.
+    #[test]
+    fn zip_map_into_mismatching_dimensions() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let a: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
+        let b: Matrix<f64> = Matrix::new(columns, rows, 1.0).unwrap();
+
+        let result: Result<Matrix<bool>> = a.zip_map_into(&b, |a, b| a > b);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test matrix multiplication when the dimensions of the matrix are correct.
+    #[test]
+    fn matrix_mul_correct_dimensions() {
+        let rows_m1 = NonZeroUsize::new(1).unwrap();
+        let columns_m1 = NonZeroUsize::new(3).unwrap();
+        let data_m1: [usize; 3] = [3, 4, 2];
+        let m1: Matrix<usize> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
+
+        let rows_m2 = NonZeroUsize::new(3).unwrap();
+        let columns_m2 = NonZeroUsize::new(4).unwrap();
+        let data_m2: [usize; 12] = [13, 9, 7, 15, 8, 7, 4, 6, 6, 4, 0, 3];
+        let m2: Matrix<usize> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
+
+        let result: Result<Matrix<usize>> = m1.matrix_mul(&m2);
+        assert!(result.is_ok());
+
+        let m3: Matrix<usize> = result.unwrap();
+        assert_eq!(m3.get_number_of_rows(), 1);
+        assert_eq!(m3.get_number_of_columns(), 4);
+        assert_eq!(m3.as_slice(), &[83, 63, 37, 75]);
+    }
+
+    /// Test that the result of a matrix multiplication contains exactly one element per row and
+    /// column.
+    #[test]
+    fn matrix_mul_result_length() {
+        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let inner: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(7).unwrap();
+        let m1: Matrix<usize> = Matrix::new(rows, inner, 2).unwrap();
+        let m2: Matrix<usize> = Matrix::new(inner, columns, 3).unwrap();
+
+        let m3: Matrix<usize> = m1.matrix_mul(&m2).unwrap();
+        assert_eq!(m3.as_slice().len(), rows.get() * columns.get());
+        assert_eq!(
+            m3.len(),
+            m3.get_number_of_rows() * m3.get_number_of_columns()
+        );
+        assert!(m3.as_slice().iter().all(|&element| element == 18));
+    }
+
+    /// Test matrix multiplication when the dimensions of the matrix are incorrect.
+    #[test]
+    fn matrix_mul_incorrect_dimensions() {
+        let rows_m1 = NonZeroUsize::new(1).unwrap();
+        let columns_m1 = NonZeroUsize::new(3).unwrap();
+        let data_m1: [usize; 3] = [3, 4, 2];
+        let m1: Matrix<usize> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
+
+        let rows_m2 = NonZeroUsize::new(4).unwrap();
+        let columns_m2 = NonZeroUsize::new(3).unwrap();
+        let data_m2: [usize; 12] = [13, 9, 7, 15, 8, 7, 4, 6, 6, 4, 0, 3];
+        let m2: Matrix<usize> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
+
+        let result: Result<Matrix<usize>> = m1.matrix_mul(&m2);
+
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test that the matrix multiplication alias computes the same result as `matrix_mul`.
+    #[test]
+    fn mm_matches_matrix_mul() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let a: Matrix<i64> = Matrix::from_slice(rows, columns, &[1, -2, 3, 4, 5, -6]).unwrap();
+        let b: Matrix<i64> = Matrix::from_slice(columns, rows, &[7, 8, 9, -10, 11, 12]).unwrap();
+
+        assert_eq!(a.mm(&b).unwrap(), a.matrix_mul(&b).unwrap());
+        assert_eq!(b.mm(&a).unwrap(), b.matrix_mul(&a).unwrap());
+        assert!(
+            matches!(a.mm(&a), Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test the matrix multiplication on elements that are `Clone`, but not `Copy`.
+    #[test]
+    fn matrix_mul_cloned_non_copy() {
+        /// A number that does not implement `Copy`.
+        #[derive(Clone, Debug, PartialEq)]
+        struct Number(Box<i64>);
+
+        impl Add<Number> for Number {
+            type Output = Number;
+
+            fn add(self, other: Number) -> Number {
+                Number(Box::new(*self.0 + *other.0))
+            }
+        }
+
+        impl Mul<Number> for Number {
+            type Output = Number;
+
+            fn mul(self, other: Number) -> Number {
+                Number(Box::new(*self.0 * *other.0))
+            }
+        }
+
+        let to_numbers = |data: &[i64]| -> Vec<Number> {
+            data.iter().map(|&value| Number(Box::new(value))).collect()
+        };
+
+        let rows_m1 = NonZeroUsize::new(2).unwrap();
+        let columns_m1 = NonZeroUsize::new(3).unwrap();
+        let m1: Matrix<Number> =
+            Matrix::from_vec(rows_m1, columns_m1, to_numbers(&[1, 2, 3, 4, 5, 6])).unwrap();
+
+        let rows_m2 = NonZeroUsize::new(3).unwrap();
+        let columns_m2 = NonZeroUsize::new(2).unwrap();
+        let m2: Matrix<Number> =
+            Matrix::from_vec(rows_m2, columns_m2, to_numbers(&[7, 8, 9, 10, 11, 12])).unwrap();
+
+        let m3: Matrix<Number> = m1.matrix_mul_cloned(&m2).unwrap();
+        assert_eq!(m3.get_number_of_rows(), 2);
+        assert_eq!(m3.get_number_of_columns(), 2);
+        assert_eq!(m3.as_slice(), to_numbers(&[58, 64, 139, 154]).as_slice());
+
+        let result: Result<Matrix<Number>> = m1.matrix_mul_cloned(&m1);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test that the matrix multiplication on cloned elements gives the same result as on copied
+    /// elements.
+    #[test]
+    fn matrix_mul_cloned_matches_matrix_mul() {
+        let rows_m1 = NonZeroUsize::new(2).unwrap();
+        let columns_m1 = NonZeroUsize::new(3).unwrap();
+        let data_m1: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let m1: Matrix<f64> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
+
+        let rows_m2 = NonZeroUsize::new(3).unwrap();
+        let columns_m2 = NonZeroUsize::new(4).unwrap();
+        let data_m2: [f64; 12] = [1.3, 0.9, 0.7, -1.5, 0.8, 0.7, 0.4, 0.6, -0.6, 0.4, 0.0, 0.3];
+        let m2: Matrix<f64> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
+
+        let expected: Matrix<f64> = m1.matrix_mul(&m2).unwrap();
+        let m3: Matrix<f64> = m1.matrix_mul_cloned(&m2).unwrap();
+        assert_eq!(m3, expected);
+    }
+
+    /// Test that equal matrices have equal hashes and can be used as keys in a map.
+    #[test]
+    fn hash() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [i32; 6] = [0, -1, 4, 89, 5, 6];
+        let m1: Matrix<i32> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let m2: Matrix<i32> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let reshaped: Matrix<i32> = Matrix::from_slice(columns, rows, &data).unwrap();
+
+        let hash = |matrix: &Matrix<i32>| {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            matrix.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&m1), hash(&m2));
+        assert_ne!(hash(&m1), hash(&reshaped));
+
+        let mut map: HashMap<Matrix<i32>, &str> = HashMap::new();
+        map.insert(m1, "original");
+        map.insert(reshaped, "reshaped");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&m2), Some(&"original"));
+    }
+
+    /// Test reading and writing elements with the index operator.
+    #[test]
+    fn index_valid_cell() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        assert_eq!(matrix[(0, 0)], 0.0);
+        assert_eq!(matrix[(1, 2)], 5.0);
+
+        matrix[(1, 2)] = -5.0;
+        matrix[(0, 1)] += 0.5;
+        assert_eq!(matrix.as_slice(), &[0.0, 1.5, 2.0, 3.0, 4.0, -5.0]);
+    }
+
+    /// Test reading an element outside the matrix with the index operator.
+    #[test]
+    #[should_panic(expected = "Cell (0, 3) is out of bounds of the 2x3 matrix.")]
+    fn index_invalid_column() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
+
+        let _: f64 = matrix[(0, 3)];
+    }
+
+    /// Test writing an element outside the matrix with the index operator.
+    #[test]
+    #[should_panic(expected = "Cell (2, 0) is out of bounds of the 2x3 matrix.")]
+    fn index_mut_invalid_row() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
+
+        matrix[(2, 0)] = 1.0;
+    }
+
+    /// Test if matrices are partially equal for two matrices that are equal to each other.
+    #[test]
+    fn partial_eq_same_matrices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.0, -1.2, 4.3, 89.9, 5.0, 6.0];
+        let m1: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let m2: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        // The matrices are equal, bot not unequal.
+        assert_eq!(m1, m2);
+        assert!(!m1.ne(&m2));
+    }
+
+    /// Test if matrices are partially equal to each other for two matrices that have differing
+    /// numbers of columns.
+    #[test]
+    fn partial_eq_different_columns() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let m1: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
+        let m2: Matrix<f64> = Matrix::new(rows, rows, 0.0).unwrap();
+
+        assert_ne!(m1, m2);
+    }
+
+    /// Test if matrices are partially equal to each other for two matrices that have different
+    /// data.
+    #[test]
+    fn partial_eq_different_data() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data1: &[f64] = &[0.0, -1.2, 4.3, 89.9, 5.0, 6.0];
+        let data2: &[f64] = &[0.0, -1.2, 4.3, 89.9, 5.0, 6.1];
+        let m1: Matrix<f64> = Matrix::from_slice(rows, columns, data1).unwrap();
+        let m2: Matrix<f64> = Matrix::from_slice(rows, columns, data2).unwrap();
+
+        assert_relative_ne!(data1, data2);
+        assert_ne!(m1, m2);
+    }
+
+    /// Test if matrices are partially equal to each other for two matrices that have differing
+    /// numbers of rows.
+    #[test]
+    fn partial_eq_different_rows() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let m1: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
+        let m2: Matrix<f64> = Matrix::new(columns, columns, 0.0).unwrap();
+
+        assert_ne!(m1, m2);
+    }
+
+    /// Test that element-wise operators on non-`Copy` elements only clone the elements of
+    /// referenced operands.
+    #[test]
+    fn element_wise_operator_non_copy_elements() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        /// A non-`Copy` number counting how often it has been cloned.
+        #[derive(Debug)]
+        struct Counted {
+            value: i64,
+            clones: Rc<Cell<usize>>,
+        }
+
+        impl Clone for Counted {
+            fn clone(&self) -> Self {
+                self.clones.set(self.clones.get() + 1);
+                Counted {
+                    value: self.value,
+                    clones: Rc::clone(&self.clones),
+                }
+            }
+        }
+
+        impl Sub for Counted {
+            type Output = Counted;
+
+            fn sub(self, other: Counted) -> Counted {
+                Counted {
+                    value: self.value - other.value,
+                    clones: self.clones,
+                }
+            }
+        }
+
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let clones: Rc<Cell<usize>> = Rc::new(Cell::new(0));
+        let create = |values: &[i64]| -> Matrix<Counted> {
+            let data: Vec<Counted> = values
+                .iter()
+                .map(|value| Counted {
+                    value: *value,
+                    clones: Rc::clone(&clones),
+                })
+                .collect();
+            Matrix::from_vec(rows, columns, data).unwrap()
+        };
+        let values = |matrix: &Matrix<Counted>| -> Vec<i64> {
+            matrix
+                .as_slice()
+                .iter()
+                .map(|element| element.value)
+                .collect()
+        };
+        let other: Matrix<Counted> = create(&[1, 2, 3, 4]);
+
+        // Owned to owned: nothing is cloned.
+        let result: Matrix<Counted> = (create(&[10; 4]) - create(&[1, 2, 3, 4])).unwrap();
+        assert_eq!(values(&result), vec![9, 8, 7, 6]);
+        assert_eq!(clones.get(), 0);
+
+        // Owned to referenced: only the referenced operand is cloned.
+        let result: Matrix<Counted> = create(&[10; 4]).sub_checked(&other).unwrap();
+        assert_eq!(values(&result), vec![9, 8, 7, 6]);
+        assert_eq!(clones.get(), 4);
+
+        // Referenced to referenced: both operands are cloned and stay unchanged.
+        let matrix: Matrix<Counted> = create(&[10; 4]);
+        let result: Matrix<Counted> = (&matrix - &other).unwrap();
+        assert_eq!(values(&result), vec![9, 8, 7, 6]);
+        assert_eq!(values(&matrix), vec![10; 4]);
+        assert_eq!(clones.get(), 12);
+    }
+
+    /// Test that element-wise operators reuse the data of an owned left-hand side matrix instead of
+    /// cloning it.
+    #[test]
+    fn element_wise_operator_owned_reuses_data() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let other: Matrix<i64> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+
+        // Owned to referenced.
+        let matrix: Matrix<i64> = Matrix::new(rows, columns, 10).unwrap();
+        let pointer: *const i64 = matrix.as_slice().as_ptr();
+        let result: Matrix<i64> = (matrix - &other).unwrap();
+        assert_eq!(result.as_slice().as_ptr(), pointer);
+        assert_eq!(result.as_slice(), &[9, 8, 7, 6, 5, 4]);
+
+        // Owned to owned.
+        let matrix: Matrix<i64> = Matrix::new(rows, columns, 10).unwrap();
+        let pointer: *const i64 = matrix.as_slice().as_ptr();
+        let result: Matrix<i64> = (matrix - other.clone()).unwrap();
+        assert_eq!(result.as_slice().as_ptr(), pointer);
+        assert_eq!(result.as_slice(), &[9, 8, 7, 6, 5, 4]);
+
+        // Referenced to referenced must not change the original matrix.
+        let matrix: Matrix<i64> = Matrix::new(rows, columns, 10).unwrap();
+        let result: Matrix<i64> = (&matrix - &other).unwrap();
+        assert_ne!(result.as_slice().as_ptr(), matrix.as_slice().as_ptr());
+        assert_eq!(matrix.as_slice(), &[10; 6]);
+    }
+
+    // Test the operators.
+    test_scalar_assign_operators!();
+    test_element_wise_binary_operators!();
+    test_scalar_binary_operators!();
+    test_unary_operators!();
+
+    // endregion
+
+    // region Rearrangement
+
+    /// Test shuffling the columns of a matrix.
+    #[test]
+    fn shuffle_columns() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let data: [usize; 10] = [0, 1, 2, 3, 4, 0, 10, 20, 30, 40];
+        let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        matrix.shuffle_columns(&mut rng);
+
+        // The columns have been moved as a whole into the order given by the seed.
+        assert_eq!(matrix.as_slice(), &[1, 4, 3, 0, 2, 10, 40, 30, 0, 20]);
+        assert_ne!(matrix.as_slice(), &data);
+    }
+
+    /// Test shuffling the columns of a feature matrix and its label matrix in the same way.
+    #[test]
+    fn shuffle_columns_paired_matching_columns() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(6).unwrap();
+        let data: [f64; 12] = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5];
+        let mut features: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let mut labels: Matrix<usize> =
+            Matrix::from_slice(one, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        let result: Result<()> = features.shuffle_columns_paired(&mut labels, &mut rng);
+        assert!(result.is_ok());
+
+        // Each feature column is still aligned with its label.
+        for column in 0..6 {
+            let label: f64 = labels.get(0, column).unwrap() as f64;
+            assert_relative_eq!(features.get(0, column).unwrap(), label / 10.0);
+            assert_relative_eq!(features.get(1, column).unwrap(), 1.0 + label / 10.0);
+        }
+    }
+
+    /// Test shuffling the columns of two matrices with a different number of columns.
+    #[test]
+    fn shuffle_columns_paired_mismatching_columns() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+        let mut m1: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let mut m2: Matrix<usize> = Matrix::from_slice(columns, rows, &data).unwrap();
+
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let result: Result<()> = m1.shuffle_columns_paired(&mut m2, &mut rng);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        // Neither matrix has been changed.
+        assert_eq!(m1.as_slice(), &data);
+        assert_eq!(m2.as_slice(), &data);
+    }
+
+    /// Test swapping two columns of a matrix.
+    #[test]
+    fn swap_columns_valid_columns() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+        let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        assert!(matrix.swap_columns(0, 2).is_ok());
+        assert_eq!(matrix.as_slice(), &[2, 1, 0, 5, 4, 3]);
+
+        assert!(matrix.swap_columns(1, 1).is_ok());
+        assert_eq!(matrix.as_slice(), &[2, 1, 0, 5, 4, 3]);
+    }
+
+    /// Test swapping a column outside the matrix.
+    #[test]
+    fn swap_columns_out_of_bounds() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+        let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let result: Result<()> = matrix.swap_columns(0, 3);
+        assert!(
+            matches!(result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+        assert_eq!(matrix.as_slice(), &data);
+    }
+
+    /// Test interleaving the columns of two matrices.
+    #[test]
+    fn interleave_columns_equal_dimensions() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let a: Matrix<usize> = Matrix::from_slice(size, size, &[0, 1, 2, 3]).unwrap();
+        let b: Matrix<usize> = Matrix::from_slice(size, size, &[10, 11, 12, 13]).unwrap();
+
+        let interleaved_result: Result<Matrix<usize>> = a.interleave_columns(&b);
+        assert!(interleaved_result.is_ok());
+
+        let interleaved: Matrix<usize> = interleaved_result.unwrap();
+        assert_eq!(interleaved.get_number_of_rows(), 2);
+        assert_eq!(interleaved.get_number_of_columns(), 4);
+        assert_eq!(interleaved.as_slice(), &[0, 10, 1, 11, 2, 12, 3, 13]);
+    }
+
+    /// Test interleaving the columns of two matrices with different dimensions.
+    #[test]
+    fn interleave_columns_dimension_mismatch() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let a: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+        let b: Matrix<usize> = Matrix::new(columns, rows, 1).unwrap();
+
+        let interleaved_result: Result<Matrix<usize>> = a.interleave_columns(&b);
+        assert!(
+            matches!(interleaved_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+
+    /// Test padding a matrix with more columns than rows to a square matrix.
+    #[test]
+    fn pad_to_square_wide() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+
+        let padded: Matrix<i32> = matrix.pad_to_square(-1).unwrap();
+        assert_eq!(padded.get_number_of_rows(), 3);
+        assert_eq!(padded.get_number_of_columns(), 3);
+        assert_eq!(padded.as_slice(), &[1, 2, 3, 4, 5, 6, -1, -1, -1]);
+    }
+
+    /// Test padding a matrix with more rows than columns, or an already square matrix.
+    #[test]
+    fn pad_to_square_tall_and_square() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+
+        let padded: Matrix<i32> = matrix.pad_to_square(0).unwrap();
+        assert_eq!(padded.get_number_of_rows(), 3);
+        assert_eq!(padded.get_number_of_columns(), 3);
+        assert_eq!(padded.as_slice(), &[1, 2, 0, 3, 4, 0, 5, 6, 0]);
+
+        assert_eq!(padded.pad_to_square(7).unwrap(), padded);
+    }
+
+    /// Test padding a matrix whose square would be too large.
+    #[test]
+    fn pad_to_square_too_large() {
+        let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(usize::MAX).unwrap();
+        let matrix: Matrix<()> = Matrix::new(rows, columns, ()).unwrap();
+
+        let padded: Result<Matrix<()>> = matrix.pad_to_square(());
+        assert!(
+            matches!(padded, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+    }
+
+    /// Test transposing a square matrix in place.
+    #[test]
+    fn transpose_in_place_square() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let mut matrix: Matrix<usize> = Matrix::from_slice(size, size, &data).unwrap();
+        let transposed: Matrix<usize> = matrix.transpose();
+
+        assert!(matrix.transpose_in_place().is_ok());
+        assert_eq!(matrix.as_slice(), &[0, 3, 6, 1, 4, 7, 2, 5, 8]);
+        assert_eq!(matrix, transposed);
+
+        // Transposing twice restores the original matrix.
+        assert!(matrix.transpose_in_place().is_ok());
+        assert_eq!(matrix.as_slice(), &data);
+    }
+
+    /// Test transposing a non-square matrix in place.
+    #[test]
+    fn transpose_in_place_non_square() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
+        let mut matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
 
-    // region Initialization
+        let result: Result<()> = matrix.transpose_in_place();
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+        assert_eq!(matrix.as_slice(), &data);
+    }
 
-    /// Test creating a new matrix with dimensions that are not exceeding the maximum size.
+    /// Test selecting columns of a matrix in a specific order.
     #[test]
-    fn new_valid_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+    fn select_columns_valid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let matrix_result: Result<Matrix<usize>> = Matrix::new(rows, columns, 0);
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
 
-        assert!(matrix_result.is_ok());
+        let selected_result: Result<Matrix<usize>> = matrix.select_columns(&[2, 0]);
+        assert!(selected_result.is_ok());
 
-        let matrix: Matrix<usize> = matrix_result.unwrap();
-        assert_eq!(matrix.rows.get(), rows.get());
-        assert_eq!(matrix.columns.get(), columns.get());
-        assert_eq!(matrix.as_slice(), [0_usize; 15]);
+        let selected: Matrix<usize> = selected_result.unwrap();
+        assert_eq!(selected.get_number_of_rows(), 2);
+        assert_eq!(selected.get_number_of_columns(), 2);
+        assert_eq!(selected.as_slice(), &[2, 0, 5, 3]);
     }
 
-    /// Test creating a new matrix with dimensions that exceed the maximum size.
+    /// Test selecting columns outside the matrix or no columns at all.
     #[test]
-    fn new_exceeding_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(::std::usize::MAX).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let matrix_result: Result<Matrix<usize>> = Matrix::new(rows, columns, 0);
+    fn select_columns_invalid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
 
+        let selected_result: Result<Matrix<usize>> = matrix.select_columns(&[0, 3]);
         assert!(
-            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
-            "Expected error Error::DimensionsTooLarge not satisfied."
+            matches!(selected_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        let selected_result: Result<Matrix<usize>> = matrix.select_columns(&[]);
+        assert!(
+            matches!(selected_result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
         );
     }
 
-    /// Test creating a new matrix with random data with dimensions that do not exceed the maximum
-    /// size.
+    /// Test selecting rows of a matrix in a specific order, including duplicates.
     #[test]
-    fn from_random_valid_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let matrix_result: Result<Matrix<f64>> = Matrix::from_random(rows, columns);
-
-        assert!(matrix_result.is_ok());
+    fn select_rows_valid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
 
-        let matrix: Matrix<f64> = matrix_result.unwrap();
-        assert_eq!(matrix.rows.get(), rows.get());
-        assert_eq!(matrix.columns.get(), columns.get());
+        let selected_result: Result<Matrix<usize>> = matrix.select_rows(&[2, 0, 2]);
+        assert!(selected_result.is_ok());
 
-        let data: &[f64] = matrix.as_slice();
-        assert_eq!(data.len(), 15);
-        for element in data.iter() {
-            assert!(*element >= 0.0);
-            assert!(*element <= 1.0);
-        }
+        let selected: Matrix<usize> = selected_result.unwrap();
+        assert_eq!(selected.get_number_of_rows(), 3);
+        assert_eq!(selected.get_number_of_columns(), 2);
+        assert_eq!(selected.as_slice(), &[4, 5, 0, 1, 4, 5]);
     }
 
-    /// Test creating a new matrix with random data with dimensions that not exceed the maximum
-    /// size.
+    /// Test selecting rows outside the matrix or no rows at all.
     #[test]
-    fn from_random_invalid_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(::std::usize::MAX).unwrap();
+    fn select_rows_invalid_indices() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let matrix_result: Result<Matrix<f64>> = Matrix::from_random(rows, columns);
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
 
+        let selected_result: Result<Matrix<usize>> = matrix.select_rows(&[3]);
         assert!(
-            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
-            "Expected error Error::DimensionsTooLarge not satisfied."
+            matches!(selected_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        let selected_result: Result<Matrix<usize>> = matrix.select_rows(&[]);
+        assert!(
+            matches!(selected_result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
         );
     }
 
-    /// Test creating a new matrix from a slice with dimensions that do not exceed the maximum size
-    /// and that match the length of the given slice.
+    /// Test splitting the last column off a matrix.
     #[test]
-    fn from_slice_valid_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [usize; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
-        let matrix_result: Result<Matrix<usize>> = Matrix::from_slice(rows, columns, &data);
+    fn split_last_column_valid() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let data: Vec<usize> = (0..12).collect();
+        let matrix: Matrix<usize> = Matrix::from_vec(rows, columns, data).unwrap();
+
+        let split_result: Result<(Matrix<usize>, Matrix<usize>)> = matrix.split_last_column();
+        assert!(split_result.is_ok());
+
+        let (features, labels): (Matrix<usize>, Matrix<usize>) = split_result.unwrap();
+        assert_eq!(features.get_number_of_rows(), 3);
+        assert_eq!(features.get_number_of_columns(), 3);
+        assert_eq!(features.as_slice(), &[0, 1, 2, 4, 5, 6, 8, 9, 10]);
+        assert_eq!(labels.get_number_of_rows(), 3);
+        assert_eq!(labels.get_number_of_columns(), 1);
+        assert_eq!(labels.as_slice(), &[3, 7, 11]);
+    }
 
-        assert!(matrix_result.is_ok());
+    /// Test splitting the last column off a matrix with only a single column.
+    #[test]
+    fn split_last_column_single_column() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2]).unwrap();
 
-        let matrix: Matrix<usize> = matrix_result.unwrap();
-        assert_eq!(matrix.rows.get(), rows.get());
-        assert_eq!(matrix.columns.get(), columns.get());
-        assert_eq!(matrix.as_slice(), data);
+        let split_result: Result<(Matrix<usize>, Matrix<usize>)> = matrix.split_last_column();
+        assert!(
+            matches!(split_result, Err(Error::EmptyResult)),
+            "Expected error Error::EmptyResult not satisfied."
+        );
     }
 
-    /// Test creating a new matrix from a slice with dimensions that exceed the maximum size.
+    /// Test stripping a border of zeros around a non-zero core.
     #[test]
-    fn from_slice_exceeding_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(::std::usize::MAX).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let data: [usize; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
-        let matrix_result: Result<Matrix<usize>> = Matrix::from_slice(rows, columns, &data);
+    fn strip_zero_border_with_border() {
+        let rows: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+        let data: [i32; 20] = [0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 0, 3, 4, 5, 0, 0, 0, 0, 0, 0];
+        let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let stripped_result: Result<Matrix<i32>> = matrix.strip_zero_border();
+        assert!(stripped_result.is_ok());
+
+        let stripped: Matrix<i32> = stripped_result.unwrap();
+        assert_eq!(stripped.get_number_of_rows(), 2);
+        assert_eq!(stripped.get_number_of_columns(), 3);
+        assert_eq!(stripped.as_slice(), &[1, 0, 2, 3, 4, 5]);
+
+        // Without a border, the matrix is unchanged.
+        assert_eq!(stripped.strip_zero_border().unwrap(), stripped);
+    }
+
+    /// Test stripping the border of a matrix that only contains zeros.
+    #[test]
+    fn strip_zero_border_all_zeros() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<i32> = Matrix::new(size, size, 0).unwrap();
 
+        let stripped_result: Result<Matrix<i32>> = matrix.strip_zero_border();
         assert!(
-            matches!(matrix_result, Err(Error::DimensionsTooLarge)),
-            "Expected error Error::DimensionsTooLarge not satisfied."
+            matches!(stripped_result, Err(Error::EmptyResult)),
+            "Expected error Error::EmptyResult not satisfied."
         );
     }
 
-    /// Test creating a new matrix from a slice with dimensions that do not match the length of the
-    /// given slice.
+    /// Test splitting the columns of a matrix into a training and a test matrix.
     #[test]
-    fn from_slice_not_matching_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+    fn train_test_split_columns_valid_fraction() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+        let data: Vec<usize> = (0..10).chain((0..10).map(|x| x * 10)).collect();
+        let matrix: Matrix<usize> = Matrix::from_vec(rows, columns, data).unwrap();
+
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let result: Result<(Matrix<usize>, Matrix<usize>)> =
+            matrix.train_test_split_columns(0.3, &mut rng);
+        assert!(result.is_ok());
+
+        let (train, test): (Matrix<usize>, Matrix<usize>) = result.unwrap();
+        assert_eq!(train.get_number_of_rows(), 2);
+        assert_eq!(train.get_number_of_columns(), 7);
+        assert_eq!(test.get_number_of_rows(), 2);
+        assert_eq!(test.get_number_of_columns(), 3);
+
+        // Each column has been moved as a whole and exists in exactly one of the matrices.
+        let mut first_row: Vec<usize> = Vec::new();
+        for part in &[&train, &test] {
+            for column in 0..part.get_number_of_columns() {
+                let first: usize = part.get(0, column).unwrap();
+                assert_eq!(part.get(1, column).unwrap(), first * 10);
+                first_row.push(first);
+            }
+        }
+        first_row.sort_unstable();
+        assert_eq!(first_row, (0..10).collect::<Vec<usize>>());
+    }
+
+    /// Test splitting the columns of a matrix with an invalid test fraction.
+    #[test]
+    fn train_test_split_columns_invalid_fraction() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [usize; 5] = [0, 1, 2, 3, 4];
-        let matrix_result: Result<Matrix<usize>> = Matrix::from_slice(rows, columns, &data);
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        for &test_fraction in &[0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            let result: Result<(Matrix<usize>, Matrix<usize>)> =
+                matrix.train_test_split_columns(test_fraction, &mut rng);
+            assert!(
+                matches!(result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
+
+    /// Test splitting a matrix with a single column.
+    #[test]
+    fn train_test_split_columns_single_column() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &[0, 1]).unwrap();
 
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let result: Result<(Matrix<usize>, Matrix<usize>)> =
+            matrix.train_test_split_columns(0.5, &mut rng);
         assert!(
-            matches!(matrix_result, Err(Error::DimensionMismatch)),
-            "Expected error Error::DimensionMismatch not satisfied."
+            matches!(result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
         );
     }
 
-    /// Test cloning a matrix.
+    /// Test splitting the columns of a feature matrix and its label matrix in the same way.
     #[test]
-    fn clone() {
+    fn train_test_split_columns_paired_matching_columns() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(6).unwrap();
+        let data: [f64; 12] = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5];
+        let features: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let labels: Matrix<usize> = Matrix::from_slice(one, columns, &[0, 1, 2, 3, 4, 5]).unwrap();
+
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        let (features_train, features_test, labels_train, labels_test) = features
+            .train_test_split_columns_paired(&labels, 0.5, &mut rng)
+            .unwrap();
+        assert_eq!(features_train.get_number_of_columns(), 3);
+        assert_eq!(features_test.get_number_of_columns(), 3);
+
+        // Each feature column is still aligned with its label.
+        for (features, labels) in &[(features_train, labels_train), (features_test, labels_test)] {
+            assert_eq!(labels.get_number_of_columns(), 3);
+            for column in 0..3 {
+                let label: f64 = labels.get(0, column).unwrap() as f64;
+                assert_relative_eq!(features.get(0, column).unwrap(), label / 10.0);
+                assert_relative_eq!(features.get(1, column).unwrap(), 1.0 + label / 10.0);
+            }
+        }
+    }
+
+    /// Test splitting the columns of two matrices with a different number of columns.
+    #[test]
+    fn train_test_split_columns_paired_mismatching_columns() {
         let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
         let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
-        let original: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let m1: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let m2: Matrix<usize> = Matrix::from_slice(columns, rows, &data).unwrap();
 
-        let copy: Matrix<usize> = original.clone();
-        assert_eq!(original, copy);
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let result = m1.train_test_split_columns_paired(&m2, 0.5, &mut rng);
+        assert!(
+            matches!(result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
     }
 
     // endregion
 
-    // region Getters
+    // region Views
 
-    /// Test getting a slice of the matrix data.
+    /// Test that writing through a mutable view only changes the targeted block of the matrix.
     #[test]
-    fn as_slice() {
-        let data: [usize; 6] = [0, 10, 20, 30, 40, 50];
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
-
-        assert_eq!(matrix.as_slice(), &data);
+    fn submatrix_mut_valid_ranges() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+
+        let view_result: Result<MatrixViewMut<usize>> = matrix.submatrix_mut(1..3, 2..4);
+        assert!(view_result.is_ok());
+
+        let mut view: MatrixViewMut<usize> = view_result.unwrap();
+        assert_eq!(view.get_number_of_rows(), 2);
+        assert_eq!(view.get_number_of_columns(), 2);
+        view.set(0, 0, 1).unwrap();
+        view.set(0, 1, 2).unwrap();
+        view.set(1, 0, 3).unwrap();
+        *view.get_mut(1, 1).unwrap() = 4;
+
+        assert_eq!(matrix.as_slice(), &[0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4]);
     }
 
-    /// Test getting the number of columns.
+    /// Test getting a mutable view if the ranges are not within the matrix.
     #[test]
-    fn get_columns() {
-        let rows: usize = 3;
-        let columns: usize = 2;
-        let matrix = Matrix {
-            rows: NonZeroUsize::new(rows).unwrap(),
-            columns: NonZeroUsize::new(columns).unwrap(),
-            data: vec![0, 1],
-        };
+    fn submatrix_mut_out_of_bounds() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
 
-        assert_eq!(matrix.get_number_of_columns(), columns);
+        // The rows exceed the matrix.
+        let view_result: Result<MatrixViewMut<usize>> = matrix.submatrix_mut(1..4, 0..2);
+        assert!(
+            matches!(view_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        // The columns exceed the matrix.
+        let view_result: Result<MatrixViewMut<usize>> = matrix.submatrix_mut(0..2, 3..5);
+        assert!(
+            matches!(view_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
     }
 
-    /// Test getting the unchecked index for given rows and columns.
+    /// Test getting a mutable view if one of the ranges is empty.
     #[test]
-    fn get_index_unchecked() {
-        let rows: NonZeroUsize = NonZeroUsize::new(10).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(10).unwrap();
-        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+    fn submatrix_mut_empty_range() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
 
-        unsafe {
-            // (0, 0) => 0
-            assert_eq!(matrix.get_index_unchecked(0, 0), 0);
+        let view_result: Result<MatrixViewMut<usize>> = matrix.submatrix_mut(1..1, 0..2);
+        assert!(
+            matches!(view_result, Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+    }
 
-            // (0, 1) => 1
-            assert_eq!(matrix.get_index_unchecked(0, 1), 1);
+    // endregion
 
-            // (1, 0) => 10
-            assert_eq!(matrix.get_index_unchecked(1, 0), 10);
+    // region Properties
 
-            // (3, 7) => 37
-            assert_eq!(matrix.get_index_unchecked(3, 7), 37);
+    /// Test determining if all elements in a matrix are finite.
+    #[test]
+    fn is_finite() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
 
-            // (9, 9) => 99
-            assert_eq!(matrix.get_index_unchecked(9, 9), 99);
+        let finite: Matrix<f64> =
+            Matrix::from_slice(rows, columns, &[0.0, -1.5, 2.0, 1e300]).unwrap();
+        assert!(finite.is_finite());
 
-            // (10, 0) => 100 (out of bounds)
-            assert_eq!(matrix.get_index_unchecked(10, 0), 100);
-        }
+        let infinite: Matrix<f64> =
+            Matrix::from_slice(rows, columns, &[0.0, f64::NEG_INFINITY, 2.0, 1.0]).unwrap();
+        assert!(!infinite.is_finite());
+
+        let nan: Matrix<f64> =
+            Matrix::from_slice(rows, columns, &[0.0, 1.0, f64::NAN, 1.0]).unwrap();
+        assert!(!nan.is_finite());
     }
 
-    /// Test that getting get index when iterating over all elements in row-major format will yield
-    /// indices that are increasing exactly by 1.
+    /// Test determining if a matrix is orthogonal.
     #[test]
-    fn get_index_unchecked_correct_increments() {
-        let rows = NonZeroUsize::new(5).unwrap();
-        let columns = NonZeroUsize::new(7).unwrap();
-        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+    fn is_orthogonal() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let (sin, cos): (f64, f64) = 1.2_f64.sin_cos();
+        let rotation: Matrix<f64> = Matrix::from_slice(size, size, &[cos, -sin, sin, cos]).unwrap();
+        assert!(rotation.is_orthogonal(1e-12));
 
-        let mut previous_index: usize = 0;
-        for row in 0..matrix.get_number_of_rows() {
-            for column in 0..matrix.get_number_of_columns() {
-                unsafe {
-                    let index: usize = matrix.get_index_unchecked(row, column);
+        let scaled: Matrix<f64> = &rotation * 1.5;
+        assert!(!scaled.is_orthogonal(1e-12));
 
-                    // For the very first element e_0,0, the expected index is, of course, 0. For
-                    // all other elements, the expected index is the previous index plus 1.
-                    let expected_index: usize = match (row, column) {
-                        (0, 0) => 0,
-                        _ => previous_index + 1,
-                    };
+        // Non-square matrices are never orthogonal, even if their columns are orthonormal.
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let data: [f64; 6] = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+        let non_square: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        assert!(!non_square.is_orthogonal(1e-12));
+    }
 
-                    assert_eq!(index, expected_index, "row {}, column {}", row, column);
+    // endregion
 
-                    previous_index = index;
-                }
-            }
-        }
+    // region Element Operations
+
+    /// Test that adding noise with a standard deviation of zero does not change the matrix.
+    #[test]
+    fn add_gaussian_noise_zero_std_dev() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let noisy_result: Result<Matrix<f64>> = matrix.add_gaussian_noise(0.0, &mut rng);
+        assert!(noisy_result.is_ok());
+        assert_eq!(noisy_result.unwrap(), matrix);
+
+        let noisy: Matrix<f64> = matrix.add_gaussian_noise(0.5, &mut rng).unwrap();
+        assert_ne!(noisy, matrix);
+
+        let noisy_result: Result<Matrix<f64>> = matrix.add_gaussian_noise(-1.0, &mut rng);
+        assert!(
+            matches!(noisy_result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
     }
 
-    /// Test getting the length of the data vector based on the number of rows and columns in the
-    /// matrix when the product of the number of rows and columns does not overflow.
+    /// Test applying dropout with a mask that keeps all elements.
     #[test]
-    fn get_length_from_rows_and_columns_non_overflowing() {
-        let rows: NonZeroUsize = NonZeroUsize::new(7).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(6).unwrap();
-        let length: Result<usize> =
-            Matrix::<usize>::get_length_from_rows_and_columns(rows, columns);
+    fn apply_dropout_all_kept() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let mask: Matrix<bool> = Matrix::new(rows, columns, true).unwrap();
 
-        assert!(length.is_ok());
-        assert_eq!(length.unwrap(), rows.get() * columns.get());
+        let dropped_result: Result<Matrix<f64>> = matrix.apply_dropout(&mask, 0.5);
+        assert!(dropped_result.is_ok());
+
+        let dropped: Matrix<f64> = dropped_result.unwrap();
+        let expected: Matrix<f64> = &matrix * 2.0;
+        assert_relative_eq!(*dropped.as_slice(), *expected.as_slice());
     }
 
-    /// Test getting the length of the data vector based on the number of rows and columns in the
-    /// matrix when the product of the number of rows and columns would overflow.
+    /// Test applying dropout with a mask that drops some elements.
     #[test]
-    fn get_length_from_rows_and_columns_overflowing() {
-        let rows: NonZeroUsize = NonZeroUsize::new(::std::usize::MAX).unwrap();
+    fn apply_dropout_partially_kept() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let length: Result<usize> =
-            Matrix::<usize>::get_length_from_rows_and_columns(rows, columns);
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+        let keep: [bool; 4] = [false, true, true, false];
+        let mask: Matrix<bool> = Matrix::from_slice(rows, columns, &keep).unwrap();
 
-        assert!(
-            matches!(length, Err(Error::DimensionsTooLarge)),
-            "Expected error Error::DimensionsTooLarge not satisfied."
-        );
+        let dropped: Matrix<f64> = matrix.apply_dropout(&mask, 0.8).unwrap();
+        assert_relative_eq!(*dropped.as_slice(), [0.0, 2.5, 3.75, 0.0]);
     }
 
-    /// Test getting the length of the data vector based on the number of rows and columns in the
-    /// matrix when the product of the number of rows and columns does not overflow, without
-    /// checking if the length would exceed the maximum size.
+    /// Test applying dropout with a mismatching mask or an invalid probability.
     #[test]
-    fn get_length_from_rows_and_columns_unchecked_non_overflowing() {
-        let rows: NonZeroUsize = NonZeroUsize::new(7).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(6).unwrap();
-        unsafe {
-            let length: usize =
-                Matrix::<usize>::get_length_from_rows_and_columns_unchecked(rows, columns);
+    fn apply_dropout_invalid_arguments() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
 
-            assert_eq!(length, rows.get() * columns.get());
+        let transposed_mask: Matrix<bool> = Matrix::new(columns, rows, true).unwrap();
+        let dropped_result: Result<Matrix<f64>> = matrix.apply_dropout(&transposed_mask, 0.5);
+        assert!(
+            matches!(dropped_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        let mask: Matrix<bool> = Matrix::new(rows, columns, true).unwrap();
+        for &keep_probability in &[0.0, -0.5, 1.5, f64::NAN] {
+            let dropped_result: Result<Matrix<f64>> = matrix.apply_dropout(&mask, keep_probability);
+            assert!(
+                matches!(dropped_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
         }
     }
 
-    /// Test getting the length of the data vector based on the number of rows and columns in the
-    /// matrix when the product of the number of rows and columns would overflow, without checking
-    /// if the length would exceed the maximum size.
-    ///
-    /// In debug mode, the overflow will cause a panic.
+    /// Test applying salt-and-pepper noise with the extreme probabilities.
     #[test]
-    #[cfg(debug_assertions)]
-    #[should_panic(expected = "attempt to multiply with overflow")]
-    fn get_length_from_rows_and_columns_unchecked_overflowing_debug() {
-        let rows: NonZeroUsize = NonZeroUsize::new(::std::usize::MAX - 1).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        unsafe {
-            let _ = Matrix::<usize>::get_length_from_rows_and_columns_unchecked(rows, columns);
+    fn salt_and_pepper_extreme_probabilities() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 9] = [0.5, 1.0, 2.0, -1.0, 3.0, 0.25, 1.5, 2.5, -0.5];
+        let matrix: Matrix<f64> = Matrix::from_slice(size, size, &data).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let unchanged: Matrix<f64> = matrix.salt_and_pepper(0.0, &mut rng).unwrap();
+        assert_eq!(unchanged, matrix);
+
+        let noisy: Matrix<f64> = matrix.salt_and_pepper(1.0, &mut rng).unwrap();
+        assert!(noisy.as_slice().iter().all(|&x| x == 0.0 || x == 3.0));
+
+        for &probability in &[-0.1, 1.1, f64::NAN] {
+            let noisy_result: Result<Matrix<f64>> = matrix.salt_and_pepper(probability, &mut rng);
+            assert!(
+                matches!(noisy_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
         }
     }
 
-    /// Test getting the length of the data vector based on the number of rows and columns in the
-    /// matrix when the product of the number of rows and columns would overflow, without checking
-    /// if the length would exceed the maximum size.
-    ///
-    /// In release mode, the computation will silently overflow.
+    /// Test that a higher temperature yields a more uniform distribution in each column.
     #[test]
-    #[cfg(not(debug_assertions))]
-    fn get_length_from_rows_and_columns_unchecked_overflowing_release() {
-        let rows: NonZeroUsize = NonZeroUsize::new(::std::usize::MAX - 1).unwrap();
+    fn softmax_columns_with_temperature_valid() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        unsafe {
-            let length: usize =
-                Matrix::<usize>::get_length_from_rows_and_columns_unchecked(rows, columns);
-
-            assert_eq!(length, ::std::usize::MAX - 3);
+        let data: [f64; 6] = [1.0, -2.0, 2.0, 0.0, 4.0, 2.0];
+        let logits: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let plain: Matrix<f64> = logits.softmax_columns_with_temperature(1.0).unwrap();
+        assert_eq!(plain, Activation::Softmax.apply(&logits));
+
+        let sharp: Matrix<f64> = logits.softmax_columns_with_temperature(0.5).unwrap();
+        let flat: Matrix<f64> = logits.softmax_columns_with_temperature(5.0).unwrap();
+        for column in 0..2 {
+            let sum: f64 = (0..3).map(|row| flat[(row, column)]).sum();
+            assert_relative_eq!(sum, 1.0, epsilon = 1e-12);
+
+            // The largest probability shrinks and the smallest one grows.
+            assert!(flat[(2, column)] < plain[(2, column)]);
+            assert!(plain[(2, column)] < sharp[(2, column)]);
+            assert!(flat[(0, column)] > plain[(0, column)]);
+            assert!(plain[(0, column)] > sharp[(0, column)]);
         }
     }
 
-    /// Test getting the number of rows.
+    /// Test applying the softmax function with a temperature that is not positive.
     #[test]
-    fn get_rows() {
-        let rows: usize = 3;
-        let columns: usize = 2;
-        let matrix = Matrix {
-            rows: NonZeroUsize::new(rows).unwrap(),
-            columns: NonZeroUsize::new(columns).unwrap(),
-            data: vec![0, 1],
-        };
-
-        assert_eq!(matrix.get_number_of_rows(), rows);
+    fn softmax_columns_with_temperature_invalid() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let logits: Matrix<f64> = Matrix::new(size, size, 1.0).unwrap();
+
+        for &temperature in &[0.0, -1.0, f64::NAN] {
+            let softmax_result: Result<Matrix<f64>> =
+                logits.softmax_columns_with_temperature(temperature);
+            assert!(
+                matches!(softmax_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
     }
 
-    /// Test getting a value when the row and column are valid.
+    /// Test that each row sums up to one after applying the softmax function to the rows.
     #[test]
-    fn get_valid_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    fn softmax_rows() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
-        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let data: [f64; 9] = [1.0, 2.0, 3.0, -5.0, 0.0, 5.0, 1000.0, 1000.0, -1000.0];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
 
-        let value: Result<u64> = matrix.get(0, 0);
-        assert!(value.is_ok());
-        assert_eq!(value.unwrap(), data[0]);
+        let softmax: Matrix<f64> = matrix.softmax_rows();
+        for row in softmax.as_slice().chunks_exact(3) {
+            assert_relative_eq!(row.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+        }
+
+        // Large values do not overflow.
+        assert_relative_eq!(softmax.as_slice()[6..], [0.5, 0.5, 0.0]);
+
+        let e: f64 = 1.0_f64.exp();
+        let sum: f64 = e + e.powi(2) + e.powi(3);
+        assert_relative_eq!(
+            softmax.as_slice()[..3],
+            [e / sum, e.powi(2) / sum, e.powi(3) / sum]
+        );
     }
 
-    /// Test getting a value when the row or column are invalid.
+    // endregion
+
+    // region Linear Algebra
+
+    /// Test finding the dominant eigenvalue and eigenvector of a diagonal matrix.
     #[test]
-    fn get_invalid_dimensions() {
+    fn dominant_eigen_diagonal() {
+        let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 9] = [2.0, 0.0, 0.0, 0.0, -5.0, 0.0, 0.0, 0.0, 1.0];
+        let matrix: Matrix<f64> = Matrix::from_slice(size, size, &data).unwrap();
+
+        let eigen_result: Result<(f64, Matrix<f64>)> = matrix.dominant_eigen(1000, 1e-12);
+        assert!(eigen_result.is_ok());
+
+        let (eigenvalue, eigenvector): (f64, Matrix<f64>) = eigen_result.unwrap();
+        assert_relative_eq!(eigenvalue, -5.0, epsilon = 1e-10);
+        assert_eq!(eigenvector.get_number_of_rows(), 3);
+        assert_eq!(eigenvector.get_number_of_columns(), 1);
+        assert_relative_eq!(eigenvector.get(0, 0).unwrap(), 0.0, epsilon = 1e-10);
+        assert_relative_eq!(eigenvector.get(1, 0).unwrap().abs(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(eigenvector.get(2, 0).unwrap(), 0.0, epsilon = 1e-10);
+    }
+
+    /// Test finding the dominant eigenvalue of a matrix that is not square.
+    #[test]
+    fn dominant_eigen_not_square() {
         let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
-        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
-
-        // Both the row and column are invalid.
-        let value: Result<u64> = matrix.get(rows.get() + 1, columns.get() + 2);
+        let matrix: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
 
+        let eigen_result: Result<(f64, Matrix<f64>)> = matrix.dominant_eigen(100, 1e-10);
         assert!(
-            matches!(value, Err(Error::CellOutOfBounds)),
-            "Expected error Error::CellOutOfBounds not satisfied."
+            matches!(eigen_result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
         );
+    }
 
-        // Only the row is invalid.
-        let value: Result<u64> = matrix.get(rows.get() + 1, columns.get());
-
-        assert!(
-            matches!(value, Err(Error::CellOutOfBounds)),
-            "Expected error Error::CellOutOfBounds not satisfied."
-        );
+    /// Test finding the dominant eigenvalue with invalid parameters.
+    #[test]
+    fn dominant_eigen_invalid_parameters() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> = Matrix::new(size, size, 1.0).unwrap();
+
+        for &(iterations, tolerance) in &[(0, 1e-10), (100, 0.0), (100, f64::NAN)] {
+            let eigen_result: Result<(f64, Matrix<f64>)> =
+                matrix.dominant_eigen(iterations, tolerance);
+            assert!(
+                matches!(eigen_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
 
-        // Only the column is invalid.
-        let value: Result<u64> = matrix.get(rows.get(), columns.get() + 2);
+    /// Test finding the dominant eigenvalue of a rotation, which has two complex eigenvalues.
+    #[test]
+    fn dominant_eigen_no_convergence() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> = Matrix::from_slice(size, size, &[0.0, -1.0, 1.0, 0.0]).unwrap();
 
+        let eigen_result: Result<(f64, Matrix<f64>)> = matrix.dominant_eigen(100, 1e-10);
         assert!(
-            matches!(value, Err(Error::CellOutOfBounds)),
-            "Expected error Error::CellOutOfBounds not satisfied."
+            matches!(eigen_result, Err(Error::NoConvergence)),
+            "Expected error Error::NoConvergence not satisfied."
         );
     }
 
-    /// Test getting a value without checking the row and column when the row and column are valid.
+    // endregion
+
+    // region Norms
+
+    /// Test computing the Frobenius norm of a matrix.
     #[test]
-    fn get_unchecked_valid_dimensions() {
+    fn frobenius_norm() {
         let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
-        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> =
+            Matrix::from_slice(rows, columns, &[1.0, -2.0, 2.0, 4.0]).unwrap();
 
-        unsafe {
-            assert_eq!(matrix.get_unchecked(0, 0), 10);
-            assert_eq!(matrix.get_unchecked(0, 1), 11);
-            assert_eq!(matrix.get_unchecked(0, 2), 12);
-            assert_eq!(matrix.get_unchecked(1, 0), 13);
-            assert_eq!(matrix.get_unchecked(1, 1), 14);
-            assert_eq!(matrix.get_unchecked(1, 2), 15);
-        }
+        assert_relative_eq!(matrix.frobenius_norm(), 5.0);
     }
 
-    /// Test getting a value without checking the row and column when the row or column are invalid.
+    /// Test computing the L-infinity norm of a matrix.
     #[test]
-    #[should_panic(expected = "index out of bounds: the len is 6 but the index is 11")]
-    fn get_unchecked_invalid_dimensions() {
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    fn l_infinity_norm() {
+        let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [u64; 6] = [10, 11, 12, 13, 14, 15];
-        let matrix: Matrix<u64> = Matrix::from_slice(rows, columns, &data).unwrap();
 
-        unsafe {
-            let _: u64 = matrix.get_unchecked(rows.get(), columns.get() + 2);
-        }
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[-3.0, 1.0, 2.0]).unwrap();
+        assert_relative_eq!(matrix.l_infinity_norm(), 3.0);
+
+        let nan: Matrix<f64> = Matrix::from_slice(rows, columns, &[-3.0, f64::NAN, 2.0]).unwrap();
+        assert!(nan.l_infinity_norm().is_nan());
+    }
+
+    /// Test computing the sum of squares of a matrix.
+    #[test]
+    fn sum_of_squares() {
+        let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[3.0, 4.0]).unwrap();
+        assert_relative_eq!(matrix.sum_of_squares(), 25.0);
+        assert_relative_eq!(matrix.sum_of_squares().sqrt(), matrix.frobenius_norm());
     }
 
     // endregion
 
-    // region Element Operations
+    // region Statistics
 
-    /// Test mapping the data in a matrix.
+    /// Test computing the covariance matrix of a small dataset.
     #[test]
-    fn map() {
-        // Temperature in °C.
-        let temperatures: [usize; 6] = [0, 10, 25, 50, 75, 100];
+    fn covariance_matrix_multiple_observations() {
+        let features: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let observations: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [1.0, 2.0, 3.0, 2.0, 4.0, 7.0];
+        let matrix: Matrix<f64> = Matrix::from_slice(features, observations, &data).unwrap();
+
+        let covariance_result: Result<Matrix<f64>> = matrix.covariance_matrix();
+        assert!(covariance_result.is_ok());
+
+        let covariance: Matrix<f64> = covariance_result.unwrap();
+        assert_eq!(covariance.get_number_of_rows(), 2);
+        assert_eq!(covariance.get_number_of_columns(), 2);
+        assert_relative_eq!(*covariance.as_slice(), [1.0, 2.5, 2.5, 19.0 / 3.0]);
+    }
 
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let mut temperature: Matrix<usize> =
-            Matrix::from_slice(rows, columns, &temperatures).unwrap();
+    /// Test computing the covariance matrix of a dataset with a single observation.
+    #[test]
+    fn covariance_matrix_single_observation() {
+        let features: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let observations: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let matrix: Matrix<f64> = Matrix::from_slice(features, observations, &[1.0, 2.0]).unwrap();
 
-        // Convert Celsius to Fahrenheit (the values come out as perfect integers).
-        temperature.map(|celsius, _row, _column| (celsius * 9 / 5) + 32);
+        let covariance_result: Result<Matrix<f64>> = matrix.covariance_matrix();
+        assert!(
+            matches!(covariance_result, Err(Error::DivisionByZero)),
+            "Expected error Error::DivisionByZero not satisfied."
+        );
+    }
 
-        // Temperature in °F.
-        assert_eq!(temperature.as_slice(), [32, 50, 77, 122, 167, 212]);
+    /// Test computing the entropy of a uniform distribution.
+    #[test]
+    fn entropy_uniform() {
+        let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+        let matrix: Matrix<f64> = Matrix::new(rows, columns, 0.25).unwrap();
+
+        assert_relative_eq!(matrix.entropy(false), 4.0_f64.ln());
     }
 
-    /// Test mapping the data in a matrix by mutable reference.
+    /// Test computing the entropy of a distribution that must be normalized first and that has
+    /// elements with a probability of zero.
     #[test]
-    fn map_ref_mut() {
+    fn entropy_normalized_with_zeros() {
         let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let temperatures: [f64; 6] = [0.0, 10.0, 25.0, 50.0, 75.0, 100.0];
-        let mut matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &temperatures).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &[0.0, 5.0, 5.0, 0.0]).unwrap();
 
-        // Convert Celsius to Kelvin.
-        matrix.map_ref_mut(|celsius, _row, _column| *celsius += 273.15);
-        assert_relative_eq!(
-            *matrix.as_slice(),
-            [273.15, 283.15, 298.15, 323.15, 348.15, 373.15]
-        );
+        assert_relative_eq!(matrix.entropy(true), 2.0_f64.ln());
     }
 
-    /// Test transposing a matrix.
+    /// Test computing the mean squared difference between two matrices.
     #[test]
-    fn transpose() {
+    fn mean_squared_difference_valid_dimensions() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let a: Matrix<f64> = Matrix::from_slice(size, size, &[1.0, -2.0, 0.5, 3.0]).unwrap();
+        let b: Matrix<f64> = Matrix::from_slice(size, size, &[0.0, 1.0, 0.5, 1.0]).unwrap();
+
+        // (1 + 9 + 0 + 4) / 4
+        let mse_result: Result<f64> = a.mean_squared_difference(&b);
+        assert!(mse_result.is_ok());
+        assert_relative_eq!(mse_result.unwrap(), 3.5);
+        assert_relative_eq!(b.mean_squared_difference(&a).unwrap(), 3.5);
+    }
+
+    /// Test computing the mean squared difference between matrices of different dimensions.
+    #[test]
+    fn mean_squared_difference_dimension_mismatch() {
         let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [usize; 6] = [0, 1, 2, 3, 4, 5];
-        let matrix: Matrix<usize> = Matrix::from_slice(rows, columns, &data).unwrap();
+        let a: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
+        let b: Matrix<f64> = Matrix::new(columns, rows, 1.0).unwrap();
 
-        let transposed: Matrix<usize> = matrix.transpose();
-        assert_eq!(transposed.get_number_of_rows(), columns.get());
-        assert_eq!(transposed.get_number_of_columns(), rows.get());
-        assert_eq!(transposed.as_slice(), [0, 3, 1, 4, 2, 5]);
+        let mse_result: Result<f64> = a.mean_squared_difference(&b);
+        assert!(
+            matches!(mse_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
     }
 
-    /// Test matrix multiplication when the dimensions of the matrix are correct.
+    /// Test computing the weighted sum of the rows with uniform weights.
     #[test]
-    fn matrix_mul_correct_dimensions() {
-        let rows_m1 = NonZeroUsize::new(1).unwrap();
-        let columns_m1 = NonZeroUsize::new(3).unwrap();
-        let data_m1: [usize; 3] = [3, 4, 2];
-        let m1: Matrix<usize> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
+    fn weighted_sum_rows_uniform_weights() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
 
-        let rows_m2 = NonZeroUsize::new(3).unwrap();
-        let columns_m2 = NonZeroUsize::new(4).unwrap();
-        let data_m2: [usize; 12] = [13, 9, 7, 15, 8, 7, 4, 6, 6, 4, 0, 3];
-        let m2: Matrix<usize> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
+        // With uniform weights of `1 / rows`, the result is the mean of each column.
+        let weights: [f64; 3] = [1.0 / 3.0; 3];
+        let pooled: Matrix<f64> = matrix.weighted_sum_rows(&weights).unwrap();
+        assert_eq!(pooled.get_number_of_rows(), 1);
+        assert_eq!(pooled.get_number_of_columns(), 2);
 
-        let result: Result<Matrix<usize>> = m1.matrix_mul(&m2);
-        assert!(result.is_ok());
+        let means: [f64; 2] = [(0.25 - 0.1 - 2.73) / 3.0, (1.33 + 1.0 + 1.2) / 3.0];
+        assert_relative_eq!(*pooled.as_slice(), means);
 
-        let m3: Matrix<usize> = result.unwrap();
-        assert_eq!(m3.get_number_of_rows(), 1);
-        assert_eq!(m3.get_number_of_columns(), 4);
-        assert_eq!(m3.as_slice(), &[83, 63, 37, 75]);
+        // With weights of `1`, the result is the sum of each column, i.e. the mean times the
+        // number of rows.
+        let pooled: Matrix<f64> = matrix.weighted_sum_rows(&[1.0; 3]).unwrap();
+        assert_relative_eq!(*pooled.as_slice(), [means[0] * 3.0, means[1] * 3.0]);
     }
 
-    /// Test matrix multiplication when the dimensions of the matrix are incorrect.
+    /// Test computing the weighted sum of the rows with the wrong number of weights.
     #[test]
-    fn matrix_mul_incorrect_dimensions() {
-        let rows_m1 = NonZeroUsize::new(1).unwrap();
-        let columns_m1 = NonZeroUsize::new(3).unwrap();
-        let data_m1: [usize; 3] = [3, 4, 2];
-        let m1: Matrix<usize> = Matrix::from_slice(rows_m1, columns_m1, &data_m1).unwrap();
-
-        let rows_m2 = NonZeroUsize::new(4).unwrap();
-        let columns_m2 = NonZeroUsize::new(3).unwrap();
-        let data_m2: [usize; 12] = [13, 9, 7, 15, 8, 7, 4, 6, 6, 4, 0, 3];
-        let m2: Matrix<usize> = Matrix::from_slice(rows_m2, columns_m2, &data_m2).unwrap();
-
-        let result: Result<Matrix<usize>> = m1.matrix_mul(&m2);
+    fn weighted_sum_rows_mismatching_weights() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
 
+        let result: Result<Matrix<f64>> = matrix.weighted_sum_rows(&[0.5, 0.5]);
         assert!(
             matches!(result, Err(Error::DimensionMismatch)),
             "Expected error Error::DimensionMismatch not satisfied."
         );
     }
 
-    /// Test if matrices are partially equal for two matrices that are equal to each other.
-    #[test]
-    fn partial_eq_same_matrices() {
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data: [f64; 6] = [0.0, -1.2, 4.3, 89.9, 5.0, 6.0];
-        let m1: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
-        let m2: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    // endregion
 
-        // The matrices are equal, bot not unequal.
-        assert_eq!(m1, m2);
-        assert_eq!(m1 != m2, false);
-    }
+    // region Serialization
 
-    /// Test if matrices are partially equal to each other for two matrices that have differing
-    /// numbers of columns.
+    /// Test converting a matrix to raw bytes and back.
     #[test]
-    fn partial_eq_different_columns() {
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let m1: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
-        let m2: Matrix<f64> = Matrix::new(rows, rows, 0.0).unwrap();
-
-        assert_ne!(m1, m2);
+    fn le_bytes_round_trip() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let data: [f64; 4] = [0.25, -1.33, f64::MAX, 1e-300];
+        let matrix: Matrix<f64> = Matrix::from_slice(size, size, &data).unwrap();
+
+        let bytes: Vec<u8> = matrix.to_le_bytes();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes[..8], 0.25_f64.to_le_bytes());
+
+        let restored_result: Result<Matrix<f64>> = Matrix::from_le_bytes(size, size, &bytes);
+        assert!(restored_result.is_ok());
+        assert_eq!(restored_result.unwrap(), matrix);
     }
 
-    /// Test if matrices are partially equal to each other for two matrices that have different
-    /// data.
+    /// Test creating a matrix from a wrong number of bytes.
     #[test]
-    fn partial_eq_different_data() {
-        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
-        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let data1: &[f64] = &[0.0, -1.2, 4.3, 89.9, 5.0, 6.0];
-        let data2: &[f64] = &[0.0, -1.2, 4.3, 89.9, 5.0, 6.1];
-        let m1: Matrix<f64> = Matrix::from_slice(rows, columns, data1).unwrap();
-        let m2: Matrix<f64> = Matrix::from_slice(rows, columns, data2).unwrap();
+    fn from_le_bytes_wrong_length() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let bytes: Vec<u8> = vec![0; 31];
 
-        assert_relative_ne!(data1, data2);
-        assert_ne!(m1, m2);
+        let matrix_result: Result<Matrix<f64>> = Matrix::from_le_bytes(size, size, &bytes);
+        assert!(
+            matches!(matrix_result, Err(Error::InvalidFormat)),
+            "Expected error Error::InvalidFormat not satisfied."
+        );
+
+        let matrix_result: Result<Matrix<f64>> = Matrix::from_le_bytes(size, size, &[0; 40]);
+        assert!(
+            matches!(matrix_result, Err(Error::InvalidFormat)),
+            "Expected error Error::InvalidFormat not satisfied."
+        );
     }
 
-    /// Test if matrices are partially equal to each other for two matrices that have differing
-    /// numbers of rows.
+    // endregion
+
+    // region Visualization
+
+    /// Test rendering matrices as ASCII art.
     #[test]
-    fn partial_eq_different_rows() {
+    fn to_ascii_art() {
         let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
         let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
-        let m1: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
-        let m2: Matrix<f64> = Matrix::new(columns, columns, 0.0).unwrap();
 
-        assert_ne!(m1, m2);
-    }
+        let zeros: Matrix<f64> = Matrix::new(rows, columns, 0.0).unwrap();
+        assert_eq!(zeros.to_ascii_art(), "   \n   ");
 
-    // Test the operators.
-    test_scalar_assign_operators!();
-    test_element_wise_binary_operators!();
-    test_scalar_binary_operators!();
-    test_unary_operators!();
+        let ones: Matrix<f64> = Matrix::new(rows, columns, 1.0).unwrap();
+        assert_eq!(ones.to_ascii_art(), "@@@\n@@@");
+
+        let data: [f64; 6] = [-1.0, 0.1, 0.45, 0.6, f64::NAN, 2.0];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+        assert_eq!(matrix.to_ascii_art(), " .=\n+ @");
+    }
 
     // endregion
 
@@ -1415,6 +6678,29 @@ mod tests {
         );
     }
 
+    /// Test debug formatting a matrix with too many elements to include all of them.
+    #[test]
+    fn debug_large() {
+        let rows: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+        matrix.map(|_element, row, column| row * 10 + column);
+
+        let debug: String = format!("{:?}", matrix);
+        assert_eq!(
+            "Matrix { rows: 10, columns: 10, data: [0, 1, 2, ..., 97, 98, 99] }",
+            debug
+        );
+
+        // A matrix with exactly the maximum number of elements is still formatted completely.
+        let rows: NonZeroUsize = NonZeroUsize::new(8).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(8).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 1).unwrap();
+        let debug: String = format!("{:?}", matrix);
+        assert_eq!(debug.matches("1, ").count(), 63);
+        assert!(!debug.contains("..."));
+    }
+
     /// Test formatting the matrix in a human readable way.
     #[test]
     fn display() {
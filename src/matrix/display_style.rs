@@ -0,0 +1,319 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Configurable text formatting of matrices.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Result;
+
+/// The style in which a matrix is formatted as text by [`Matrix::display_styled`].
+///
+/// The default style is the one used by the `Display` implementation of [`Matrix`]: each row is
+/// surrounded by square brackets, the values are aligned in columns and separated by three spaces,
+/// and the rows are separated by new lines.
+///
+/// # Example
+///
+/// Format a matrix as comma-separated values:
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::DisplayStyle;
+/// use reural_network::matrix::Matrix;
+///
+/// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let matrix: Matrix<f64> = Matrix::from_slice(size, size, &[0.5, 1.0, -2.0, 10.0]).unwrap();
+///
+/// let style = DisplayStyle {
+///     open: None,
+///     close: None,
+///     separator: String::from(","),
+///     row_separator: String::from("\n"),
+///     align_columns: false,
+/// };
+/// assert_eq!(format!("{}", matrix.display_styled(&style)), "0.5,1\n-2,10");
+/// ```
+///
+/// [`Matrix`]: struct.Matrix.html
+/// [`Matrix::display_styled`]: struct.Matrix.html#method.display_styled
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisplayStyle {
+    /// The delimiter in front of each row, if any.
+    ///
+    /// Without a delimiter, e.g. for comma-separated values, the rows are not enclosed.
+    pub open: Option<char>,
+
+    /// The delimiter after each row, if any.
+    pub close: Option<char>,
+
+    /// The text between two values within a row.
+    pub separator: String,
+
+    /// The text between two rows.
+    pub row_separator: String,
+
+    /// If `true`, all values in a column will be padded to the same width.
+    pub align_columns: bool,
+}
+
+impl Default for DisplayStyle {
+    /// Get the style used by the `Display` implementation of matrices.
+    fn default() -> Self {
+        DisplayStyle {
+            open: Some('['),
+            close: Some(']'),
+            separator: String::from("   "),
+            row_separator: String::from("\n"),
+            align_columns: true,
+        }
+    }
+}
+
+/// A matrix that is formatted in a specific style.
+struct StyledMatrix<'a, T> {
+    /// The matrix to format.
+    matrix: &'a Matrix<T>,
+
+    /// The style in which the matrix is formatted.
+    style: &'a DisplayStyle,
+}
+
+impl<'a, T> Display for StyledMatrix<'a, T>
+where
+    T: Display,
+{
+    /// Format the matrix in the given style.
+    fn fmt(&self, formatter: &mut Formatter) -> ::std::fmt::Result {
+        let columns: usize = self.matrix.get_number_of_columns();
+        let values: Vec<String> = self
+            .matrix
+            .as_slice()
+            .iter()
+            .map(|value| format!("{}", value))
+            .collect();
+
+        // Each column may have a different width, which is the maximum width of all values in the
+        // column.
+        let mut column_widths: Vec<usize> = vec![0; columns];
+        if self.style.align_columns {
+            for (index, value) in values.iter().enumerate() {
+                let width: &mut usize = &mut column_widths[index % columns];
+                *width = (*width).max(value.chars().count());
+            }
+        }
+
+        let rows: Vec<String> = values
+            .chunks_exact(columns)
+            .map(|row| {
+                let row_values: Vec<String> = row
+                    .iter()
+                    .zip(&column_widths)
+                    .map(|(value, width)| format!("{:<width$}", value, width = width))
+                    .collect();
+
+                let mut row_text: String = String::new();
+                row_text.extend(self.style.open);
+                row_text.push_str(&row_values.join(&self.style.separator));
+                row_text.extend(self.style.close);
+                row_text
+            })
+            .collect();
+
+        write!(formatter, "{}", rows.join(&self.style.row_separator))
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Display,
+{
+    /// Get a value that formats the matrix as text in the given `style`, e.g. for tools that
+    /// expect a specific text format.
+    ///
+    /// See [`DisplayStyle`] for an example.
+    ///
+    /// [`DisplayStyle`]: struct.DisplayStyle.html
+    pub fn display_styled<'a>(&'a self, style: &'a DisplayStyle) -> impl Display + 'a {
+        StyledMatrix {
+            matrix: self,
+            style,
+        }
+    }
+
+    /// Format the matrix as a table with the given `row_labels` in front of the rows and the
+    /// given `column_labels` above the columns, e.g. to print a confusion matrix with the names of
+    /// the classes.
+    ///
+    /// The labels and the values are aligned in columns which are separated by three spaces. The
+    /// rows are separated by new lines.
+    ///
+    /// There must be exactly one row label per row and one column label per column. Otherwise, an
+    /// [`Error::DimensionMismatch`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let classes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let confusion_matrix: Matrix<usize> =
+    ///     Matrix::from_slice(classes, classes, &[12, 3, 1, 9]).unwrap();
+    ///
+    /// let labels: Vec<String> = vec![String::from("cat"), String::from("dog")];
+    /// let table: String = confusion_matrix.display_labeled(&labels, &labels).unwrap();
+    /// assert_eq!(table, "      cat   dog\ncat   12    3  \ndog   1     9  ");
+    /// ```
+    ///
+    /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+    pub fn display_labeled(
+        &self,
+        row_labels: &[String],
+        column_labels: &[String],
+    ) -> Result<String> {
+        let columns: usize = self.get_number_of_columns();
+        if row_labels.len() != self.get_number_of_rows() || column_labels.len() != columns {
+            return Err(Error::DimensionMismatch);
+        }
+
+        // The first cell in the header row is empty, above the row labels.
+        let mut cells: Vec<String> = Vec::with_capacity((row_labels.len() + 1) * (columns + 1));
+        cells.push(String::new());
+        cells.extend(column_labels.iter().cloned());
+        for (row_label, row) in row_labels.iter().zip(self.as_slice().chunks_exact(columns)) {
+            cells.push(row_label.clone());
+            cells.extend(row.iter().map(|value| format!("{}", value)));
+        }
+
+        // Each column of the table may have a different width, which is the maximum width of all
+        // cells in the column.
+        let mut column_widths: Vec<usize> = vec![0; columns + 1];
+        for (index, cell) in cells.iter().enumerate() {
+            let width: &mut usize = &mut column_widths[index % (columns + 1)];
+            *width = (*width).max(cell.chars().count());
+        }
+
+        let lines: Vec<String> = cells
+            .chunks_exact(columns + 1)
+            .map(|line| {
+                let padded: Vec<String> = line
+                    .iter()
+                    .zip(&column_widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                    .collect();
+                padded.join("   ")
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+
+    /// Test formatting a matrix as comma-separated values.
+    #[test]
+    fn display_styled_comma_separated() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<f64> = Matrix::from_slice(size, size, &[0.25, 1.0, -3.5, 4.0]).unwrap();
+
+        let style = DisplayStyle {
+            open: None,
+            close: None,
+            separator: String::from(", "),
+            row_separator: String::from("; "),
+            align_columns: false,
+        };
+        let formatted: String = format!("{}", matrix.display_styled(&style));
+        assert_eq!(formatted, "0.25, 1; -3.5, 4");
+    }
+
+    /// Test formatting a matrix in the default style.
+    #[test]
+    fn display_styled_default() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let style: DisplayStyle = DisplayStyle::default();
+        let formatted: String = format!("{}", matrix.display_styled(&style));
+        assert_eq!(formatted, format!("{}", matrix));
+    }
+
+    /// Test that values with non-ASCII characters are aligned by their number of characters.
+    #[test]
+    fn display_styled_non_ascii() {
+        let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<&str> =
+            Matrix::from_slice(size, size, &["äö", "x", "abc", "y"]).unwrap();
+
+        let style = DisplayStyle {
+            open: Some('('),
+            close: Some(')'),
+            separator: String::from(" "),
+            row_separator: String::from("\n"),
+            align_columns: true,
+        };
+        let formatted: String = format!("{}", matrix.display_styled(&style));
+        assert_eq!(formatted, "(äö  x)\n(abc y)");
+
+        // The labeled table measures its cells the same way.
+        let labels: Vec<String> = vec![String::from("ü"), String::from("v")];
+        let table: String = matrix.display_labeled(&labels, &labels).unwrap();
+        assert_eq!(table, "    ü     v\nü   äö    x\nv   abc   y");
+    }
+
+    /// Test labeling the rows and columns of a confusion matrix with the names of the classes.
+    #[test]
+    fn display_labeled_valid_labels() {
+        let classes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let matrix: Matrix<usize> = Matrix::from_slice(classes, classes, &[5, 120, 7, 3]).unwrap();
+        let labels: Vec<String> = vec![String::from("setosa"), String::from("virginica")];
+
+        let table_result: Result<String> = matrix.display_labeled(&labels, &labels);
+        assert!(table_result.is_ok());
+        assert_eq!(
+            table_result.unwrap(),
+            "            setosa   virginica\n\
+             setosa      5        120      \n\
+             virginica   7        3        "
+        );
+    }
+
+    /// Test labeling a matrix with the wrong number of labels.
+    #[test]
+    fn display_labeled_mismatching_labels() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+        let two: Vec<String> = vec![String::from("a"), String::from("b")];
+        let three: Vec<String> = vec![String::from("x"), String::from("y"), String::from("z")];
+
+        assert!(matrix.display_labeled(&two, &three).is_ok());
+
+        let table_result: Result<String> = matrix.display_labeled(&two, &two);
+        assert!(
+            matches!(table_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+
+        let table_result: Result<String> = matrix.display_labeled(&three, &three);
+        assert!(
+            matches!(table_result, Err(Error::DimensionMismatch)),
+            "Expected error Error::DimensionMismatch not satisfied."
+        );
+    }
+}
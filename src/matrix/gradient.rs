@@ -0,0 +1,122 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Numerical differentiation of scalar functions of matrices.
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Result;
+
+/// Numerically compute the gradient of the scalar function `function` at the point `at`, i.e. the
+/// partial derivatives of `function` with respect to each element of `at`.
+///
+/// Each partial derivative is approximated with the central difference
+///
+/// ```text
+/// (function(at + epsilon * e) - function(at - epsilon * e)) / (2 * epsilon)
+/// ```
+///
+/// where `e` is the matrix that is `1.0` at the position of the element and `0.0` everywhere else.
+/// The result has the same dimensions as `at`. This is useful for checking analytically derived
+/// gradients.
+///
+/// `epsilon` must be greater than `0.0`. Otherwise, an [`Error::InvalidRange`] will be returned.
+///
+/// # Example
+///
+/// The gradient of the sum of all squared elements is twice the matrix:
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::numerical_gradient;
+/// use reural_network::matrix::Matrix;
+///
+/// let rows: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+/// let columns: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let at: Matrix<f64> = Matrix::from_slice(rows, columns, &[1.0, -2.0]).unwrap();
+///
+/// let sum_of_squares = |x: &Matrix<f64>| x.as_slice().iter().map(|e| e * e).sum::<f64>();
+/// let gradient: Matrix<f64> = numerical_gradient(sum_of_squares, &at, 1e-5).unwrap();
+/// assert!((gradient.get(0, 0).unwrap() - 2.0).abs() < 1e-6);
+/// assert!((gradient.get(0, 1).unwrap() + 4.0).abs() < 1e-6);
+/// ```
+///
+/// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+pub fn numerical_gradient<F>(function: F, at: &Matrix<f64>, epsilon: f64) -> Result<Matrix<f64>>
+where
+    F: Fn(&Matrix<f64>) -> f64,
+{
+    if epsilon.is_nan() || epsilon <= 0.0 {
+        return Err(Error::InvalidRange);
+    }
+
+    // Perturb the elements of a copy of the point one after another, resetting each element to
+    // its original value afterwards.
+    let mut point: Matrix<f64> = at.clone();
+    let mut gradient: Matrix<f64> = at.clone();
+    for row in 0..at.get_number_of_rows() {
+        for column in 0..at.get_number_of_columns() {
+            let original: f64 = at[(row, column)];
+
+            point[(row, column)] = original + epsilon;
+            let value_plus: f64 = function(&point);
+
+            point[(row, column)] = original - epsilon;
+            let value_minus: f64 = function(&point);
+
+            point[(row, column)] = original;
+            gradient[(row, column)] = (value_plus - value_minus) / (2.0 * epsilon);
+        }
+    }
+
+    Ok(gradient)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    /// Test the numerical gradient of the sum of squares against its analytic gradient.
+    #[test]
+    fn numerical_gradient_sum_of_squares() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let at: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let sum_of_squares = |x: &Matrix<f64>| x.as_slice().iter().map(|e| e * e).sum::<f64>();
+        let gradient: Matrix<f64> = numerical_gradient(sum_of_squares, &at, 1e-5).unwrap();
+        assert_eq!(gradient.get_number_of_rows(), 2);
+        assert_eq!(gradient.get_number_of_columns(), 3);
+
+        let analytic_gradient: Matrix<f64> = &at * 2.0;
+        assert_relative_eq!(
+            *gradient.as_slice(),
+            *analytic_gradient.as_slice(),
+            epsilon = 1e-6
+        );
+    }
+
+    /// Test the numerical gradient with an invalid epsilon.
+    #[test]
+    fn numerical_gradient_invalid_epsilon() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let at: Matrix<f64> = Matrix::new(rows, rows, 1.0).unwrap();
+
+        for &epsilon in &[0.0, -1e-5, f64::NAN] {
+            let result: Result<Matrix<f64>> = numerical_gradient(|_x| 0.0, &at, epsilon);
+            assert!(
+                matches!(result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
+}
@@ -40,3 +40,45 @@ macro_rules! specify_matrix_type {
         $crate::specify_type!($token Matrix<T>)
     }
 }
+
+/// Get the data vector of the given matrix variable, either by moving it out of an owned matrix or
+/// by cloning it from a referenced matrix.
+///
+/// # Parameters
+///
+/// * `$access`: Either `*` if `$matrix` is an owned matrix or `&` if it is a referenced matrix.
+/// * `$matrix`: The matrix variable from which to get the data vector.
+///
+/// # Example
+///
+/// ```
+/// # use reural_network::take_or_clone_matrix_data;
+/// #
+/// # struct Matrix<T> {
+/// #     data: Vec<T>,
+/// # };
+/// #
+/// let owned = Matrix { data: vec![1, 2, 3] };
+/// let referenced = &Matrix { data: vec![4, 5, 6] };
+///
+/// // Move the data out of the owned matrix.
+/// let data_owned: Vec<usize> = take_or_clone_matrix_data!(* owned);
+/// assert_eq!(data_owned, [1, 2, 3]);
+///
+/// // Clone the data from the referenced matrix.
+/// let data_referenced: Vec<usize> = take_or_clone_matrix_data!(& referenced);
+/// assert_eq!(data_referenced, [4, 5, 6]);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! take_or_clone_matrix_data {
+    // Move the data out of an owned matrix.
+    (* $matrix:ident) => {
+        $matrix.data
+    };
+
+    // Clone the data of a referenced matrix.
+    (& $matrix:ident) => {
+        $matrix.data.clone()
+    };
+}
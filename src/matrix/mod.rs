@@ -7,11 +7,28 @@
 
 //! A simple and naive implementation of mathematical matrices.
 
+pub use self::builder::MatrixBuilder;
+pub use self::comparison::matrices_within;
 pub use self::definition::Matrix;
+pub use self::display_style::DisplayStyle;
+pub use self::gradient::numerical_gradient;
+pub use self::product::matmul;
+pub use self::view_mut::MatrixViewMut;
 
 mod assign_operators_scalar;
 mod binary_operators_element_wise;
 mod binary_operators_scalar;
+mod builder;
+mod comparison;
 mod definition;
+mod display_style;
+mod gradient;
 mod macros;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+mod npy;
+mod product;
+#[cfg(feature = "serde")]
+mod serde_interop;
 mod unary_operators;
+mod view_mut;
@@ -0,0 +1,127 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Conversion between matrices and two-dimensional arrays of the `ndarray` crate.
+//!
+//! This module is only available if the `ndarray` feature is enabled.
+
+use std::num::NonZeroUsize;
+
+use ndarray::Array2;
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Result;
+
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Create a new matrix from the given two-dimensional `ndarray` array, e.g. to continue
+    /// working on the result of an operation that this crate does not implement.
+    ///
+    /// The element at row `i` and column `j` of the array will be the element at row `i` and
+    /// column `j` of the matrix, independent of the memory layout of the array.
+    ///
+    /// If the array does not have any elements, an [`Error::EmptyMatrix`] will be returned.
+    ///
+    /// This method is only available if the `ndarray` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let array = array![[1, 2, 3], [4, 5, 6]];
+    /// let matrix: Matrix<i32> = Matrix::from_ndarray(array).unwrap();
+    /// assert_eq!(matrix.get_number_of_rows(), 2);
+    /// assert_eq!(matrix.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// [`Error::EmptyMatrix`]: ../enum.Error.html#variant.EmptyMatrix
+    pub fn from_ndarray(array: Array2<T>) -> Result<Matrix<T>> {
+        let (rows, columns): (usize, usize) = array.dim();
+        let rows: NonZeroUsize = NonZeroUsize::new(rows).ok_or(Error::EmptyMatrix)?;
+        let columns: NonZeroUsize = NonZeroUsize::new(columns).ok_or(Error::EmptyMatrix)?;
+
+        // Iterating the array always yields the elements in row-major order.
+        let data: Vec<T> = array.iter().cloned().collect();
+
+        Ok(Matrix::from_vec_unchecked(rows, columns, data))
+    }
+
+    /// Convert the matrix into a two-dimensional `ndarray` array with the same dimensions, e.g. to
+    /// apply an operation that this crate does not implement.
+    ///
+    /// This method is only available if the `ndarray` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use ndarray::array;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let matrix: Matrix<i32> = Matrix::from_slice(rows, columns, &[1, 2, 3, 4, 5, 6]).unwrap();
+    ///
+    /// assert_eq!(matrix.to_ndarray(), array![[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn to_ndarray(&self) -> Array2<T> {
+        let columns: usize = self.get_number_of_columns();
+        let data: &[T] = self.as_slice();
+        Array2::from_shape_fn((self.get_number_of_rows(), columns), |(row, column)| {
+            data[row * columns + column].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that converting a matrix into an array and back reproduces the matrix.
+    #[test]
+    fn ndarray_round_trip() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let array: Array2<f64> = matrix.to_ndarray();
+        assert_eq!(array.dim(), (2, 3));
+        assert_eq!(array[[1, 0]], 1.0);
+
+        let round_trip_result: Result<Matrix<f64>> = Matrix::from_ndarray(array);
+        assert!(round_trip_result.is_ok());
+        assert_eq!(round_trip_result.unwrap(), matrix);
+    }
+
+    /// Test that converting a transposed array respects its logical order.
+    #[test]
+    fn from_ndarray_column_major() {
+        let array: Array2<i32> = Array2::from_shape_vec((2, 3), vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        let matrix: Matrix<i32> = Matrix::from_ndarray(array.reversed_axes()).unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 3);
+        assert_eq!(matrix.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    }
+
+    /// Test converting an array without any elements.
+    #[test]
+    fn from_ndarray_empty() {
+        let array: Array2<i32> = Array2::from_shape_vec((0, 3), Vec::new()).unwrap();
+
+        let matrix_result: Result<Matrix<i32>> = Matrix::from_ndarray(array);
+        assert!(
+            matches!(matrix_result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
+        );
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Serialization of matrices to and from the NumPy `.npy` format.
+//!
+//! See the [format specification] for details.
+//!
+//! [format specification]: https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html
+
+use std::io::Read;
+use std::io::Write;
+use std::num::NonZeroUsize;
+
+use crate::matrix::Matrix;
+use crate::Error;
+use crate::Result;
+
+/// The magic string at the beginning of each `.npy` file.
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The alignment of the data in bytes, i.e. the length of the magic string, the version, the
+/// header length, and the header is a multiple of this value.
+const ALIGNMENT: usize = 64;
+
+/// The maximum number of elements for which memory will be allocated before reading the data.
+///
+/// The shape in the header cannot be trusted, thus larger matrices grow their buffer while the
+/// data is read, so that a corrupt header results in an error instead of a failed allocation.
+const MAX_PREALLOCATED_ELEMENTS: usize = 1 << 16;
+
+impl Matrix<f64> {
+    // region Serialization
+
+    /// Read a matrix from the given `reader` in the NumPy `.npy` format, e.g. from a file that has
+    /// been saved with `numpy.save`.
+    ///
+    /// The file must contain a one- or two-dimensional array of little-endian 64 bit floats (the
+    /// NumPy type `<f8`) in either C or Fortran order. A one-dimensional array of length `n`
+    /// will become an `n x 1` column vector. Both the format versions 1.0 and 2.0 are supported.
+    ///
+    /// If the header is not valid or describes an unsupported array, an [`Error::InvalidFormat`]
+    /// will be returned. If the array does not contain any elements, an [`Error::EmptyMatrix`]
+    /// will be returned. Errors while reading (including a premature end of the data) will be
+    /// returned as an [`Error::Io`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    ///
+    /// let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+    /// let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+    ///
+    /// let mut bytes: Vec<u8> = Vec::new();
+    /// matrix.to_npy(&mut bytes).unwrap();
+    ///
+    /// let loaded: Matrix<f64> = Matrix::from_npy(bytes.as_slice()).unwrap();
+    /// assert_eq!(loaded, matrix);
+    /// ```
+    ///
+    /// [`Error::EmptyMatrix`]: ../enum.Error.html#variant.EmptyMatrix
+    /// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub fn from_npy<R>(mut reader: R) -> Result<Matrix<f64>>
+    where
+        R: Read,
+    {
+        let mut preamble: [u8; 8] = [0; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != MAGIC {
+            return Err(Error::InvalidFormat);
+        }
+
+        // The length of the header is stored on two bytes in version 1.0 and on four bytes in
+        // version 2.0.
+        let header_length: usize = match (preamble[6], preamble[7]) {
+            (1, 0) => {
+                let mut length: [u8; 2] = [0; 2];
+                reader.read_exact(&mut length)?;
+                u16::from_le_bytes(length) as usize
+            }
+            (2, 0) => {
+                let mut length: [u8; 4] = [0; 4];
+                reader.read_exact(&mut length)?;
+                u32::from_le_bytes(length) as usize
+            }
+            _ => return Err(Error::InvalidFormat),
+        };
+
+        let mut header: Vec<u8> = vec![0; header_length];
+        reader.read_exact(&mut header)?;
+        let header: String = String::from_utf8(header).map_err(|_| Error::InvalidFormat)?;
+
+        if get_header_value(&header, "descr")? != "'<f8'" {
+            return Err(Error::InvalidFormat);
+        }
+
+        let fortran_order: bool = match get_header_value(&header, "fortran_order")? {
+            "False" => false,
+            "True" => true,
+            _ => return Err(Error::InvalidFormat),
+        };
+
+        let (rows, columns): (usize, usize) = parse_shape(get_header_value(&header, "shape")?)?;
+        let (rows, columns): (NonZeroUsize, NonZeroUsize) =
+            match (NonZeroUsize::new(rows), NonZeroUsize::new(columns)) {
+                (Some(rows), Some(columns)) => (rows, columns),
+                _ => return Err(Error::EmptyMatrix),
+            };
+
+        let length: usize = rows
+            .get()
+            .checked_mul(columns.get())
+            .ok_or(Error::DimensionsTooLarge)?;
+        let mut data: Vec<f64> = Vec::with_capacity(length.min(MAX_PREALLOCATED_ELEMENTS));
+        let mut bytes: [u8; 8] = [0; 8];
+        for _ in 0..length {
+            reader.read_exact(&mut bytes)?;
+            data.push(f64::from_le_bytes(bytes));
+        }
+
+        // In Fortran order, the data is stored column by column, i.e. it is the row-major data of
+        // the transposed matrix.
+        if fortran_order {
+            Ok(Matrix::from_vec(columns, rows, data)?.transpose())
+        } else {
+            Matrix::from_vec(rows, columns, data)
+        }
+    }
+
+    /// Write the matrix to the given `writer` in the NumPy `.npy` format (version 1.0), e.g. to
+    /// load it with `numpy.load`.
+    ///
+    /// The matrix will be written as a two-dimensional array of little-endian 64 bit floats (the
+    /// NumPy type `<f8`) in C order.
+    ///
+    /// Errors while writing will be returned as an [`Error::Io`].
+    ///
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub fn to_npy<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        let mut header: String = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+            self.get_number_of_rows(),
+            self.get_number_of_columns()
+        );
+
+        // Pad the header with spaces and terminate it with a newline so that the data is aligned.
+        // The preamble consists of the magic string, the version, and the header length.
+        let preamble_length: usize = MAGIC.len() + 2 + 2;
+        let unpadded_length: usize = preamble_length + header.len() + 1;
+        let padding: usize = (ALIGNMENT - unpadded_length % ALIGNMENT) % ALIGNMENT;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for element in self.as_slice() {
+            writer.write_all(&element.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+/// Get the value for the given `key` from the `header`, which is the string representation of a
+/// Python dictionary.
+///
+/// If the key does not exist, an [`Error::InvalidFormat`] will be returned.
+///
+/// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+fn get_header_value<'a>(header: &'a str, key: &str) -> Result<&'a str> {
+    let key: String = format!("'{}':", key);
+    let start: usize = header.find(&key).ok_or(Error::InvalidFormat)? + key.len();
+    let value: &str = header[start..].trim_start();
+
+    // Tuples and strings may contain commas, thus find their closing delimiter first.
+    let end: Option<usize> = match value.chars().next() {
+        Some('(') => value.find(')').map(|end| end + 1),
+        Some(quote @ '\'') | Some(quote @ '"') => value[1..].find(quote).map(|end| end + 2),
+        Some(_) => value.find([',', '}']),
+        None => None,
+    };
+
+    match end {
+        Some(end) => Ok(value[..end].trim_end()),
+        None => Err(Error::InvalidFormat),
+    }
+}
+
+/// Parse the `shape` tuple of a one- or two-dimensional array into the number of rows and columns.
+/// A one-dimensional array becomes a column vector.
+///
+/// If the shape is not a valid tuple with one or two dimensions, an [`Error::InvalidFormat`] will
+/// be returned.
+///
+/// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+fn parse_shape(shape: &str) -> Result<(usize, usize)> {
+    if !shape.starts_with('(') || !shape.ends_with(')') {
+        return Err(Error::InvalidFormat);
+    }
+
+    let dimensions: Vec<usize> = shape[1..shape.len() - 1]
+        .split(',')
+        .map(str::trim)
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| dimension.parse::<usize>().map_err(|_| Error::InvalidFormat))
+        .collect::<Result<Vec<usize>>>()?;
+
+    match dimensions.as_slice() {
+        [rows] => Ok((*rows, 1)),
+        [rows, columns] => Ok((*rows, *columns)),
+        _ => Err(Error::InvalidFormat),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the bytes of a `.npy` file for a matrix of the given shape with the given data, in the
+    /// exact layout written by `numpy.save`.
+    fn numpy_bytes(header: &str, data: &[f64]) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::new();
+        bytes.extend_from_slice(b"\x93NUMPY\x01\x00");
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for element in data {
+            bytes.extend_from_slice(&element.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Test writing a matrix in the `.npy` format and reading it again.
+    #[test]
+    fn round_trip() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let mut bytes: Vec<u8> = Vec::new();
+        assert!(matrix.to_npy(&mut bytes).is_ok());
+
+        // The data is aligned to 64 bytes.
+        assert_eq!(bytes.len(), 128 + 6 * 8);
+
+        let loaded_result: Result<Matrix<f64>> = Matrix::from_npy(bytes.as_slice());
+        assert!(loaded_result.is_ok());
+        assert_eq!(loaded_result.unwrap(), matrix);
+    }
+
+    /// Test that the written bytes are exactly the ones written by NumPy.
+    #[test]
+    fn to_npy_matches_numpy() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let header: String = format!(
+            "{}{}\n",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+            " ".repeat(58)
+        );
+
+        let mut bytes: Vec<u8> = Vec::new();
+        matrix.to_npy(&mut bytes).unwrap();
+        assert_eq!(bytes, numpy_bytes(&header, &data));
+    }
+
+    /// Test reading a matrix stored in Fortran order.
+    #[test]
+    fn from_npy_fortran_order() {
+        let header: &str = "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }\n";
+        let bytes: Vec<u8> = numpy_bytes(header, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+
+        let matrix: Matrix<f64> = Matrix::from_npy(bytes.as_slice()).unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 2);
+        assert_eq!(matrix.get_number_of_columns(), 3);
+        assert_eq!(matrix.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    /// Test reading a one-dimensional array as a column vector.
+    #[test]
+    fn from_npy_one_dimensional() {
+        let header: &str = "{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }\n";
+        let bytes: Vec<u8> = numpy_bytes(header, &[1.0, 2.0, 3.0]);
+
+        let matrix: Matrix<f64> = Matrix::from_npy(bytes.as_slice()).unwrap();
+        assert_eq!(matrix.get_number_of_rows(), 3);
+        assert_eq!(matrix.get_number_of_columns(), 1);
+        assert_eq!(matrix.as_slice(), &[1.0, 2.0, 3.0]);
+    }
+
+    /// Test reading files with unsupported headers.
+    #[test]
+    fn from_npy_unsupported_header() {
+        let headers: [&str; 4] = [
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (2, 3), }\n",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3, 1), }\n",
+            "{'descr': '<f8', 'shape': (2, 3), }\n",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2, x), }\n",
+        ];
+
+        for header in &headers {
+            let bytes: Vec<u8> = numpy_bytes(header, &[0.0; 6]);
+            let result: Result<Matrix<f64>> = Matrix::from_npy(bytes.as_slice());
+            assert!(
+                matches!(result, Err(Error::InvalidFormat)),
+                "Expected error Error::InvalidFormat not satisfied."
+            );
+        }
+
+        let result: Result<Matrix<f64>> = Matrix::from_npy(&b"NUMPY\x01\x00\x00\x00"[..]);
+        assert!(
+            matches!(result, Err(Error::InvalidFormat)),
+            "Expected error Error::InvalidFormat not satisfied."
+        );
+    }
+
+    /// Test reading an empty array.
+    #[test]
+    fn from_npy_empty_array() {
+        let header: &str = "{'descr': '<f8', 'fortran_order': False, 'shape': (0, 3), }\n";
+        let bytes: Vec<u8> = numpy_bytes(header, &[]);
+
+        let result: Result<Matrix<f64>> = Matrix::from_npy(bytes.as_slice());
+        assert!(
+            matches!(result, Err(Error::EmptyMatrix)),
+            "Expected error Error::EmptyMatrix not satisfied."
+        );
+    }
+
+    /// Test reading a file whose data ends prematurely.
+    #[test]
+    fn from_npy_truncated_data() {
+        let header: &str = "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }\n";
+        let bytes: Vec<u8> = numpy_bytes(header, &[0.0; 5]);
+
+        let result: Result<Matrix<f64>> = Matrix::from_npy(bytes.as_slice());
+        assert!(
+            matches!(result, Err(Error::Io(_))),
+            "Expected error Error::Io not satisfied."
+        );
+    }
+
+    /// Test reading a file whose header describes a huge array, but whose data is short.
+    #[test]
+    fn from_npy_huge_shape() {
+        let shapes: [&str; 2] = ["(4294967296, 4294967295)", "(268435456, 268435456)"];
+        for shape in &shapes {
+            let header: String = format!(
+                "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}\n",
+                shape
+            );
+            let bytes: Vec<u8> = numpy_bytes(&header, &[0.0; 3]);
+
+            let result: Result<Matrix<f64>> = Matrix::from_npy(bytes.as_slice());
+            assert!(
+                matches!(result, Err(Error::Io(_))),
+                "Expected error Error::Io not satisfied."
+            );
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! The matrix product as a free function.
+
+use std::ops::Add;
+use std::ops::Mul;
+
+use crate::matrix::Matrix;
+use crate::Result;
+
+/// Compute the matrix product of `a` and `b` and return the result.
+///
+/// This is the same as [`Matrix::mm`], written in the notation `matmul(a, b)` known from other
+/// libraries. Note that the `*` operator on two matrices computes the element-wise (Hadamard)
+/// product instead.
+///
+/// The number of columns in `a` must be equal to the number of rows in `b`. Otherwise,
+/// [`Error::DimensionMismatch`] will be returned.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::matmul;
+/// use reural_network::matrix::Matrix;
+///
+/// let size: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let a: Matrix<i32> = Matrix::from_slice(size, size, &[1, 2, 3, 4]).unwrap();
+/// let b: Matrix<i32> = Matrix::from_slice(size, size, &[5, 6, 7, 8]).unwrap();
+///
+/// let product: Matrix<i32> = matmul(&a, &b).unwrap();
+/// assert_eq!(product.as_slice(), &[19, 22, 43, 50]);
+/// ```
+///
+/// [`Matrix::mm`]: struct.Matrix.html#method.mm
+/// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+pub fn matmul<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: Add<T, Output = T> + Mul<T, Output = T> + Copy,
+{
+    a.mm(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+
+    /// Test that the free function computes the same result as the method.
+    #[test]
+    fn matmul_matches_mm() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let a: Matrix<f64> =
+            Matrix::from_slice(rows, columns, &[0.5, 1.0, -2.0, 3.0, 0.0, 1.5]).unwrap();
+        let b: Matrix<f64> = a.transpose();
+
+        assert_eq!(matmul(&a, &b).unwrap(), a.mm(&b).unwrap());
+        assert_eq!(matmul(&b, &a).unwrap(), b.mm(&a).unwrap());
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Serialization and deserialization of matrices with `serde`, e.g. to persist trained weights.
+//!
+//! A matrix is serialized as a struct with the fields `rows`, `columns`, and `data`, where `data`
+//! contains the elements in row-major order. This module is only available if the `serde` feature
+//! is enabled.
+
+use std::num::NonZeroUsize;
+
+use serde::de;
+use serde::ser::SerializeStruct;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::matrix::Matrix;
+use crate::Error;
+
+/// The unvalidated representation of a deserialized matrix.
+///
+/// The dimensions are plain numbers so that matrices without rows or columns can be deserialized
+/// and rejected with a meaningful error afterwards.
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct RawMatrix<T> {
+    /// The number of rows in the matrix.
+    rows: usize,
+
+    /// The number of columns in the matrix.
+    columns: usize,
+
+    /// The elements of the matrix in row-major order.
+    data: Vec<T>,
+}
+
+impl<T> Serialize for Matrix<T>
+where
+    T: Serialize,
+{
+    /// Serialize the dimensions and the data of the matrix.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &self.get_number_of_rows())?;
+        state.serialize_field("columns", &self.get_number_of_columns())?;
+        state.serialize_field("data", self.as_slice())?;
+        state.end()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Matrix<T>
+where
+    T: Deserialize<'de>,
+{
+    /// Deserialize a matrix, ensuring that it has at least one row and one column, and that the
+    /// number of elements matches its dimensions.
+    ///
+    /// If the matrix does not have any rows or columns, the error message of
+    /// [`Error::EmptyMatrix`] will be reported. If the number of elements does not match the
+    /// dimensions, the error message of [`Error::DimensionMismatch`] will be reported.
+    ///
+    /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
+    /// [`Error::EmptyMatrix`]: ../enum.Error.html#variant.EmptyMatrix
+    fn deserialize<D>(deserializer: D) -> Result<Matrix<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: RawMatrix<T> = RawMatrix::deserialize(deserializer)?;
+        let rows: NonZeroUsize =
+            NonZeroUsize::new(raw.rows).ok_or_else(|| de::Error::custom(Error::EmptyMatrix))?;
+        let columns: NonZeroUsize =
+            NonZeroUsize::new(raw.columns).ok_or_else(|| de::Error::custom(Error::EmptyMatrix))?;
+
+        Matrix::from_vec(rows, columns, raw.data).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that serializing a matrix to JSON and back reproduces the matrix.
+    #[test]
+    fn json_round_trip() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let data: [f64; 6] = [0.25, 1.33, -0.1, 1.0, -2.73, 1.2];
+        let matrix: Matrix<f64> = Matrix::from_slice(rows, columns, &data).unwrap();
+
+        let json: String = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rows":2,"columns":3,"data":[0.25,1.33,-0.1,1.0,-2.73,1.2]}"#
+        );
+
+        let deserialized: Matrix<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, matrix);
+    }
+
+    /// Test deserializing matrices without rows or columns.
+    #[test]
+    fn deserialize_empty() {
+        let result: serde_json::Result<Matrix<i32>> =
+            serde_json::from_str(r#"{"rows":0,"columns":3,"data":[]}"#);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with(&Error::EmptyMatrix.to_string()));
+
+        let result: serde_json::Result<Matrix<i32>> =
+            serde_json::from_str(r#"{"rows":1,"columns":0,"data":[]}"#);
+        assert!(result.is_err());
+    }
+
+    /// Test deserializing a matrix whose number of elements does not match its dimensions.
+    #[test]
+    fn deserialize_dimension_mismatch() {
+        let result: serde_json::Result<Matrix<i32>> =
+            serde_json::from_str(r#"{"rows":2,"columns":2,"data":[1,2,3]}"#);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with(&Error::DimensionMismatch.to_string()));
+    }
+}
@@ -391,11 +391,9 @@ macro_rules! doc_unary_operator {
             stringify!($operator),
             $crate::access_variable_as_string!($access matrix),
             ";\n",
-            "assert_eq!(result.as_slice(), &",
-            stringify!($expected_result),
-            ");\n",
+            $crate::assert_result_as_string!($data_type, "result", $expected_result),
             "```"
-        );
+        )
     };
 }
 
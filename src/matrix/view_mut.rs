@@ -0,0 +1,146 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Definition and implementation of mutable views into a rectangular region of a matrix.
+
+use std::num::NonZeroUsize;
+
+use crate::Error;
+use crate::Result;
+
+/// A mutable view into a rectangular region of a matrix.
+///
+/// The view does not copy any data. All writes through the view are directly performed on the
+/// matrix from which the view has been created. Rows and columns of the view are zero-indexed
+/// relative to the top left element of the region.
+///
+/// A view can be created using [`Matrix::submatrix_mut`].
+///
+/// [`Matrix::submatrix_mut`]: struct.Matrix.html#method.submatrix_mut
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    /// The data of the matrix from which this view has been created.
+    data: &'a mut [T],
+
+    /// The number of columns in the matrix from which this view has been created.
+    parent_columns: NonZeroUsize,
+
+    /// The row in the parent matrix in which the region starts.
+    row_offset: usize,
+
+    /// The column in the parent matrix in which the region starts.
+    column_offset: usize,
+
+    /// The number of rows the view has.
+    rows: NonZeroUsize,
+
+    /// The number of columns the view has.
+    columns: NonZeroUsize,
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    // region Initialization
+
+    /// Create a new view into the region of the parent `data` that starts in the given row and
+    /// column offsets and has the given number of rows and columns.
+    ///
+    /// The caller must ensure that the region lies within the parent matrix.
+    pub(super) fn new(
+        data: &'a mut [T],
+        parent_columns: NonZeroUsize,
+        row_offset: usize,
+        column_offset: usize,
+        rows: NonZeroUsize,
+        columns: NonZeroUsize,
+    ) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            data,
+            parent_columns,
+            row_offset,
+            column_offset,
+            rows,
+            columns,
+        }
+    }
+
+    // endregion
+
+    // region Getters
+
+    /// Get the number of columns in the view.
+    pub fn get_number_of_columns(&self) -> usize {
+        self.columns.get()
+    }
+
+    /// Get a mutable reference to the value in the given `row` and `column` of the view.
+    ///
+    /// If the `row` or `column` value is larger than the number of rows or columns in the view,
+    /// respectively, an [`Error::CellOutOfBounds`] will be returned.
+    ///
+    /// [`Error::CellOutOfBounds`]: ../enum.Error.html#variant.CellOutOfBounds
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Result<&mut T> {
+        if row >= self.get_number_of_rows() || column >= self.get_number_of_columns() {
+            return Err(Error::CellOutOfBounds);
+        }
+
+        // Translate the coordinates within the view to the index in the parent's data.
+        let index: usize =
+            self.parent_columns.get() * (self.row_offset + row) + self.column_offset + column;
+        Ok(&mut self.data[index])
+    }
+
+    /// Get the number of rows in the view.
+    pub fn get_number_of_rows(&self) -> usize {
+        self.rows.get()
+    }
+
+    // endregion
+
+    // region Setters
+
+    /// Set the value in the given `row` and `column` of the view to `value`.
+    ///
+    /// If the `row` or `column` value is larger than the number of rows or columns in the view,
+    /// respectively, an [`Error::CellOutOfBounds`] will be returned.
+    ///
+    /// [`Error::CellOutOfBounds`]: ../enum.Error.html#variant.CellOutOfBounds
+    pub fn set(&mut self, row: usize, column: usize, value: T) -> Result<()> {
+        *self.get_mut(row, column)? = value;
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    use super::*;
+
+    /// Test accessing an element outside the view.
+    #[test]
+    fn get_mut_out_of_bounds() {
+        let rows: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let mut matrix: Matrix<usize> = Matrix::new(rows, columns, 0).unwrap();
+        let mut view: MatrixViewMut<usize> = matrix.submatrix_mut(0..2, 0..2).unwrap();
+
+        assert!(
+            matches!(view.get_mut(2, 0), Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+        assert!(
+            matches!(view.set(0, 2, 1), Err(Error::CellOutOfBounds)),
+            "Expected error Error::CellOutOfBounds not satisfied."
+        );
+
+        // The matrix has not been changed.
+        assert_eq!(matrix.as_slice(), &[0; 9]);
+    }
+}
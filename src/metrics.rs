@@ -0,0 +1,203 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Classification metrics computed from confusion matrices.
+//!
+//! A confusion matrix for `n` classes is an `n x n` matrix in which the element at row `i` and
+//! column `j` is the number of samples of the actual class `i` that have been predicted as class
+//! `j`. All functions in this module require the confusion matrix to be square. Otherwise, an
+//! [`Error::ShapeMismatch`] will be returned.
+//!
+//! If the denominator of a metric is zero for some class (e.g. the precision of a class that has
+//! never been predicted), the metric is defined as `0.0` for this class.
+//!
+//! [`Error::ShapeMismatch`]: ../enum.Error.html#variant.ShapeMismatch
+
+use crate::matrix::Matrix;
+use crate::Result;
+
+/// Compute the F1 score, i.e. the harmonic mean of precision and recall, for each class of the
+/// given `confusion_matrix`.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::Matrix;
+/// use reural_network::metrics::f1_score;
+///
+/// let classes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let confusion_matrix: Matrix<usize> = Matrix::from_slice(classes, classes, &[4, 0, 4, 0])
+///     .unwrap();
+///
+/// let f1: Vec<f64> = f1_score(&confusion_matrix).unwrap();
+/// assert!((f1[0] - 2.0 / 3.0).abs() < 1e-10);
+/// assert_eq!(f1[1], 0.0);
+/// ```
+pub fn f1_score(confusion_matrix: &Matrix<usize>) -> Result<Vec<f64>> {
+    let precision: Vec<f64> = precision(confusion_matrix)?;
+    let recall: Vec<f64> = recall(confusion_matrix)?;
+
+    Ok(precision
+        .into_iter()
+        .zip(recall)
+        .map(|(precision, recall)| ratio(2.0 * precision * recall, precision + recall))
+        .collect())
+}
+
+/// Compute the macro-averaged F1 score of the given `confusion_matrix`, i.e. the mean of the F1
+/// scores of all classes.
+pub fn macro_f1_score(confusion_matrix: &Matrix<usize>) -> Result<f64> {
+    Ok(mean(&f1_score(confusion_matrix)?))
+}
+
+/// Compute the macro-averaged precision of the given `confusion_matrix`, i.e. the mean of the
+/// precisions of all classes.
+pub fn macro_precision(confusion_matrix: &Matrix<usize>) -> Result<f64> {
+    Ok(mean(&precision(confusion_matrix)?))
+}
+
+/// Compute the macro-averaged recall of the given `confusion_matrix`, i.e. the mean of the
+/// recalls of all classes.
+pub fn macro_recall(confusion_matrix: &Matrix<usize>) -> Result<f64> {
+    Ok(mean(&recall(confusion_matrix)?))
+}
+
+/// Compute the precision for each class of the given `confusion_matrix`, i.e. the fraction of the
+/// samples predicted as the class that actually belong to it.
+pub fn precision(confusion_matrix: &Matrix<usize>) -> Result<Vec<f64>> {
+    let classes: usize = confusion_matrix.get_number_of_rows();
+    confusion_matrix.ensure_dimensions(classes, classes)?;
+
+    let mut precision: Vec<f64> = Vec::with_capacity(classes);
+    for class in 0..classes {
+        let mut predicted: usize = 0;
+        for actual in 0..classes {
+            predicted += confusion_matrix.get(actual, class)?;
+        }
+
+        let correct: usize = confusion_matrix.get(class, class)?;
+        precision.push(ratio(correct as f64, predicted as f64));
+    }
+
+    Ok(precision)
+}
+
+/// Compute the recall for each class of the given `confusion_matrix`, i.e. the fraction of the
+/// samples actually belonging to the class that have been predicted as it.
+pub fn recall(confusion_matrix: &Matrix<usize>) -> Result<Vec<f64>> {
+    let classes: usize = confusion_matrix.get_number_of_rows();
+    confusion_matrix.ensure_dimensions(classes, classes)?;
+
+    let mut recall: Vec<f64> = Vec::with_capacity(classes);
+    for class in 0..classes {
+        let mut actual: usize = 0;
+        for predicted in 0..classes {
+            actual += confusion_matrix.get(class, predicted)?;
+        }
+
+        let correct: usize = confusion_matrix.get(class, class)?;
+        recall.push(ratio(correct as f64, actual as f64));
+    }
+
+    Ok(recall)
+}
+
+/// Compute the mean of the given non-empty `values`.
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Divide the `numerator` by the `denominator`, defining the result as `0.0` if the denominator is
+/// zero.
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        return 0.0;
+    }
+
+    numerator / denominator
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use approx::assert_relative_eq;
+
+    use crate::Error;
+
+    use super::*;
+
+    /// Get a confusion matrix with five true negatives, two false positives, one false negative,
+    /// and three true positives.
+    fn confusion_matrix() -> Matrix<usize> {
+        let classes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        Matrix::from_slice(classes, classes, &[5, 2, 1, 3]).unwrap()
+    }
+
+    /// Test computing the per-class metrics of a binary confusion matrix.
+    #[test]
+    fn per_class_metrics() {
+        let confusion_matrix: Matrix<usize> = confusion_matrix();
+
+        let precision: Vec<f64> = precision(&confusion_matrix).unwrap();
+        assert_relative_eq!(*precision, [5.0 / 6.0, 3.0 / 5.0]);
+
+        let recall: Vec<f64> = recall(&confusion_matrix).unwrap();
+        assert_relative_eq!(*recall, [5.0 / 7.0, 3.0 / 4.0]);
+
+        let f1: Vec<f64> = f1_score(&confusion_matrix).unwrap();
+        assert_relative_eq!(*f1, [10.0 / 13.0, 2.0 / 3.0]);
+    }
+
+    /// Test computing the macro-averaged metrics of a binary confusion matrix.
+    #[test]
+    fn macro_metrics() {
+        let confusion_matrix: Matrix<usize> = confusion_matrix();
+
+        let precision: f64 = macro_precision(&confusion_matrix).unwrap();
+        assert_relative_eq!(precision, (5.0 / 6.0 + 3.0 / 5.0) / 2.0);
+
+        let recall: f64 = macro_recall(&confusion_matrix).unwrap();
+        assert_relative_eq!(recall, (5.0 / 7.0 + 3.0 / 4.0) / 2.0);
+
+        let f1: f64 = macro_f1_score(&confusion_matrix).unwrap();
+        assert_relative_eq!(f1, (10.0 / 13.0 + 2.0 / 3.0) / 2.0);
+    }
+
+    /// Test computing the metrics for classes with zero denominators.
+    #[test]
+    fn metrics_zero_denominator() {
+        let classes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let confusion_matrix: Matrix<usize> =
+            Matrix::from_slice(classes, classes, &[3, 0, 0, 0]).unwrap();
+
+        assert_relative_eq!(*precision(&confusion_matrix).unwrap(), [1.0, 0.0]);
+        assert_relative_eq!(*recall(&confusion_matrix).unwrap(), [1.0, 0.0]);
+        assert_relative_eq!(*f1_score(&confusion_matrix).unwrap(), [1.0, 0.0]);
+    }
+
+    /// Test computing the metrics of a confusion matrix that is not square.
+    #[test]
+    fn metrics_not_square() {
+        let rows: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let columns: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let confusion_matrix: Matrix<usize> = Matrix::new(rows, columns, 1).unwrap();
+
+        let result: Result<Vec<f64>> = precision(&confusion_matrix);
+        assert!(
+            matches!(result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+
+        let result: Result<f64> = macro_recall(&confusion_matrix);
+        assert!(
+            matches!(result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+    }
+}
@@ -7,11 +7,35 @@
 
 //! Definition and implementation of the neural network.
 
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Read;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use rand::Rng;
+
+use crate::random::Normal;
+use crate::Activation;
 use crate::Error;
+use crate::InitStrategy;
 use crate::Layer;
 use crate::Matrix;
 use crate::Result;
 
+/// The magic string at the beginning of each file containing a saved neural network.
+const FILE_MAGIC: &[u8] = b"REURALNN";
+
+/// The version of the format of saved neural networks.
+const FILE_VERSION: u8 = 1;
+
+/// The maximum number of nodes per layer that will be drawn individually in the Graphviz export.
+/// Larger layers will be summarized in a single node.
+const DOT_MAX_NODES: usize = 16;
+
 // TODO: Improve documentation.
 /// A neural network.
 #[derive(Debug)]
@@ -21,6 +45,9 @@ pub struct NeuralNetwork {
     /// The order of the layers within the vector is the order in which the layers will be accessed
     /// by the neural network.
     layers: Vec<Layer>,
+
+    /// If `true`, the forward pass will fail if it encounters infinite or `NaN` values.
+    strict: bool,
 }
 
 impl NeuralNetwork {
@@ -40,46 +67,587 @@ impl NeuralNetwork {
             return Err(Error::EmptyNetwork);
         }
 
-        Ok(NeuralNetwork { layers })
+        Ok(NeuralNetwork {
+            layers,
+            strict: false,
+        })
     }
 
     // endregion
 
     // region Getters
 
+    /// Get the biases of all layers in the neural network as a single flat vector.
+    ///
+    /// The biases are concatenated in the order of the layers.
+    pub fn all_biases(&self) -> Vec<f64> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.get_bias().as_slice().iter().copied())
+            .collect()
+    }
+
+    /// Get the weights of all layers in the neural network as a single flat vector, e.g. for
+    /// plotting a histogram of the weights.
+    ///
+    /// The weights are concatenated in the order of the layers. Within each layer, the weights are
+    /// in row-major format.
+    pub fn all_weights(&self) -> Vec<f64> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.get_weights().as_slice().iter().copied())
+            .collect()
+    }
+
     /// Get a slice of all layers in the neural network.
     #[cfg(test)]
     pub(crate) fn get_layers(&self) -> &[Layer] {
         self.layers.as_slice()
     }
 
+    /// Get a mutable slice of all layers in the neural network.
+    pub(crate) fn get_layers_mut(&mut self) -> &mut [Layer] {
+        self.layers.as_mut_slice()
+    }
+
+    /// Determine if the neural network checks its forward pass for non-finite values.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Compute the L2 regularization penalty of the neural network, i.e. the sum of the squares of
+    /// all weights of all layers. The biases are not included.
+    pub fn l2_penalty(&self) -> f64 {
+        self.layers
+            .iter()
+            .map(|layer| layer.get_weights().sum_of_squares())
+            .sum()
+    }
+
+    // endregion
+
+    // region Setters
+
+    /// Insert a new layer with the given number of `output_nodes` at position `index` into the
+    /// neural network, e.g. to search for a better architecture. Like in the
+    /// [`NeuralNetworkBuilder`] by default, the layer will apply the [`Activation::Sigmoid`]
+    /// function and its weights and bias will be initialized with [`InitStrategy::Uniform01`],
+    /// using the given random number generator `rng`.
+    ///
+    /// The number of input nodes of the new layer is the number of output nodes of the layer
+    /// before it, or the number of input nodes of the neural network if `index` is `0`. An `index`
+    /// equal to the number of layers appends a new output layer.
+    ///
+    /// If there is a layer after the new one, its number of input nodes changes to `output_nodes`.
+    /// Therefore, its weights will be discarded and re-initialized according to its own
+    /// initialization strategy, using `rng` as well. Its activation function, bias, and number of
+    /// output nodes will not be changed. All other layers will not be changed.
+    ///
+    /// If `index` is greater than the number of layers, an [`Error::InvalidRange`] will be
+    /// returned. If a layer would be too large, an [`Error::DimensionsTooLarge`] will be returned.
+    /// In both cases, the neural network will not be changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::Activation;
+    /// use reural_network::NeuralNetwork;
+    /// use reural_network::NeuralNetworkBuilder;
+    ///
+    /// let input_nodes: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+    /// let output_nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let mut neural_network: NeuralNetwork = NeuralNetworkBuilder::new(input_nodes)
+    ///     .add_output_layer(output_nodes, Activation::Sigmoid)
+    ///     .unwrap();
+    ///
+    /// // Insert a hidden layer with five nodes in front of the output layer. The weights of the
+    /// // output layer are re-initialized to take five inputs.
+    /// let hidden_nodes: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+    /// let mut rng = rand::thread_rng();
+    /// assert!(neural_network.insert_layer(0, hidden_nodes, &mut rng).is_ok());
+    /// assert_eq!(neural_network.all_weights().len(), 3 * 5 + 5 * 2);
+    /// ```
+    ///
+    /// [`Activation::Sigmoid`]: ../activation/enum.Activation.html#variant.Sigmoid
+    /// [`Error::DimensionsTooLarge`]: ../enum.Error.html#variant.DimensionsTooLarge
+    /// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+    /// [`InitStrategy::Uniform01`]: ../enum.InitStrategy.html#variant.Uniform01
+    /// [`NeuralNetworkBuilder`]: struct.NeuralNetworkBuilder.html
+    pub fn insert_layer<R>(
+        &mut self,
+        index: usize,
+        output_nodes: NonZeroUsize,
+        rng: &mut R,
+    ) -> Result<()>
+    where
+        R: Rng + ?Sized,
+    {
+        if index > self.layers.len() {
+            return Err(Error::InvalidRange);
+        }
+
+        let input_nodes: usize = match index {
+            0 => self.layers[0].get_number_of_input_nodes(),
+            _ => self.layers[index - 1].get_number_of_output_nodes(),
+        };
+        let input_nodes: NonZeroUsize =
+            NonZeroUsize::new(input_nodes).expect("A layer always has at least one input node.");
+        let activation: Activation = Activation::default();
+        let strategy: InitStrategy = InitStrategy::default();
+        let layer: Layer =
+            Layer::new_with_rng(input_nodes, output_nodes, activation, strategy, rng)?;
+
+        // Adjust the following layer before inserting the new one so that the neural network is
+        // not changed if this fails.
+        if let Some(next_layer) = self.layers.get_mut(index) {
+            next_layer.reset_input_nodes(output_nodes, rng)?;
+        }
+        self.layers.insert(index, layer);
+
+        Ok(())
+    }
+
+    /// Add independent Gaussian noise `N(0, std_dev^2)` to each weight and bias element of all
+    /// layers in the neural network, using the given random number generator `rng`, e.g. to study
+    /// how robust the predictions are. With a `std_dev` of `0.0`, the parameters won't change.
+    ///
+    /// `std_dev` must be finite and not negative. Otherwise, an [`Error::InvalidRange`] will be
+    /// returned and the neural network will not be changed.
+    ///
+    /// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+    pub fn perturb_weights<R>(&mut self, std_dev: f64, rng: &mut R) -> Result<()>
+    where
+        R: Rng + ?Sized,
+    {
+        let noise: Normal = Normal::new(0.0, std_dev)?;
+        for layer in &mut self.layers {
+            layer.add_noise(&noise, rng);
+        }
+
+        Ok(())
+    }
+
+    /// Re-initialize the weights and biases of all layers in the neural network according to each
+    /// layer's initialization strategy, using the given random number generator `rng`, e.g. to
+    /// restart an experiment without rebuilding the neural network. The architecture of the neural
+    /// network will not be changed.
+    ///
+    /// If the new parameters of any layer cannot be created, the error will be returned and the
+    /// neural network will not be changed.
+    pub fn reset_weights<R>(&mut self, rng: &mut R) -> Result<()>
+    where
+        R: Rng + ?Sized,
+    {
+        // Reset a copy of the layers so that the neural network is not changed if this fails.
+        let mut layers: Vec<Layer> = self.layers.clone();
+        for layer in &mut layers {
+            layer.reset_parameters(rng)?;
+        }
+        self.layers = layers;
+
+        Ok(())
+    }
+
+    /// Multiply the weights and biases of all layers in the neural network by the given `factor`
+    /// in place, e.g. to test how sensitive the predictions are to the magnitude of the
+    /// parameters.
+    pub fn scale_all_weights(&mut self, factor: f64) {
+        for layer in &mut self.layers {
+            layer.scale_parameters(factor);
+        }
+    }
+
+    /// Set the range `(min, max)` into which all outputs of the layer at position `layer` will be
+    /// clamped after its activation function has been applied. If `clip` is `None`, the outputs
+    /// will not be clamped. This is the same as [`Layer::set_activation_clip`] for layers within
+    /// a neural network.
+    ///
+    /// If `layer` is not a valid position in the neural network, or if the bounds of the range
+    /// are `NaN` or `min` is greater than `max`, an [`Error::InvalidRange`] will be returned and
+    /// the neural network will not be changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    /// use reural_network::Activation;
+    /// use reural_network::NeuralNetwork;
+    /// use reural_network::NeuralNetworkBuilder;
+    ///
+    /// let nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let mut neural_network: NeuralNetwork = NeuralNetworkBuilder::new(nodes)
+    ///     .add_output_layer(nodes, Activation::Sigmoid)
+    ///     .unwrap();
+    ///
+    /// // Keep the outputs of the output layer away from the saturated ends of the sigmoid.
+    /// assert!(neural_network.set_activation_clip(0, Some((0.1, 0.9))).is_ok());
+    /// let input: Matrix<f64> = Matrix::new(nodes, one, 100.0).unwrap();
+    /// let output: Matrix<f64> = neural_network.predict(&input).unwrap();
+    /// assert!(output.as_slice().iter().all(|x| (0.1..=0.9).contains(x)));
+    /// ```
+    ///
+    /// [`Layer::set_activation_clip`]: struct.Layer.html#method.set_activation_clip
+    /// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+    pub fn set_activation_clip(&mut self, layer: usize, clip: Option<(f64, f64)>) -> Result<()> {
+        match self.layers.get_mut(layer) {
+            Some(layer) => layer.set_activation_clip(clip),
+            None => Err(Error::InvalidRange),
+        }
+    }
+
+    /// Enable or disable the strict mode of the neural network.
+    ///
+    /// In strict mode, the prediction will fail with an [`Error::NonFiniteValue`] if an infinite
+    /// or `NaN` value occurs anywhere in the forward pass (e.g. because of pathological weights),
+    /// instead of silently propagating it. Since these checks cost some time, the strict mode is
+    /// disabled by default.
+    ///
+    /// [`Error::NonFiniteValue`]: ../enum.Error.html#variant.NonFiniteValue
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     // endregion
 
     // region AI
 
+    /// Propagate the given inputs, one sample per column, through all layers and return the
+    /// output of the final layer. The inputs must have one row per input node of the neural
+    /// network.
+    fn forward(&self, inputs: &Matrix<f64>) -> Result<Matrix<f64>> {
+        // Let each layer compute its output, using the previous layer's output as its input.
+        // The initial input is of course the input to this method. The final layer's output is the
+        // output of the neural network.
+        let (first_layer, layers): (&Layer, &[Layer]) = self
+            .layers
+            .split_first()
+            .expect("A neural network always has at least one layer.");
+        layers.iter().try_fold(
+            first_layer.forward(inputs, self.strict)?,
+            |outputs, layer| layer.forward(&outputs, self.strict),
+        )
+    }
+
     /// Let the neural network predict an output for the given input.
     ///
     /// The input matrix must be an `i x 1` matrix where `i` is the number of input nodes of the
-    /// neural network. Otherwise, [`Error::DimensionMismatch`] will be returned.
+    /// neural network. Otherwise, [`Error::ShapeMismatch`] will be returned.
     ///
     /// The output matrix will be a `o x 1` matrix where `o` is the number of outputs of this layer.
     ///
+    /// If the neural network is in strict mode and a non-finite value occurs during the
+    /// prediction, [`Error::NonFiniteValue`] will be returned.
+    ///
+    /// [`Error::NonFiniteValue`]: ../enum.Error.html#variant.NonFiniteValue
+    /// [`Error::ShapeMismatch`]: ../enum.Error.html#variant.ShapeMismatch
+    pub fn predict(&self, input: &Matrix<f64>) -> Result<Matrix<f64>> {
+        // The input matrix must have one row per input node and only one column.
+        input.ensure_dimensions(self.layers[0].get_number_of_input_nodes(), 1)?;
+        self.forward(input)
+    }
+
+    /// Let the neural network predict the outputs for a batch of inputs at once, e.g. to evaluate
+    /// a whole data set without one call per sample.
+    ///
+    /// The input matrix must be an `i x n` matrix where `i` is the number of input nodes of the
+    /// neural network and each of the `n` columns is one sample. Otherwise, like in [`predict`], an
+    /// [`Error::ShapeMismatch`] will be returned. The output matrix will be a `o x n` matrix where
+    /// column `j` is the output for the input in column `j`.
+    ///
+    /// If the neural network is in strict mode and a non-finite value occurs during the
+    /// prediction, [`Error::NonFiniteValue`] will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    /// use reural_network::Activation;
+    /// use reural_network::NeuralNetwork;
+    /// use reural_network::NeuralNetworkBuilder;
+    ///
+    /// let input_nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let output_nodes: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let samples: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+    /// let neural_network: NeuralNetwork = NeuralNetworkBuilder::new(input_nodes)
+    ///     .add_output_layer(output_nodes, Activation::Sigmoid)
+    ///     .unwrap();
+    ///
+    /// let inputs: Matrix<f64> = Matrix::new(input_nodes, samples, 0.5).unwrap();
+    /// let outputs: Matrix<f64> = neural_network.predict_batch(&inputs).unwrap();
+    /// assert!(outputs.ensure_dimensions(1, 4).is_ok());
+    /// ```
+    ///
+    /// [`predict`]: #method.predict
+    /// [`Error::NonFiniteValue`]: ../enum.Error.html#variant.NonFiniteValue
+    /// [`Error::ShapeMismatch`]: ../enum.Error.html#variant.ShapeMismatch
+    pub fn predict_batch(&self, inputs: &Matrix<f64>) -> Result<Matrix<f64>> {
+        // The input matrix must have one row per input node, but may have any number of columns.
+        inputs.ensure_dimensions(
+            self.layers[0].get_number_of_input_nodes(),
+            inputs.get_number_of_columns(),
+        )?;
+
+        // Since each column is an independent sample, the layers can simply propagate all of them
+        // at once.
+        self.forward(inputs)
+    }
+
+    /// Let the neural network predict an output for the given input, then normalize the output so
+    /// that all its elements sum up to `1.0`.
+    ///
+    /// This is not a softmax: the output of the neural network is simply divided by the sum of its
+    /// elements. If the elements of the output sum up to `0.0` (e.g. because all of them are
+    /// `0.0`), an [`Error::DivisionByZero`] will be returned.
+    ///
+    /// The input matrix must be an `i x 1` matrix where `i` is the number of input nodes of the
+    /// neural network. Otherwise, [`Error::ShapeMismatch`] will be returned.
+    ///
+    /// If the neural network is in strict mode and a non-finite value occurs during the
+    /// prediction or the normalization, [`Error::NonFiniteValue`] will be returned.
+    ///
+    /// [`Error::DivisionByZero`]: ../enum.Error.html#variant.DivisionByZero
+    /// [`Error::NonFiniteValue`]: ../enum.Error.html#variant.NonFiniteValue
+    /// [`Error::ShapeMismatch`]: ../enum.Error.html#variant.ShapeMismatch
+    pub fn predict_proba(&self, input: &Matrix<f64>) -> Result<Matrix<f64>> {
+        let mut output: Matrix<f64> = self.predict(input)?;
+
+        let sum: f64 = output.as_slice().iter().sum();
+        if sum == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+
+        output /= sum;
+
+        if self.strict && !output.is_finite() {
+            return Err(Error::NonFiniteValue);
+        }
+
+        Ok(output)
+    }
+
+    /// Lazily let the neural network predict an output for each input of the given iterator, e.g.
+    /// to process a large stream of samples without keeping all of them in memory.
+    ///
+    /// Each item of the returned iterator is the result of calling [`predict`] with the
+    /// corresponding input. Errors for one input do not stop the iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use reural_network::matrix::Matrix;
+    /// use reural_network::Activation;
+    /// use reural_network::NeuralNetwork;
+    /// use reural_network::NeuralNetworkBuilder;
+    /// use reural_network::Result;
+    ///
+    /// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+    /// let input_nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+    /// let neural_network: NeuralNetwork = NeuralNetworkBuilder::new(input_nodes)
+    ///     .add_output_layer(one, Activation::Sigmoid)
+    ///     .unwrap();
+    ///
+    /// let inputs = (0..1000).map(|sample| Matrix::new(input_nodes, one, sample as f64).unwrap());
+    /// let outputs: Result<Vec<Matrix<f64>>> = neural_network.predict_stream(inputs).collect();
+    /// assert_eq!(outputs.unwrap().len(), 1000);
+    /// ```
+    ///
+    /// [`predict`]: #method.predict
+    pub fn predict_stream<'a, I>(
+        &'a self,
+        inputs: I,
+    ) -> impl Iterator<Item = Result<Matrix<f64>>> + 'a
+    where
+        I: Iterator<Item = Matrix<f64>> + 'a,
+    {
+        inputs.map(move |input| self.predict(&input))
+    }
+
+    // endregion
+
+    // region Serialization
+
+    /// Load a neural network that has been saved with [`save`] from the file at the given `path`.
+    ///
+    /// The layers will be restored with their exact dimensions, parameters, activation functions,
+    /// initialization strategies, and activation clip ranges. The loaded neural network will not be
+    /// in strict mode.
+    ///
+    /// If the number of input nodes of a layer does not match the number of output nodes of the
+    /// previous layer, or if a bias does not match its weights, an [`Error::DimensionMismatch`]
+    /// will be returned. If the file does not contain any layers, an [`Error::EmptyNetwork`] will
+    /// be returned. If the file is not a saved neural network, an [`Error::InvalidFormat`] will be
+    /// returned. Errors while reading the file (including data that ends before the dimensions
+    /// stated in the file) will be returned as an [`Error::Io`].
+    ///
+    /// [`save`]: #method.save
     /// [`Error::DimensionMismatch`]: ../enum.Error.html#variant.DimensionMismatch
-    pub fn predict(&self, input: Matrix<f64>) -> Result<Matrix<f64>> {
-        // The input matrix must have only one column.
-        if input.get_number_of_columns() != 1 {
-            return Err(Error::DimensionMismatch);
+    /// [`Error::EmptyNetwork`]: ../enum.Error.html#variant.EmptyNetwork
+    /// [`Error::InvalidFormat`]: ../enum.Error.html#variant.InvalidFormat
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub fn load<P>(path: P) -> Result<NeuralNetwork>
+    where
+        P: AsRef<Path>,
+    {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic: [u8; 8] = [0; 8];
+        reader.read_exact(&mut magic)?;
+        let mut version: [u8; 1] = [0; 1];
+        reader.read_exact(&mut version)?;
+        if magic != FILE_MAGIC || version[0] != FILE_VERSION {
+            return Err(Error::InvalidFormat);
         }
 
-        // Let each layer predict its output, using the previous layer's output as its input.
-        // The initial input is of course the input to this method. The final layer's output is the
-        // output of the neural network.
-        let mut output: Matrix<f64> = input;
+        let mut number_of_layers: [u8; 8] = [0; 8];
+        reader.read_exact(&mut number_of_layers)?;
+        let number_of_layers: usize = usize::try_from(u64::from_le_bytes(number_of_layers))
+            .map_err(|_| Error::InvalidFormat)?;
+
+        // Do not reserve memory for the layers upfront since the number of layers has not been
+        // validated yet.
+        let mut layers: Vec<Layer> = Vec::new();
+        for _ in 0..number_of_layers {
+            let layer: Layer = Layer::read_from(&mut reader)?;
+            if let Some(previous) = layers.last() {
+                if previous.get_weights().get_number_of_rows()
+                    != layer.get_weights().get_number_of_columns()
+                {
+                    return Err(Error::DimensionMismatch);
+                }
+            }
+
+            layers.push(layer);
+        }
+
+        NeuralNetwork::new(layers)
+    }
+
+    /// Save the neural network to a file at the given `path`, overwriting the file if it already
+    /// exists. The neural network can be restored with [`load`].
+    ///
+    /// The file contains the activation function, the initialization strategy, the activation clip
+    /// range, the weights, and the bias of each layer in a binary format. The weights and biases
+    /// are stored in the NumPy `.npy` format. Whether the neural network is in strict mode is not
+    /// saved.
+    ///
+    /// Errors while writing the file will be returned as an [`Error::Io`].
+    ///
+    /// [`load`]: #method.load
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(FILE_MAGIC)?;
+        writer.write_all(&[FILE_VERSION])?;
+        writer.write_all(&(self.layers.len() as u64).to_le_bytes())?;
         for layer in &self.layers {
-            output = layer.predict(output)?;
+            layer.write_to(&mut writer)?;
         }
 
-        Ok(output)
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    // endregion
+
+    // region Visualization
+
+    /// Get a diagram of the neural network in the DOT format of Graphviz.
+    ///
+    /// Each layer of nodes (starting with the input nodes) is drawn as a cluster containing one
+    /// node per neuron, and the nodes of consecutive layers are connected by edges labeled with
+    /// their weights. To keep the diagram readable, layers with more than 16 nodes are summarized
+    /// in a single node, and the weights between them and their neighboring layers are drawn as a
+    /// single edge labeled with the dimensions of the weight matrix.
+    ///
+    /// The diagram can be rendered with e.g. `dot -Tsvg network.dot -o network.svg`.
+    pub fn to_dot(&self) -> String {
+        // The number of nodes in each layer, including the input nodes.
+        let mut nodes: Vec<usize> = vec![self.layers[0].get_weights().get_number_of_columns()];
+        nodes.extend(
+            self.layers
+                .iter()
+                .map(|layer| layer.get_weights().get_number_of_rows()),
+        );
+        let summarized: Vec<bool> = nodes.iter().map(|&count| count > DOT_MAX_NODES).collect();
+
+        let mut dot: String = String::from("digraph neural_network {\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    node [shape=circle, label=\"\"];\n");
+
+        for (index, &count) in nodes.iter().enumerate() {
+            let label: String = match index {
+                0 => String::from("Input"),
+                _ if index == nodes.len() - 1 => String::from("Output"),
+                _ => format!("Hidden {}", index),
+            };
+
+            dot.push_str(&format!("    subgraph cluster_{} {{\n", index));
+            dot.push_str(&format!("        label=\"{}\";\n", label));
+            if summarized[index] {
+                dot.push_str(&format!(
+                    "        l{}_all [shape=box, label=\"{} nodes\"];\n",
+                    index, count
+                ));
+            } else {
+                for node in 0..count {
+                    dot.push_str(&format!("        l{}_{};\n", index, node));
+                }
+            }
+            dot.push_str("    }\n");
+        }
+
+        // The weights of each layer connect the nodes of the previous layer to its own nodes.
+        for (index, layer) in self.layers.iter().enumerate() {
+            let weights: &Matrix<f64> = layer.get_weights();
+            let (from, to): (usize, usize) = (index, index + 1);
+
+            if summarized[from] || summarized[to] {
+                let from_node: String = if summarized[from] {
+                    format!("l{}_all", from)
+                } else {
+                    format!("l{}_0", from)
+                };
+                let to_node: String = if summarized[to] {
+                    format!("l{}_all", to)
+                } else {
+                    format!("l{}_0", to)
+                };
+                dot.push_str(&format!(
+                    "    {} -> {} [label=\"{}x{} weights\"];\n",
+                    from_node,
+                    to_node,
+                    weights.get_number_of_rows(),
+                    weights.get_number_of_columns()
+                ));
+                continue;
+            }
+
+            for row in 0..weights.get_number_of_rows() {
+                for column in 0..weights.get_number_of_columns() {
+                    dot.push_str(&format!(
+                        "    l{}_{} -> l{}_{} [label=\"{:.3}\"];\n",
+                        from,
+                        column,
+                        to,
+                        row,
+                        weights[(row, column)]
+                    ));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
     }
 
     // endregion
@@ -90,7 +658,15 @@ mod tests {
 
     use super::*;
 
+    use std::env;
+    use std::fs;
     use std::num::NonZeroUsize;
+    use std::path::PathBuf;
+    use std::process;
+
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     use crate::Layer;
 
@@ -104,16 +680,23 @@ mod tests {
         let nodes_hidden_layer_2 = NonZeroUsize::new(2).unwrap();
         let output_nodes = NonZeroUsize::new(1).unwrap();
 
-        let mut layers: Vec<Layer> = Vec::with_capacity(3);
-        layers.push(Layer::new(input_nodes, nodes_hidden_layer_1).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_1, nodes_hidden_layer_2).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_2, output_nodes).unwrap());
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer_1, Activation::Sigmoid).unwrap(),
+            Layer::new(
+                nodes_hidden_layer_1,
+                nodes_hidden_layer_2,
+                Activation::Sigmoid,
+            )
+            .unwrap(),
+            Layer::new(nodes_hidden_layer_2, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
 
         let neural_network_result: Result<NeuralNetwork> = NeuralNetwork::new(layers);
         assert!(neural_network_result.is_ok());
 
         let neural_network: NeuralNetwork = neural_network_result.unwrap();
         assert_eq!(neural_network.layers.len(), 3);
+        assert!(!neural_network.is_strict());
     }
 
     /// Test creating a new neural network without layers.
@@ -132,6 +715,46 @@ mod tests {
 
     // region Getters
 
+    /// Test getting the biases of all layers.
+    #[test]
+    fn all_biases() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::Sigmoid).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let mut expected_biases: Vec<f64> = layers[0].get_bias().as_slice().to_vec();
+        expected_biases.extend_from_slice(layers[1].get_bias().as_slice());
+
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let biases: Vec<f64> = neural_network.all_biases();
+        assert_eq!(biases.len(), 5 + 2);
+        assert_eq!(biases, expected_biases);
+    }
+
+    /// Test getting the weights of all layers.
+    #[test]
+    fn all_weights() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::Sigmoid).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let mut expected_weights: Vec<f64> = layers[0].get_weights().as_slice().to_vec();
+        expected_weights.extend_from_slice(layers[1].get_weights().as_slice());
+
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let weights: Vec<f64> = neural_network.all_weights();
+        assert_eq!(weights.len(), 3 * 5 + 5 * 2);
+        assert_eq!(weights, expected_weights);
+    }
+
     /// Test getting all layers.
     #[test]
     fn get_layers() {
@@ -140,10 +763,16 @@ mod tests {
         let nodes_hidden_layer_2 = NonZeroUsize::new(2).unwrap();
         let output_nodes = NonZeroUsize::new(1).unwrap();
 
-        let mut layers: Vec<Layer> = Vec::with_capacity(3);
-        layers.push(Layer::new(input_nodes, nodes_hidden_layer_1).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_1, nodes_hidden_layer_2).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_2, output_nodes).unwrap());
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer_1, Activation::Sigmoid).unwrap(),
+            Layer::new(
+                nodes_hidden_layer_1,
+                nodes_hidden_layer_2,
+                Activation::Sigmoid,
+            )
+            .unwrap(),
+            Layer::new(nodes_hidden_layer_2, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
 
         let expected_layers: Vec<Layer> = layers.clone();
 
@@ -154,10 +783,418 @@ mod tests {
         assert_eq!(neural_network.get_layers(), expected_layers.as_slice());
     }
 
+    /// Test computing the L2 penalty from the weights of all layers.
+    #[test]
+    fn l2_penalty() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::Sigmoid).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        // The penalty sums up each layer separately, which may round differently.
+        let expected: f64 = neural_network.all_weights().iter().map(|w| w * w).sum();
+        assert_relative_eq!(neural_network.l2_penalty(), expected, epsilon = 1e-12);
+    }
+
+    // endregion
+
+    // region Setters
+
+    /// Test inserting layers at the beginning, in the middle, and at the end of a neural network.
+    #[test]
+    fn insert_layer() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> =
+            vec![Layer::new(input_nodes, output_nodes, Activation::ReLU).unwrap()];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let bias: Matrix<f64> = neural_network.get_layers()[0].get_bias().clone();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let nodes_1 = NonZeroUsize::new(5).unwrap();
+        let nodes_2 = NonZeroUsize::new(4).unwrap();
+        let nodes_3 = NonZeroUsize::new(6).unwrap();
+        assert!(neural_network.insert_layer(0, nodes_1, &mut rng).is_ok());
+        assert!(neural_network.insert_layer(1, nodes_2, &mut rng).is_ok());
+        assert!(neural_network.insert_layer(3, nodes_3, &mut rng).is_ok());
+
+        // The layers are now `3 -> 5 -> 4 -> 2 -> 6`.
+        let layers: &[Layer] = neural_network.get_layers();
+        assert_eq!(layers.len(), 4);
+        assert!(layers[0].get_weights().ensure_dimensions(5, 3).is_ok());
+        assert!(layers[1].get_weights().ensure_dimensions(4, 5).is_ok());
+        assert!(layers[2].get_weights().ensure_dimensions(2, 4).is_ok());
+        assert!(layers[3].get_weights().ensure_dimensions(6, 2).is_ok());
+        for window in layers.windows(2) {
+            assert_eq!(
+                window[0].get_number_of_output_nodes(),
+                window[1].get_number_of_input_nodes()
+            );
+        }
+
+        // The original layer keeps its activation function and bias.
+        assert_eq!(layers[2].get_activation(), Activation::ReLU);
+        assert_eq!(layers[2].get_bias(), &bias);
+
+        // The new layers use the same defaults as the builder.
+        for index in &[0, 1, 3] {
+            assert_eq!(layers[*index].get_activation(), Activation::Sigmoid);
+            assert_eq!(layers[*index].get_init_strategy(), InitStrategy::Uniform01);
+        }
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = neural_network.predict(&input).unwrap();
+        assert!(prediction.ensure_dimensions(6, 1).is_ok());
+    }
+
+    /// Test that inserting a layer re-initializes the weights of the following layer with its own
+    /// strategy, but does not change any other layer.
+    #[test]
+    fn insert_layer_reinitializes_next_layer() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let strategy = InitStrategy::HeNormal;
+        let layers: Vec<Layer> = vec![
+            Layer::new_with_init(input_nodes, hidden_nodes, Activation::ReLU, strategy).unwrap(),
+            Layer::new_with_init(hidden_nodes, output_nodes, Activation::ReLU, strategy).unwrap(),
+        ];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers.clone()).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let new_nodes = NonZeroUsize::new(5).unwrap();
+        assert!(neural_network.insert_layer(1, new_nodes, &mut rng).is_ok());
+
+        // The layer before the new one is unchanged.
+        let new_layers: &[Layer] = neural_network.get_layers();
+        assert_eq!(new_layers[0], layers[0]);
+
+        // The layer after the new one has new He-initialized weights for five inputs, but keeps
+        // everything else.
+        let next_layer: &Layer = &new_layers[2];
+        assert!(next_layer.get_weights().ensure_dimensions(2, 5).is_ok());
+        assert!(next_layer
+            .get_weights()
+            .as_slice()
+            .iter()
+            .any(|weight| *weight < 0.0));
+        assert_eq!(next_layer.get_bias(), layers[1].get_bias());
+        assert_eq!(next_layer.get_activation(), Activation::ReLU);
+        assert_eq!(next_layer.get_init_strategy(), strategy);
+
+        // Appending a layer does not re-initialize any existing layer.
+        let before: Vec<Layer> = neural_network.get_layers().to_vec();
+        assert!(neural_network.insert_layer(3, new_nodes, &mut rng).is_ok());
+        assert_eq!(&neural_network.get_layers()[..3], &before[..]);
+    }
+
+    /// Test inserting a layer at a position after the end of the neural network.
+    #[test]
+    fn insert_layer_out_of_range() {
+        let nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![Layer::new(nodes, nodes, Activation::Sigmoid).unwrap()];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let result: Result<()> = neural_network.insert_layer(2, nodes, &mut rng);
+        assert!(
+            matches!(result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+        assert_eq!(neural_network.get_layers().len(), 1);
+
+        // A layer that is too large does not change the neural network either.
+        let too_large = NonZeroUsize::new(usize::MAX).unwrap();
+        let result: Result<()> = neural_network.insert_layer(0, too_large, &mut rng);
+        assert!(
+            matches!(result, Err(Error::DimensionsTooLarge)),
+            "Expected error Error::DimensionsTooLarge not satisfied."
+        );
+        assert_eq!(neural_network.get_layers().len(), 1);
+        assert_eq!(
+            neural_network.get_layers()[0].get_number_of_input_nodes(),
+            2
+        );
+    }
+
+    /// Test adding noise to the weights and biases of all layers.
+    #[test]
+    fn perturb_weights() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> =
+            vec![Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap()];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let weights: Vec<f64> = neural_network.all_weights();
+        let biases: Vec<f64> = neural_network.all_biases();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        // Without any noise, the parameters do not change.
+        assert!(neural_network.perturb_weights(0.0, &mut rng).is_ok());
+        assert_eq!(neural_network.all_weights(), weights);
+        assert_eq!(neural_network.all_biases(), biases);
+
+        // With noise, each parameter changes.
+        assert!(neural_network.perturb_weights(0.1, &mut rng).is_ok());
+        for (perturbed, original) in neural_network.all_weights().iter().zip(&weights) {
+            assert_ne!(perturbed, original);
+        }
+        for (perturbed, original) in neural_network.all_biases().iter().zip(&biases) {
+            assert_ne!(perturbed, original);
+        }
+    }
+
+    /// Test re-initializing the weights and biases of all layers.
+    #[test]
+    fn reset_weights() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::Sigmoid).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = neural_network.predict(&input).unwrap();
+        let weights: Vec<f64> = neural_network.all_weights();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        assert!(neural_network.reset_weights(&mut rng).is_ok());
+        assert_ne!(neural_network.all_weights(), weights);
+        assert_ne!(neural_network.predict(&input).unwrap(), prediction);
+
+        let layers: &[Layer] = neural_network.get_layers();
+        assert_eq!(layers.len(), 2);
+        assert!(layers[0].get_weights().ensure_dimensions(4, 3).is_ok());
+        assert!(layers[1].get_weights().ensure_dimensions(2, 4).is_ok());
+        assert!(layers[1].get_bias().ensure_dimensions(2, 1).is_ok());
+        for weight in neural_network.all_weights() {
+            assert!((0.0..=1.0).contains(&weight));
+        }
+    }
+
+    /// Test that re-initializing the layers keeps their initialization strategy.
+    #[test]
+    fn reset_weights_keeps_strategy() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layer: Layer = Layer::new_with_init(
+            input_nodes,
+            output_nodes,
+            Activation::ReLU,
+            InitStrategy::HeNormal,
+        )
+        .unwrap();
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(vec![layer]).unwrap();
+        let weights: Vec<f64> = neural_network.all_weights();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        assert!(neural_network.reset_weights(&mut rng).is_ok());
+        assert_ne!(neural_network.all_weights(), weights);
+        assert_eq!(neural_network.all_biases(), vec![0.0, 0.0]);
+
+        let layer: &Layer = &neural_network.get_layers()[0];
+        assert_eq!(layer.get_init_strategy(), InitStrategy::HeNormal);
+        assert!(layer
+            .get_weights()
+            .as_slice()
+            .iter()
+            .any(|weight| *weight < 0.0));
+    }
+
+    /// Test adding noise with an invalid standard deviation.
+    #[test]
+    fn perturb_weights_invalid_std_dev() {
+        let nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![Layer::new(nodes, nodes, Activation::Sigmoid).unwrap()];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+        let weights: Vec<f64> = neural_network.all_weights();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let result: Result<()> = neural_network.perturb_weights(-0.1, &mut rng);
+        assert!(
+            matches!(result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+        assert_eq!(neural_network.all_weights(), weights);
+    }
+
+    /// Test scaling the weights and biases of all layers to zero.
+    #[test]
+    fn scale_all_weights() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::Sigmoid).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        neural_network.scale_all_weights(0.0);
+        assert!(neural_network
+            .all_weights()
+            .iter()
+            .all(|weight| *weight == 0.0));
+        assert!(neural_network.all_biases().iter().all(|bias| *bias == 0.0));
+
+        // With all parameters being zero, each output is `sigmoid(0) = 0.5`.
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = neural_network.predict(&input).unwrap();
+        assert_relative_eq!(*prediction.as_slice(), [0.5, 0.5]);
+    }
+
+    /// Test that the prediction of a neural network is clipped once a clip range has been set.
+    #[test]
+    fn set_activation_clip() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let weights: Matrix<f64> = Matrix::from_slice(one, one, &[1.0]).unwrap();
+        let bias: Matrix<f64> = Matrix::from_slice(one, one, &[0.0]).unwrap();
+        let layer: Layer = Layer::from_parameters(weights, bias).unwrap();
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(vec![layer]).unwrap();
+        let input: Matrix<f64> = Matrix::from_slice(one, one, &[5.0]).unwrap();
+        assert!(neural_network.predict(&input).unwrap().as_slice()[0] > 0.99);
+
+        assert!(neural_network
+            .set_activation_clip(0, Some((0.0, 0.3)))
+            .is_ok());
+        assert_eq!(neural_network.predict(&input).unwrap().as_slice(), &[0.3]);
+
+        // An invalid layer or range does not change the neural network.
+        let result: Result<()> = neural_network.set_activation_clip(1, None);
+        assert!(
+            matches!(result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+        let result: Result<()> = neural_network.set_activation_clip(0, Some((0.5, 0.1)));
+        assert!(
+            matches!(result, Err(Error::InvalidRange)),
+            "Expected error Error::InvalidRange not satisfied."
+        );
+        assert_eq!(neural_network.predict(&input).unwrap().as_slice(), &[0.3]);
+
+        assert!(neural_network.set_activation_clip(0, None).is_ok());
+        assert!(neural_network.predict(&input).unwrap().as_slice()[0] > 0.99);
+    }
+
+    /// Test enabling and disabling the strict mode.
+    #[test]
+    fn set_strict() {
+        let nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![Layer::new(nodes, nodes, Activation::Sigmoid).unwrap()];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        neural_network.set_strict(true);
+        assert!(neural_network.is_strict());
+
+        neural_network.set_strict(false);
+        assert!(!neural_network.is_strict());
+    }
+
     // endregion
 
     // region AI
 
+    /// Test that predicting a batch of inputs is the same as predicting each input separately.
+    #[test]
+    fn predict_batch() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let two = NonZeroUsize::new(2).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(3).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::ReLU).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Softmax).unwrap(),
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction: Matrix<f64> = neural_network.predict(&input).unwrap();
+
+        // Two identical columns.
+        let inputs: Matrix<f64> =
+            Matrix::from_slice(input_nodes, two, &[1.0, 1.0, 1.1, 1.1, 1.2, 1.2]).unwrap();
+        let predictions_result: Result<Matrix<f64>> = neural_network.predict_batch(&inputs);
+        assert!(predictions_result.is_ok());
+
+        let predictions: Matrix<f64> = predictions_result.unwrap();
+        assert!(predictions.ensure_dimensions(3, 2).is_ok());
+        for column in 0..2 {
+            for row in 0..3 {
+                assert_relative_eq!(
+                    predictions.get(row, column).unwrap(),
+                    prediction.get(row, 0).unwrap()
+                );
+            }
+        }
+    }
+
+    /// Test predicting a batch of inputs with the wrong number of rows.
+    #[test]
+    fn predict_batch_wrong_number_of_input_rows() {
+        let nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![Layer::new(nodes, nodes, Activation::Sigmoid).unwrap()];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let inputs: Matrix<f64> = Matrix::new(NonZeroUsize::new(3).unwrap(), nodes, 1.0).unwrap();
+        let predictions_result: Result<Matrix<f64>> = neural_network.predict_batch(&inputs);
+        assert!(
+            matches!(
+                predictions_result,
+                Err(Error::ShapeMismatch {
+                    expected: (2, 2),
+                    actual: (3, 2)
+                })
+            ),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+    }
+
+    /// Test that predicting a stream of inputs is the same as predicting each input separately.
+    #[test]
+    fn predict_stream() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::ReLU).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let inputs: Vec<Matrix<f64>> = vec![
+            Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap(),
+            Matrix::from_slice(input_nodes, one, &[-0.5, 0.0, 0.5]).unwrap(),
+            Matrix::new(input_nodes, output_nodes, 1.0).unwrap(),
+        ];
+
+        let stream: Vec<Result<Matrix<f64>>> = neural_network
+            .predict_stream(inputs.clone().into_iter())
+            .collect();
+        assert_eq!(stream.len(), 3);
+        for (streamed, input) in stream.iter().zip(&inputs) {
+            match (streamed, neural_network.predict(input)) {
+                (Ok(streamed), Ok(expected)) => assert_eq!(streamed, &expected),
+                (Err(Error::ShapeMismatch { .. }), Err(Error::ShapeMismatch { .. })) => {}
+                _ => panic!("Streamed prediction differs from the individual prediction."),
+            }
+        }
+
+        // The last input has too many columns.
+        assert!(stream[2].is_err());
+    }
+
     /// Test predicting an output of a neural network for valid input data.
     #[test]
     fn predict_valid_input() {
@@ -167,15 +1204,21 @@ mod tests {
         let nodes_hidden_layer_2 = NonZeroUsize::new(4).unwrap();
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
-        let mut layers: Vec<Layer> = Vec::with_capacity(3);
-        layers.push(Layer::new(input_nodes, nodes_hidden_layer_1).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_1, nodes_hidden_layer_2).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_2, output_nodes).unwrap());
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer_1, Activation::Sigmoid).unwrap(),
+            Layer::new(
+                nodes_hidden_layer_1,
+                nodes_hidden_layer_2,
+                Activation::Sigmoid,
+            )
+            .unwrap(),
+            Layer::new(nodes_hidden_layer_2, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
 
         let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
 
         let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
-        let prediction_result: Result<Matrix<f64>> = neural_network.predict(input);
+        let prediction_result: Result<Matrix<f64>> = neural_network.predict(&input);
         assert!(prediction_result.is_ok());
 
         let prediction: Matrix<f64> = prediction_result.unwrap();
@@ -187,6 +1230,109 @@ mod tests {
         }
     }
 
+    /// Test that the same input can be passed to several neural networks without cloning it.
+    #[test]
+    fn predict_shared_input() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let first: NeuralNetwork = NeuralNetwork::new(vec![Layer::new(
+            input_nodes,
+            output_nodes,
+            Activation::Sigmoid,
+        )
+        .unwrap()])
+        .unwrap();
+        let second: NeuralNetwork = NeuralNetwork::new(vec![Layer::new(
+            input_nodes,
+            output_nodes,
+            Activation::Sigmoid,
+        )
+        .unwrap()])
+        .unwrap();
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let first_prediction: Matrix<f64> = first.predict(&input).unwrap();
+        let second_prediction: Matrix<f64> = second.predict(&input).unwrap();
+
+        assert_eq!(first_prediction, first.predict(&input).unwrap());
+        assert_eq!(second_prediction.get_number_of_rows(), output_nodes.get());
+        assert_eq!(input.as_slice(), [1.0, 1.1, 1.2]);
+    }
+
+    /// Test predicting a normalized output of a neural network.
+    #[test]
+    fn predict_proba_valid_input() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let nodes_hidden_layer = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(4).unwrap();
+
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer, Activation::Sigmoid).unwrap(),
+            Layer::new(nodes_hidden_layer, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[1.0, 1.1, 1.2]).unwrap();
+        let prediction_result: Result<Matrix<f64>> = neural_network.predict_proba(&input);
+        assert!(prediction_result.is_ok());
+
+        let prediction: Matrix<f64> = prediction_result.unwrap();
+        assert_eq!(prediction.get_number_of_rows(), output_nodes.get());
+        assert_eq!(prediction.get_number_of_columns(), 1);
+        assert_relative_eq!(prediction.as_slice().iter().sum::<f64>(), 1.0);
+    }
+
+    /// Test predicting a normalized output of a neural network if all outputs are zero.
+    #[test]
+    fn predict_proba_zero_output() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layers: Vec<Layer> =
+            vec![Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap()];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        // The weights are non-negative, so such an input will let the sigmoid function return zero.
+        let input: Matrix<f64> = Matrix::new(input_nodes, one, -1e300).unwrap();
+        let prediction_result: Result<Matrix<f64>> = neural_network.predict_proba(&input);
+
+        assert!(
+            matches!(prediction_result, Err(Error::DivisionByZero)),
+            "Expected error Error::DivisionByZero not satisfied."
+        );
+    }
+
+    /// Test predicting an output of a neural network in strict mode if a non-finite value occurs.
+    #[test]
+    fn predict_strict_non_finite() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let nodes_hidden_layer = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer, Activation::Sigmoid).unwrap(),
+            Layer::new(nodes_hidden_layer, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let mut neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        // The sigmoid function maps the infinite value to a finite one if not in strict mode.
+        let input: Matrix<f64> =
+            Matrix::from_slice(input_nodes, one, &[1.0, f64::INFINITY, 1.2]).unwrap();
+        assert!(neural_network.predict(&input).is_ok());
+
+        neural_network.set_strict(true);
+        let prediction_result: Result<Matrix<f64>> = neural_network.predict(&input);
+        assert!(
+            matches!(prediction_result, Err(Error::NonFiniteValue)),
+            "Expected error Error::NonFiniteValue not satisfied."
+        );
+    }
+
     /// Test predicting an output of a neural network if the input matrix has too many columns.
     #[test]
     fn predict_too_many_input_columns() {
@@ -195,19 +1341,25 @@ mod tests {
         let nodes_hidden_layer_2 = NonZeroUsize::new(4).unwrap();
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
-        let mut layers: Vec<Layer> = Vec::with_capacity(3);
-        layers.push(Layer::new(input_nodes, nodes_hidden_layer_1).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_1, nodes_hidden_layer_2).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_2, output_nodes).unwrap());
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer_1, Activation::Sigmoid).unwrap(),
+            Layer::new(
+                nodes_hidden_layer_1,
+                nodes_hidden_layer_2,
+                Activation::Sigmoid,
+            )
+            .unwrap(),
+            Layer::new(nodes_hidden_layer_2, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
 
         let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
 
         let input: Matrix<f64> = Matrix::new(input_nodes, output_nodes, 1.0).unwrap();
-        let prediction_result: Result<Matrix<f64>> = neural_network.predict(input);
+        let prediction_result: Result<Matrix<f64>> = neural_network.predict(&input);
 
         assert!(
-            matches!(prediction_result, Err(Error::DimensionMismatch)),
-            "Expected error Error::DimensionMismatch not satisfied."
+            matches!(prediction_result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
         );
     }
 
@@ -221,21 +1373,245 @@ mod tests {
         let nodes_hidden_layer_2 = NonZeroUsize::new(4).unwrap();
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
-        let mut layers: Vec<Layer> = Vec::with_capacity(3);
-        layers.push(Layer::new(input_nodes, nodes_hidden_layer_1).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_1, nodes_hidden_layer_2).unwrap());
-        layers.push(Layer::new(nodes_hidden_layer_2, output_nodes).unwrap());
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, nodes_hidden_layer_1, Activation::Sigmoid).unwrap(),
+            Layer::new(
+                nodes_hidden_layer_1,
+                nodes_hidden_layer_2,
+                Activation::Sigmoid,
+            )
+            .unwrap(),
+            Layer::new(nodes_hidden_layer_2, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
 
         let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
 
         let input: Matrix<f64> = Matrix::new(output_nodes, one, 1.0).unwrap();
-        let prediction_result: Result<Matrix<f64>> = neural_network.predict(input);
+        let prediction_result: Result<Matrix<f64>> = neural_network.predict(&input);
 
         assert!(
-            matches!(prediction_result, Err(Error::DimensionMismatch)),
+            matches!(prediction_result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+    }
+
+    // endregion
+
+    // region Serialization
+
+    /// Get a path for a temporary file with the given `name` that is unique to this process.
+    fn temporary_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("reural_network_{}_{}.nn", name, process::id()))
+    }
+
+    /// Test that saving and loading a neural network reproduces its predictions.
+    #[test]
+    fn save_and_load() {
+        let one = NonZeroUsize::new(1).unwrap();
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(5).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let mut output_layer = Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap();
+        output_layer.set_activation_clip(Some((0.1, 0.9))).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::ReLU).unwrap(),
+            output_layer,
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let path: PathBuf = temporary_path("save_and_load");
+        assert!(neural_network.save(&path).is_ok());
+        let loaded_result: Result<NeuralNetwork> = NeuralNetwork::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(loaded_result.is_ok());
+        let loaded: NeuralNetwork = loaded_result.unwrap();
+        assert_eq!(loaded.layers, neural_network.layers);
+
+        let input: Matrix<f64> = Matrix::from_slice(input_nodes, one, &[0.5, -1.0, 2.0]).unwrap();
+        assert_eq!(
+            loaded.predict(&input).unwrap(),
+            neural_network.predict(&input).unwrap()
+        );
+    }
+
+    /// Test that saving and loading a neural network keeps the initialization strategy of each
+    /// layer, so that re-initializing the loaded neural network uses the same distributions.
+    #[test]
+    fn save_and_load_init_strategy() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new_with_init(
+                input_nodes,
+                hidden_nodes,
+                Activation::ReLU,
+                InitStrategy::HeNormal,
+            )
+            .unwrap(),
+            Layer::new_with_init(
+                hidden_nodes,
+                output_nodes,
+                Activation::Tanh,
+                InitStrategy::XavierUniform,
+            )
+            .unwrap(),
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let path: PathBuf = temporary_path("save_and_load_init_strategy");
+        assert!(neural_network.save(&path).is_ok());
+        let loaded_result: Result<NeuralNetwork> = NeuralNetwork::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        let mut loaded: NeuralNetwork = loaded_result.unwrap();
+        let strategies: Vec<InitStrategy> = loaded
+            .get_layers()
+            .iter()
+            .map(Layer::get_init_strategy)
+            .collect();
+        assert_eq!(
+            strategies,
+            vec![InitStrategy::HeNormal, InitStrategy::XavierUniform]
+        );
+
+        // Both strategies initialize the biases with zeros.
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        assert!(loaded.reset_weights(&mut rng).is_ok());
+        assert!(loaded.all_biases().iter().all(|bias| *bias == 0.0));
+    }
+
+    /// Test loading a neural network whose consecutive layers do not fit together.
+    #[test]
+    fn load_dimension_mismatch() {
+        let two = NonZeroUsize::new(2).unwrap();
+        let three = NonZeroUsize::new(3).unwrap();
+
+        // The second layer expects three inputs, but the first one only has two outputs.
+        let path: PathBuf = temporary_path("load_dimension_mismatch");
+        let mut file: File = File::create(&path).unwrap();
+        file.write_all(FILE_MAGIC).unwrap();
+        file.write_all(&[FILE_VERSION]).unwrap();
+        file.write_all(&2_u64.to_le_bytes()).unwrap();
+        Layer::new(two, two, Activation::Sigmoid)
+            .unwrap()
+            .write_to(&mut file)
+            .unwrap();
+        Layer::new(three, two, Activation::Sigmoid)
+            .unwrap()
+            .write_to(&mut file)
+            .unwrap();
+        drop(file);
+
+        let loaded_result: Result<NeuralNetwork> = NeuralNetwork::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(
+            matches!(loaded_result, Err(Error::DimensionMismatch)),
             "Expected error Error::DimensionMismatch not satisfied."
         );
     }
 
+    /// Test loading a file whose weights claim a huge shape, but whose data is short.
+    #[test]
+    fn load_oversized_weights() {
+        let header: &str =
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (268435456, 268435456), }\n";
+
+        let path: PathBuf = temporary_path("load_oversized_weights");
+        let mut file: File = File::create(&path).unwrap();
+        file.write_all(FILE_MAGIC).unwrap();
+        file.write_all(&[FILE_VERSION]).unwrap();
+        file.write_all(&1_u64.to_le_bytes()).unwrap();
+
+        // The sigmoid function with the default initialization strategy and without an activation
+        // clip range, followed by the weights.
+        let activation: u8 = Activation::Sigmoid.to_id();
+        let init_strategy: u8 = InitStrategy::default().to_id();
+        file.write_all(&[activation, init_strategy, 0]).unwrap();
+        file.write_all(b"\x93NUMPY\x01\x00").unwrap();
+        file.write_all(&(header.len() as u16).to_le_bytes())
+            .unwrap();
+        file.write_all(header.as_bytes()).unwrap();
+        file.write_all(&[0; 24]).unwrap();
+        drop(file);
+
+        let loaded_result: Result<NeuralNetwork> = NeuralNetwork::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(
+            matches!(loaded_result, Err(Error::Io(_))),
+            "Expected error Error::Io not satisfied."
+        );
+    }
+
+    /// Test loading a file that does not contain a neural network.
+    #[test]
+    fn load_invalid_format() {
+        let path: PathBuf = temporary_path("load_invalid_format");
+        fs::write(&path, b"NOT A NEURAL NETWORK").unwrap();
+
+        let loaded_result: Result<NeuralNetwork> = NeuralNetwork::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(
+            matches!(loaded_result, Err(Error::InvalidFormat)),
+            "Expected error Error::InvalidFormat not satisfied."
+        );
+
+        let loaded_result: Result<NeuralNetwork> = NeuralNetwork::load(&path);
+        assert!(
+            matches!(loaded_result, Err(Error::Io(_))),
+            "Expected error Error::Io not satisfied."
+        );
+    }
+
+    // endregion
+
+    // region Visualization
+
+    /// Test exporting a small neural network to the DOT format.
+    #[test]
+    fn to_dot_small_network() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> = vec![
+            Layer::new(input_nodes, hidden_nodes, Activation::Sigmoid).unwrap(),
+            Layer::new(hidden_nodes, output_nodes, Activation::Sigmoid).unwrap(),
+        ];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let dot: String = neural_network.to_dot();
+        assert!(dot.starts_with("digraph neural_network {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+
+        // One cluster for the input nodes and one for each layer.
+        assert_eq!(dot.matches("subgraph cluster_").count(), 3);
+        assert!(dot.contains("label=\"Input\""));
+        assert!(dot.contains("label=\"Hidden 1\""));
+        assert!(dot.contains("label=\"Output\""));
+
+        // Each weight is drawn as an edge.
+        assert_eq!(dot.matches(" -> ").count(), 3 * 4 + 4 * 2);
+        assert!(dot.contains("    l0_2 -> l1_3 [label=\""));
+    }
+
+    /// Test exporting a neural network with a large layer to the DOT format.
+    #[test]
+    fn to_dot_large_layer() {
+        let input_nodes = NonZeroUsize::new(100).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+        let layers: Vec<Layer> =
+            vec![Layer::new(input_nodes, output_nodes, Activation::Sigmoid).unwrap()];
+        let neural_network: NeuralNetwork = NeuralNetwork::new(layers).unwrap();
+
+        let dot: String = neural_network.to_dot();
+        assert_eq!(dot.matches("subgraph cluster_").count(), 2);
+        assert!(dot.contains("l0_all [shape=box, label=\"100 nodes\"];"));
+        assert_eq!(dot.matches(" -> ").count(), 1);
+        assert!(dot.contains("l0_all -> l1_0 [label=\"2x100 weights\"];"));
+    }
+
     // endregion
 }
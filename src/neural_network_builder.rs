@@ -9,6 +9,12 @@
 
 use std::num::NonZeroUsize;
 
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::random;
+use crate::Activation;
+use crate::InitStrategy;
 use crate::Layer;
 use crate::NeuralNetwork;
 use crate::Result;
@@ -19,8 +25,15 @@ pub struct NeuralNetworkBuilder {
     /// The number of input nodes of the neural network that will be built.
     input_nodes: NonZeroUsize,
 
-    /// For each hidden layer in the neural network, the number of its input nodes.
-    hidden_layer_nodes: Vec<NonZeroUsize>,
+    /// For each hidden layer in the neural network, the number of its input nodes and its
+    /// activation function.
+    hidden_layers: Vec<(NonZeroUsize, Activation)>,
+
+    /// The strategy for initializing the parameters of all layers.
+    init_strategy: InitStrategy,
+
+    /// The seed for the random number generator initializing all layers, if any.
+    seed: Option<u64>,
 }
 
 impl NeuralNetworkBuilder {
@@ -33,23 +46,55 @@ impl NeuralNetworkBuilder {
     pub fn new(input_nodes: NonZeroUsize) -> Self {
         Self {
             input_nodes,
-            hidden_layer_nodes: Vec::new(),
+            hidden_layers: Vec::new(),
+            init_strategy: InitStrategy::default(),
+            seed: None,
         }
     }
 
-    /// Add a hidden layer with the given number of `nodes` to the neural network.
+    /// Initialize the parameters of all layers of the neural network with the given `strategy`.
+    ///
+    /// Without a strategy, the layers are initialized with [`InitStrategy::Uniform01`].
+    ///
+    /// [`InitStrategy::Uniform01`]: enum.InitStrategy.html#variant.Uniform01
+    pub fn set_init_strategy(&'_ mut self, strategy: InitStrategy) -> &'_ mut Self {
+        self.init_strategy = strategy;
+
+        self
+    }
+
+    /// Initialize all layers of the neural network from a random number generator seeded with the
+    /// given `seed`, so that building the same neural network with the same seed always results in
+    /// the same parameters, e.g. to reproduce a training run.
+    ///
+    /// Without a seed, the layers are initialized like [`Layer::new`].
+    ///
+    /// [`Layer::new`]: struct.Layer.html#method.new
+    pub fn set_seed(&'_ mut self, seed: u64) -> &'_ mut Self {
+        self.seed = Some(seed);
+
+        self
+    }
+
+    /// Add a hidden layer with the given number of `nodes` and the given `activation` function to
+    /// the neural network.
     ///
     /// The order in which the hidden layers are inserted will be their order in the neural network
     /// once it is built.
-    pub fn add_hidden_layer(&'_ mut self, nodes: NonZeroUsize) -> &'_ mut Self {
-        self.hidden_layer_nodes.push(nodes);
+    pub fn add_hidden_layer(
+        &'_ mut self,
+        nodes: NonZeroUsize,
+        activation: Activation,
+    ) -> &'_ mut Self {
+        self.hidden_layers.push((nodes, activation));
 
         self
     }
 
     // TODO: Describe failures.
-    /// Add an output layer with the given number of nodes to the neural network, then initialize
-    /// the neural network with the parameters that have been set so far and return it.
+    /// Add an output layer with the given number of nodes and the given `activation` function to
+    /// the neural network, then initialize the neural network with the parameters that have been
+    /// set so far and return it.
     ///
     /// # Undefined Behaviour
     ///
@@ -57,15 +102,28 @@ impl NeuralNetworkBuilder {
     /// behaviour will be undefined.
     ///
     /// [`::std::usize::MAX - 1`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
-    pub fn add_output_layer(&self, nodes: NonZeroUsize) -> Result<NeuralNetwork> {
+    pub fn add_output_layer(
+        &self,
+        nodes: NonZeroUsize,
+        activation: Activation,
+    ) -> Result<NeuralNetwork> {
         // Create a vector of all nodes so we can just iterate over all of them.
-        // If self.hidden_layer_nodes.len() >= usize::MAX - 1, the addition will silently overflow.
-        let number_of_nodes: usize = self.hidden_layer_nodes.len() + 2;
+        // If self.hidden_layers.len() >= usize::MAX - 1, the addition will silently overflow.
+        let number_of_nodes: usize = self.hidden_layers.len() + 2;
         let mut layer_nodes: Vec<NonZeroUsize> = Vec::with_capacity(number_of_nodes);
         layer_nodes.push(self.input_nodes);
-        layer_nodes.append(&mut self.hidden_layer_nodes.clone());
+        layer_nodes.extend(self.hidden_layers.iter().map(|(nodes, _activation)| *nodes));
         layer_nodes.push(nodes);
 
+        // The activation function of each layer, i.e. of all hidden layers and the output layer.
+        let mut activations: Vec<Activation> = Vec::with_capacity(number_of_nodes - 1);
+        activations.extend(
+            self.hidden_layers
+                .iter()
+                .map(|(_nodes, activation)| *activation),
+        );
+        activations.push(activation);
+
         // Create a copy of the vector, then move ahead to the second item in one of the vectors.
         // We can then just zip those two together and will get a pair of numbers: the first one
         // will be the number of input nodes of a layer and the second one the number of output
@@ -74,10 +132,25 @@ impl NeuralNetworkBuilder {
         let input_iter: Vec<NonZeroUsize> = layer_nodes.clone();
         let output_iter = layer_nodes.iter().skip(1);
 
-        // Create the layers as described above.
+        // Create the layers as described above. With a seed, all layers share a single seeded
+        // random number generator so they do not all get the same parameters.
+        let mut seeded_rng: Option<StdRng> = self.seed.map(StdRng::seed_from_u64);
         let mut layers: Vec<Layer> = Vec::with_capacity(layer_nodes.len() - 1);
-        for (input_nodes, output_nodes) in input_iter.iter().zip(output_iter) {
-            layers.push(Layer::new(*input_nodes, *output_nodes)?)
+        for ((input_nodes, output_nodes), activation) in
+            input_iter.iter().zip(output_iter).zip(activations)
+        {
+            let (input_nodes, output_nodes): (NonZeroUsize, NonZeroUsize) =
+                (*input_nodes, *output_nodes);
+            let strategy: InitStrategy = self.init_strategy;
+            let layer: Layer = match seeded_rng.as_mut() {
+                Some(rng) => {
+                    Layer::new_with_rng(input_nodes, output_nodes, activation, strategy, rng)?
+                }
+                None => random::with_default_rng(|rng| {
+                    Layer::new_with_rng(input_nodes, output_nodes, activation, strategy, rng)
+                })?,
+            };
+            layers.push(layer);
         }
 
         // Create and return the actual neural network.
@@ -102,7 +175,9 @@ mod tests {
         let builder = NeuralNetworkBuilder::new(input_nodes);
 
         assert_eq!(builder.input_nodes, input_nodes);
-        assert!(builder.hidden_layer_nodes.is_empty())
+        assert!(builder.hidden_layers.is_empty());
+        assert_eq!(builder.init_strategy, InitStrategy::Uniform01);
+        assert!(builder.seed.is_none());
     }
 
     /// Test adding hidden layers to the neural network.
@@ -112,14 +187,20 @@ mod tests {
         let mut builder = NeuralNetworkBuilder::new(input_nodes);
 
         let nodes_1 = NonZeroUsize::new(7).unwrap();
-        builder.add_hidden_layer(nodes_1);
+        builder.add_hidden_layer(nodes_1, Activation::ReLU);
         assert_eq!(builder.input_nodes, input_nodes);
-        assert_eq!(builder.hidden_layer_nodes.as_slice(), &[nodes_1]);
+        assert_eq!(
+            builder.hidden_layers.as_slice(),
+            &[(nodes_1, Activation::ReLU)]
+        );
 
         let nodes_2 = NonZeroUsize::new(3).unwrap();
-        builder.add_hidden_layer(nodes_2);
+        builder.add_hidden_layer(nodes_2, Activation::Tanh);
         assert_eq!(builder.input_nodes, input_nodes);
-        assert_eq!(builder.hidden_layer_nodes.as_slice(), &[nodes_1, nodes_2]);
+        assert_eq!(
+            builder.hidden_layers.as_slice(),
+            &[(nodes_1, Activation::ReLU), (nodes_2, Activation::Tanh)]
+        );
     }
 
     /// Test adding an output layer to the neural network and getting a built network.
@@ -131,9 +212,10 @@ mod tests {
         let output_nodes = NonZeroUsize::new(2).unwrap();
 
         let mut builder = NeuralNetworkBuilder::new(input_nodes);
-        builder.add_hidden_layer(nodes_1);
-        builder.add_hidden_layer(nodes_2);
-        let network_result: Result<NeuralNetwork> = builder.add_output_layer(output_nodes);
+        builder.add_hidden_layer(nodes_1, Activation::ReLU);
+        builder.add_hidden_layer(nodes_2, Activation::Tanh);
+        let network_result: Result<NeuralNetwork> =
+            builder.add_output_layer(output_nodes, Activation::Sigmoid);
         assert!(network_result.is_ok());
 
         let network: NeuralNetwork = network_result.unwrap();
@@ -143,13 +225,64 @@ mod tests {
         // Input Layer.
         assert_eq!(layers[0].get_number_of_input_nodes(), input_nodes.get());
         assert_eq!(layers[0].get_number_of_output_nodes(), nodes_1.get());
+        assert_eq!(layers[0].get_activation(), Activation::ReLU);
 
         // Hidden Layer.
         assert_eq!(layers[1].get_number_of_input_nodes(), nodes_1.get());
         assert_eq!(layers[1].get_number_of_output_nodes(), nodes_2.get());
+        assert_eq!(layers[1].get_activation(), Activation::Tanh);
 
         // Output Layer.
         assert_eq!(layers[2].get_number_of_input_nodes(), nodes_2.get());
         assert_eq!(layers[2].get_number_of_output_nodes(), output_nodes.get());
+        assert_eq!(layers[2].get_activation(), Activation::Sigmoid);
+    }
+
+    /// Test that building a neural network with the same seed results in the same parameters.
+    #[test]
+    fn add_output_layer_seeded() {
+        let input_nodes = NonZeroUsize::new(3).unwrap();
+        let hidden_nodes = NonZeroUsize::new(3).unwrap();
+        let output_nodes = NonZeroUsize::new(3).unwrap();
+
+        let mut builder = NeuralNetworkBuilder::new(input_nodes);
+        builder
+            .set_seed(42)
+            .add_hidden_layer(hidden_nodes, Activation::ReLU);
+        assert_eq!(builder.seed, Some(42));
+
+        let first: NeuralNetwork = builder
+            .add_output_layer(output_nodes, Activation::Sigmoid)
+            .unwrap();
+        let second: NeuralNetwork = builder
+            .add_output_layer(output_nodes, Activation::Sigmoid)
+            .unwrap();
+        assert_eq!(first.get_layers(), second.get_layers());
+
+        // The layers do not share the same parameters.
+        let layers: &[Layer] = first.get_layers();
+        assert_ne!(layers[0].get_weights(), layers[1].get_weights());
+    }
+
+    /// Test that the initialization strategy is applied to all layers.
+    #[test]
+    fn add_output_layer_init_strategy() {
+        let input_nodes = NonZeroUsize::new(6).unwrap();
+        let hidden_nodes = NonZeroUsize::new(4).unwrap();
+        let output_nodes = NonZeroUsize::new(2).unwrap();
+
+        let mut builder = NeuralNetworkBuilder::new(input_nodes);
+        builder
+            .set_init_strategy(InitStrategy::XavierUniform)
+            .add_hidden_layer(hidden_nodes, Activation::Tanh);
+        assert_eq!(builder.init_strategy, InitStrategy::XavierUniform);
+
+        let network: NeuralNetwork = builder
+            .add_output_layer(output_nodes, Activation::Sigmoid)
+            .unwrap();
+        for layer in network.get_layers() {
+            // Xavier initialization sets the bias to zero, in contrast to the default strategy.
+            assert!(layer.get_bias().as_slice().iter().all(|bias| *bias == 0.0));
+        }
     }
 }
@@ -0,0 +1,182 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Random distributions that are not provided by `rand` itself, and the default random number
+//! generator used for initializing matrices.
+
+use std::cell::RefCell;
+use std::f64::consts::PI;
+
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use rand::thread_rng;
+use rand::Rng;
+use rand::RngCore;
+use rand::SeedableRng;
+
+use crate::Error;
+use crate::Result;
+
+thread_local! {
+    /// The seeded random number generator installed with [`set_default_seed`], if any.
+    ///
+    /// [`set_default_seed`]: fn.set_default_seed.html
+    static DEFAULT_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Make all random initializations on the current thread deterministic by installing a random
+/// number generator seeded with the given `seed`, e.g. for reproducible runs in a CI pipeline.
+///
+/// Afterwards, [`Matrix::from_random`] and [`Layer::new`] draw their values from this random
+/// number generator instead of `rand::thread_rng`. Setting the same seed again resets
+/// the random number generator, so the same sequence of random values will be generated. The
+/// setting only affects the current thread. Call [`clear_default_seed`] to revert to the
+/// non-deterministic default.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::Matrix;
+/// use reural_network::set_default_seed;
+///
+/// let size: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+///
+/// set_default_seed(42);
+/// let first: Matrix<f64> = Matrix::from_random(size, size).unwrap();
+///
+/// set_default_seed(42);
+/// let second: Matrix<f64> = Matrix::from_random(size, size).unwrap();
+///
+/// assert_eq!(first, second);
+/// ```
+///
+/// [`clear_default_seed`]: fn.clear_default_seed.html
+/// [`Layer::new`]: struct.Layer.html#method.new
+/// [`Matrix::from_random`]: matrix/struct.Matrix.html#method.from_random
+pub fn set_default_seed(seed: u64) {
+    DEFAULT_RNG.with(|rng| *rng.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Remove the seeded random number generator installed with [`set_default_seed`] on the current
+/// thread, so that random initializations use `rand::thread_rng` again.
+///
+/// [`set_default_seed`]: fn.set_default_seed.html
+pub fn clear_default_seed() {
+    DEFAULT_RNG.with(|rng| *rng.borrow_mut() = None);
+}
+
+/// Call the closure `f` with the default random number generator of the current thread: the
+/// seeded random number generator installed with [`set_default_seed`] if there is one, or
+/// `rand::thread_rng` otherwise.
+///
+/// [`set_default_seed`]: fn.set_default_seed.html
+pub(crate) fn with_default_rng<F, T>(f: F) -> T
+where
+    F: FnOnce(&mut dyn RngCore) -> T,
+{
+    DEFAULT_RNG.with(|rng| match rng.borrow_mut().as_mut() {
+        Some(seeded) => f(seeded),
+        None => f(&mut thread_rng()),
+    })
+}
+
+/// The normal (Gaussian) distribution `N(mean, std_dev^2)`.
+///
+/// Samples are generated with the Box-Muller transform.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Normal {
+    /// The mean of the distribution.
+    mean: f64,
+
+    /// The standard deviation of the distribution.
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Create a new normal distribution with the given `mean` and standard deviation `std_dev`.
+    ///
+    /// The `mean` must be finite and `std_dev` must be finite and not negative. Otherwise, an
+    /// [`Error::InvalidRange`] will be returned. A standard deviation of `0.0` is allowed; in this
+    /// case, each sample will be exactly the mean.
+    ///
+    /// [`Error::InvalidRange`]: ../enum.Error.html#variant.InvalidRange
+    pub(crate) fn new(mean: f64, std_dev: f64) -> Result<Normal> {
+        if !mean.is_finite() || !std_dev.is_finite() || std_dev < 0.0 {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(Normal { mean, std_dev })
+    }
+}
+
+impl Distribution<f64> for Normal {
+    /// Generate a random value from the distribution using the given random number generator
+    /// `rng`.
+    fn sample<R>(&self, rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized,
+    {
+        // Box-Muller transform. The first uniform value must not be zero to avoid `ln(0)`.
+        let u1: f64 = 1.0 - rng.gen::<f64>();
+        let u2: f64 = rng.gen::<f64>();
+        let standard_normal: f64 = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+
+        self.mean + self.std_dev * standard_normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that the same default seed yields the same random values.
+    #[test]
+    fn set_default_seed() {
+        super::set_default_seed(7);
+        let first: Vec<u64> = (0..5)
+            .map(|_| with_default_rng(|rng| rng.next_u64()))
+            .collect();
+
+        super::set_default_seed(7);
+        let second: Vec<u64> = (0..5)
+            .map(|_| with_default_rng(|rng| rng.next_u64()))
+            .collect();
+        assert_eq!(first, second);
+
+        clear_default_seed();
+        DEFAULT_RNG.with(|rng| assert!(rng.borrow().is_none()));
+    }
+
+    /// Test creating a normal distribution with invalid parameters.
+    #[test]
+    fn new_invalid_parameters() {
+        for &(mean, std_dev) in &[(0.0, -1.0), (0.0, f64::NAN), (f64::INFINITY, 1.0)] {
+            let normal_result: Result<Normal> = Normal::new(mean, std_dev);
+            assert!(
+                matches!(normal_result, Err(Error::InvalidRange)),
+                "Expected error Error::InvalidRange not satisfied."
+            );
+        }
+    }
+
+    /// Test that the empirical mean and standard deviation of many samples are close to the
+    /// parameters of the distribution.
+    #[test]
+    fn sample_moments() {
+        let normal: Normal = Normal::new(2.0, 0.5).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+
+        let samples: Vec<f64> = (0..10_000).map(|_| normal.sample(&mut rng)).collect();
+        let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance: f64 =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+
+        assert!((mean - 2.0).abs() < 0.05);
+        assert!((variance.sqrt() - 0.5).abs() < 0.05);
+    }
+}
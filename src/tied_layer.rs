@@ -0,0 +1,343 @@
+// Copyright 2020 Bastian Meyer
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be copied, modified, or
+// distributed except according to those terms.
+
+//! Definition and implementation of layers sharing their weights with each other.
+
+use std::cell::RefCell;
+use std::num::NonZeroUsize;
+use std::rc::Rc;
+
+use crate::matrix::Matrix;
+use crate::Activation;
+use crate::Result;
+
+/// A layer whose weights are shared with other layers, e.g. the encoder and the decoder of an
+/// autoencoder with tied weights.
+///
+/// # Ownership
+///
+/// The weights are stored in an `Rc<RefCell<Matrix<f64>>>`: each layer tied to the same weights
+/// holds a reference-counted pointer to them, and the weights are dropped once the last of these
+/// layers is dropped. Each layer can update the shared weights through the `RefCell`, and the
+/// change is immediately visible to all other tied layers. A clone of a tied layer is tied to the
+/// same weights. Because of `Rc`, tied layers cannot be sent to other threads.
+///
+/// The layer created by [`new`] uses the weights as they are (an `o x i` matrix), the layers
+/// created by [`tie_transposed`] use their transpose (an `i x o` matrix). Each layer has its own
+/// bias and activation function.
+///
+/// # Limitations
+///
+/// A [`NeuralNetwork`] cannot contain tied layers yet. Since neural networks cannot be trained
+/// yet either, there is no training that could update the shared weights automatically. Until
+/// then, compute the gradients of the parameters yourself and pass them to [`apply_gradient`] of
+/// each layer.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use reural_network::matrix::Matrix;
+/// use reural_network::Activation;
+/// use reural_network::TiedLayer;
+///
+/// let input_nodes: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+/// let code_nodes: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+/// let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+///
+/// let encoder: TiedLayer = TiedLayer::new(input_nodes, code_nodes, Activation::ReLU).unwrap();
+/// let decoder: TiedLayer = encoder.tie_transposed(Activation::Sigmoid).unwrap();
+///
+/// let input: Matrix<f64> = Matrix::new(input_nodes, one, 0.5).unwrap();
+/// let code: Matrix<f64> = encoder.predict(&input).unwrap();
+/// let reconstruction: Matrix<f64> = decoder.predict(&code).unwrap();
+/// assert!(reconstruction.ensure_dimensions(4, 1).is_ok());
+/// ```
+///
+/// [`apply_gradient`]: #method.apply_gradient
+/// [`new`]: #method.new
+/// [`NeuralNetwork`]: struct.NeuralNetwork.html
+/// [`tie_transposed`]: #method.tie_transposed
+#[derive(Clone, Debug)]
+pub struct TiedLayer {
+    /// The weights shared with all tied layers.
+    ///
+    /// This is a `o x i` matrix where `o` is the number of output nodes and `i` the number of
+    /// input nodes of the layer that does not use the transpose.
+    weights: Rc<RefCell<Matrix<f64>>>,
+
+    /// If `true`, this layer uses the transpose of the shared weights.
+    transposed: bool,
+
+    /// The bias of this layer.
+    ///
+    /// This is a `o x 1` matrix where `o` is the number of this layer's output nodes.
+    bias: Matrix<f64>,
+
+    /// The activation function applied to the weighted sum of this layer's input.
+    activation: Activation,
+}
+
+impl TiedLayer {
+    // region Initialize
+
+    /// Create a new layer with the given number of input and output nodes that will apply the
+    /// given `activation` function to its outputs. Other layers can then be tied to its weights
+    /// with [`tie_transposed`].
+    ///
+    /// The weights and bias will be initialized with random values within `[0.0, 1.0]`.
+    ///
+    /// The product of the number of input nodes and output nodes must not exceed the maximum
+    /// `usize` value, [`::std::usize::MAX`]. Otherwise, an [`Error::DimensionsTooLarge`] will be
+    /// returned.
+    ///
+    /// [`tie_transposed`]: #method.tie_transposed
+    /// [`::std::usize::MAX`]: https://doc.rust-lang.org/stable/std/usize/constant.MAX.html
+    /// [`Error::DimensionsTooLarge`]: enum.Error.html#variant.DimensionsTooLarge
+    pub fn new(
+        input_nodes: NonZeroUsize,
+        output_nodes: NonZeroUsize,
+        activation: Activation,
+    ) -> Result<TiedLayer> {
+        // Weights are `o x i`.
+        let weights: Matrix<f64> = Matrix::from_random(output_nodes, input_nodes)?;
+
+        // Bias is `o x 1`.
+        let bias: Matrix<f64> = Matrix::from_random(output_nodes, NonZeroUsize::new(1).unwrap())?;
+
+        Ok(TiedLayer {
+            weights: Rc::new(RefCell::new(weights)),
+            transposed: false,
+            bias,
+            activation,
+        })
+    }
+
+    /// Create a new layer that uses the transpose of this layer's weights, and thus maps this
+    /// layer's outputs back to its inputs, e.g. the decoder for this encoder. The new layer will
+    /// apply the given `activation` function to its outputs.
+    ///
+    /// The bias of the new layer will be initialized with random values within `[0.0, 1.0]`.
+    pub fn tie_transposed(&self, activation: Activation) -> Result<TiedLayer> {
+        let output_nodes: NonZeroUsize = NonZeroUsize::new(self.get_number_of_input_nodes())
+            .expect("A layer always has at least one input node.");
+        let bias: Matrix<f64> = Matrix::from_random(output_nodes, NonZeroUsize::new(1).unwrap())?;
+
+        Ok(TiedLayer {
+            weights: Rc::clone(&self.weights),
+            transposed: !self.transposed,
+            bias,
+            activation,
+        })
+    }
+
+    // endregion
+
+    // region Getters
+
+    /// Get the activation function of this layer.
+    pub fn get_activation(&self) -> Activation {
+        self.activation
+    }
+
+    /// Get the bias of this layer.
+    ///
+    /// This is a `o x 1` matrix where `o` is the number of this layer's output nodes.
+    pub fn get_bias(&self) -> &Matrix<f64> {
+        &self.bias
+    }
+
+    /// Get the number of input nodes.
+    pub fn get_number_of_input_nodes(&self) -> usize {
+        let weights = self.weights.borrow();
+        if self.transposed {
+            weights.get_number_of_rows()
+        } else {
+            weights.get_number_of_columns()
+        }
+    }
+
+    /// Get the number of output nodes.
+    pub fn get_number_of_output_nodes(&self) -> usize {
+        self.bias.get_number_of_rows()
+    }
+
+    /// Get the weights shared with all tied layers, without transposing them.
+    ///
+    /// Changing the weights through the returned pointer affects all tied layers.
+    pub fn get_shared_weights(&self) -> &Rc<RefCell<Matrix<f64>>> {
+        &self.weights
+    }
+
+    /// Determine if this layer uses the transpose of the shared weights.
+    pub fn is_transposed(&self) -> bool {
+        self.transposed
+    }
+
+    // endregion
+
+    // region AI
+
+    /// Predict an output of this layer for the given input.
+    ///
+    /// The input matrix must be an `i x 1` matrix where `i` is the number of (input) nodes in this
+    /// layer. Otherwise, [`Error::ShapeMismatch`] will be returned.
+    ///
+    /// The output matrix will be a `o x 1` matrix where `o` is the number of outputs of this layer.
+    ///
+    /// [`Error::ShapeMismatch`]: enum.Error.html#variant.ShapeMismatch
+    pub fn predict(&self, input: &Matrix<f64>) -> Result<Matrix<f64>> {
+        // The input matrix must have one row per input node and only one column.
+        input.ensure_dimensions(self.get_number_of_input_nodes(), 1)?;
+
+        let weights = self.weights.borrow();
+        let output: Matrix<f64> = if self.transposed {
+            weights.transpose().matrix_mul(input)?
+        } else {
+            weights.matrix_mul(input)?
+        };
+        let output: Matrix<f64> = output.broadcast_add(&self.bias)?;
+
+        Ok(self.activation.apply(&output))
+    }
+
+    // endregion
+
+    // region Training
+
+    /// Update the parameters of this layer with the given gradients, i.e. subtract the gradients
+    /// scaled by the `learning_rate` from the weights as used by this layer and the bias,
+    /// respectively. The update of the weights is visible to all tied layers.
+    ///
+    /// The `weight_gradient` must be a `o x i` matrix where `o` is the number of output nodes and
+    /// `i` the number of input nodes of this layer, and the `bias_gradient` must have the same
+    /// dimensions as the bias. Otherwise, [`Error::ShapeMismatch`] will be returned and the layer
+    /// will not be changed.
+    ///
+    /// [`Error::ShapeMismatch`]: enum.Error.html#variant.ShapeMismatch
+    pub fn apply_gradient(
+        &mut self,
+        weight_gradient: &Matrix<f64>,
+        bias_gradient: &Matrix<f64>,
+        learning_rate: f64,
+    ) -> Result<()> {
+        // Check both gradients before changing anything.
+        weight_gradient.ensure_dimensions(
+            self.get_number_of_output_nodes(),
+            self.get_number_of_input_nodes(),
+        )?;
+        bias_gradient.ensure_dimensions(self.bias.get_number_of_rows(), 1)?;
+
+        // The shared weights are not transposed, thus the gradient must be.
+        let weight_step: Matrix<f64> = if self.transposed {
+            &weight_gradient.transpose() * learning_rate
+        } else {
+            weight_gradient * learning_rate
+        };
+
+        let mut weights = self.weights.borrow_mut();
+        *weights = (&*weights - &weight_step)?;
+        self.bias = (&self.bias - &(bias_gradient * learning_rate))?;
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::Error;
+
+    /// Create a tied encoder with known weights and bias, and its decoder with a zero bias.
+    fn autoencoder() -> (TiedLayer, TiedLayer) {
+        let mut encoder: TiedLayer = TiedLayer::new(
+            NonZeroUsize::new(3).unwrap(),
+            NonZeroUsize::new(2).unwrap(),
+            Activation::Identity,
+        )
+        .unwrap();
+        encoder
+            .weights
+            .borrow_mut()
+            .map(|_element, row, column| (row * 3 + column) as f64);
+        encoder.bias.map(|_element, _row, _column| 0.0);
+
+        let mut decoder: TiedLayer = encoder.tie_transposed(Activation::Identity).unwrap();
+        decoder.bias.map(|_element, _row, _column| 0.0);
+
+        (encoder, decoder)
+    }
+
+    /// Test that the decoder uses the transpose of the encoder's weights.
+    #[test]
+    fn tie_transposed() {
+        let (encoder, decoder) = autoencoder();
+        assert!(!encoder.is_transposed());
+        assert!(decoder.is_transposed());
+        assert!(Rc::ptr_eq(
+            encoder.get_shared_weights(),
+            decoder.get_shared_weights()
+        ));
+        assert_eq!(decoder.get_number_of_input_nodes(), 2);
+        assert_eq!(decoder.get_number_of_output_nodes(), 3);
+
+        // The weights are [[0, 1, 2], [3, 4, 5]].
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let code: Matrix<f64> =
+            Matrix::from_slice(NonZeroUsize::new(2).unwrap(), one, &[1.0, 2.0]).unwrap();
+        let output: Matrix<f64> = decoder.predict(&code).unwrap();
+        assert_relative_eq!(*output.as_slice(), [6.0, 9.0, 12.0]);
+
+        let prediction_result: Result<Matrix<f64>> = decoder.predict(&output);
+        assert!(
+            matches!(prediction_result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+    }
+
+    /// Test that updating the encoder's weights is reflected in the decoder's forward pass, and
+    /// vice versa.
+    #[test]
+    fn apply_gradient_shared() {
+        let (mut encoder, mut decoder) = autoencoder();
+        let one: NonZeroUsize = NonZeroUsize::new(1).unwrap();
+        let two: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+        let three: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+        let code: Matrix<f64> = Matrix::from_slice(two, one, &[1.0, 2.0]).unwrap();
+
+        // Subtracting a gradient of ones from the encoder's weights reduces each weight by one.
+        let weight_gradient: Matrix<f64> = Matrix::new(two, three, 1.0).unwrap();
+        let bias_gradient: Matrix<f64> = Matrix::new(two, one, 0.0).unwrap();
+        assert!(encoder
+            .apply_gradient(&weight_gradient, &bias_gradient, 1.0)
+            .is_ok());
+        let output: Matrix<f64> = decoder.predict(&code).unwrap();
+        assert_relative_eq!(*output.as_slice(), [3.0, 6.0, 9.0]);
+
+        // The decoder's gradient is transposed before it is applied to the shared weights.
+        let mut weight_gradient: Matrix<f64> = Matrix::new(three, two, 0.0).unwrap();
+        weight_gradient[(0, 1)] = 1.0;
+        let bias_gradient: Matrix<f64> = Matrix::new(three, one, 0.0).unwrap();
+        assert!(decoder
+            .apply_gradient(&weight_gradient, &bias_gradient, 2.0)
+            .is_ok());
+        let weights: Matrix<f64> = encoder.get_shared_weights().borrow().clone();
+        assert_relative_eq!(*weights.as_slice(), [-1.0, 0.0, 1.0, 0.0, 3.0, 4.0]);
+
+        // Gradients with the dimensions of the shared weights do not fit the decoder.
+        let result: Result<()> =
+            decoder.apply_gradient(&Matrix::new(two, three, 1.0).unwrap(), &bias_gradient, 1.0);
+        assert!(
+            matches!(result, Err(Error::ShapeMismatch { .. })),
+            "Expected error Error::ShapeMismatch not satisfied."
+        );
+    }
+}